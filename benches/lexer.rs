@@ -0,0 +1,51 @@
+// 词法分析的吞吐量：生成一个大的 C 文件，统计每秒处理的字节数和 token 数
+// 运行：cargo bench --bench lexer
+use quickcc::lexer::lexer::Lexer;
+use std::hint::black_box;
+use std::time::Instant;
+
+// 大约每个函数 400 字节，覆盖标识符、关键字、各种字面量、运算符和注释
+fn generate(functions: usize) -> String {
+    let mut src = String::new();
+    for k in 0..functions {
+        src += &format!(
+            "/* 函数 {k}：累加 */\n\
+             int func_{k}(int a, int b) {{\n\
+             \x20   int sum_{k} = 0x{k:x} + 0{o} + {k}u; // 初值\n\
+             \x20   double d = 1.5e-3 * {k}.25f;\n\
+             \x20   while (a < b && b != 0) {{\n\
+             \x20       sum_{k} += a++ * 2 - (b >> 1) % 7;\n\
+             \x20       a <<= 1; b -= 'x' + '\\n';\n\
+             \x20   }}\n\
+             \x20   if (sum_{k} >= 100 || !a) return sum_{k};\n\
+             \x20   else return func_{k}(a, b) ? \"done\\t{k}\" [0] : -1;\n\
+             }}\n",
+            o = k % 8,
+        );
+    }
+    src
+}
+
+fn main() {
+    let src = generate(50_000);
+    let mb = src.len() as f64 / (1024.0 * 1024.0);
+    println!("input: {:.1} MiB", mb);
+    // 先跑一遍预热
+    black_box(Lexer::new(&src).count());
+    let runs = 5;
+    let mut best = f64::MAX;
+    let mut tokens = 0;
+    for _ in 0..runs {
+        let start = Instant::now();
+        tokens = black_box(Lexer::new(&src).filter(|r| r.is_ok()).count());
+        best = best.min(start.elapsed().as_secs_f64());
+    }
+    println!(
+        "lexer: {} tokens in {:.3}s, {:.1} MiB/s, {:.2} Mtokens/s (best of {})",
+        tokens,
+        best,
+        mb / best,
+        tokens as f64 / best / 1e6,
+        runs
+    );
+}
@@ -0,0 +1,40 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+// 构建时生成的表
+#[path = "build/dfa.rs"]
+mod dfa;
+#[path = "src/parser/grammar.rs"]
+mod grammar;
+#[path = "build/lalr.rs"]
+mod lalr;
+#[path = "src/lexer/spec.rs"]
+mod spec;
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=build/dfa.rs");
+    println!("cargo:rerun-if-changed=build/lalr.rs");
+    println!("cargo:rerun-if-changed=src/lexer/spec.rs");
+    println!("cargo:rerun-if-changed=src/parser/grammar.rs");
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+
+    let patterns: Vec<&str> = spec::RULES.iter().map(|(_, re)| *re).collect();
+    let rules: Vec<spec::Rule> = spec::RULES.iter().map(|(rule, _)| *rule).collect();
+    let lexer_dfa = dfa::build_dfa(&patterns);
+    fs::write(out_dir.join("lexer_dfa.rs"), dfa::emit(&lexer_dfa, &rules)).unwrap();
+
+    let productions: Vec<&str> = grammar::GRAMMAR.iter().map(|(_, rule)| *rule).collect();
+    let prods: Vec<grammar::Prod> = grammar::GRAMMAR.iter().map(|(prod, _)| *prod).collect();
+    let parser_grammar = lalr::parse_grammar(&productions);
+    let tables = match lalr::build_tables(&parser_grammar) {
+        Ok(tables) => tables,
+        Err(report) => panic!("grammar is not LALR(1):\n{}", report),
+    };
+    fs::write(
+        out_dir.join("parser_tables.rs"),
+        lalr::emit(&parser_grammar, &tables, &prods),
+    )
+    .unwrap();
+}
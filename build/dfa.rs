@@ -0,0 +1,416 @@
+// 词法规则 -> NFA -> DFA -> 最小化 DFA -> Rust 表
+// 只在 build.rs 里使用，生成的表见 src/lexer/dfa.rs
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::{Debug, Write};
+
+type ByteSet = [bool; 256];
+
+#[derive(Debug, Clone)]
+enum Regex {
+    Set(Box<ByteSet>),
+    Concat(Vec<Regex>),
+    Alt(Vec<Regex>),
+    Star(Box<Regex>),
+    Plus(Box<Regex>),
+    Opt(Box<Regex>),
+}
+
+fn single(b: u8) -> ByteSet {
+    let mut set = [false; 256];
+    set[b as usize] = true;
+    set
+}
+
+// 递归下降解析规则里的正则
+struct RegexParser<'a> {
+    src: &'a [u8],
+    pos: usize,
+}
+
+impl RegexParser<'_> {
+    fn parse(src: &str) -> Regex {
+        let mut p = RegexParser {
+            src: src.as_bytes(),
+            pos: 0,
+        };
+        let re = p.alt();
+        if p.pos != p.src.len() {
+            panic!("regex {:?}: unexpected {:?}", src, p.src[p.pos] as char);
+        }
+        re
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.src.get(self.pos).copied()
+    }
+
+    fn bump(&mut self) -> u8 {
+        let b = self.src[self.pos];
+        self.pos += 1;
+        b
+    }
+
+    fn alt(&mut self) -> Regex {
+        let mut items = vec![self.concat()];
+        while self.peek() == Some(b'|') {
+            self.bump();
+            items.push(self.concat());
+        }
+        if items.len() == 1 {
+            items.pop().unwrap()
+        } else {
+            Regex::Alt(items)
+        }
+    }
+
+    fn concat(&mut self) -> Regex {
+        let mut items = Vec::new();
+        while let Some(b) = self.peek()
+            && b != b'|'
+            && b != b')'
+        {
+            items.push(self.repeat());
+        }
+        Regex::Concat(items)
+    }
+
+    fn repeat(&mut self) -> Regex {
+        let mut re = self.atom();
+        loop {
+            re = match self.peek() {
+                Some(b'*') => Regex::Star(Box::new(re)),
+                Some(b'+') => Regex::Plus(Box::new(re)),
+                Some(b'?') => Regex::Opt(Box::new(re)),
+                _ => return re,
+            };
+            self.bump();
+        }
+    }
+
+    fn atom(&mut self) -> Regex {
+        match self.bump() {
+            b'(' => {
+                let re = self.alt();
+                assert_eq!(self.bump(), b')', "unbalanced parenthesis");
+                re
+            }
+            b'[' => self.class(),
+            b'.' => {
+                let mut set = [true; 256];
+                set[b'\n' as usize] = false;
+                Regex::Set(Box::new(set))
+            }
+            b'\\' => Regex::Set(Box::new(single(self.escape()))),
+            b => Regex::Set(Box::new(single(b))),
+        }
+    }
+
+    fn escape(&mut self) -> u8 {
+        match self.bump() {
+            b'n' => b'\n',
+            b't' => b'\t',
+            b'r' => b'\r',
+            b'v' => 0x0b,
+            b'f' => 0x0c,
+            b'0' => 0,
+            b => b,
+        }
+    }
+
+    fn class_byte(&mut self) -> u8 {
+        match self.bump() {
+            b'\\' => self.escape(),
+            b => b,
+        }
+    }
+
+    fn class(&mut self) -> Regex {
+        let negate = self.peek() == Some(b'^');
+        if negate {
+            self.bump();
+        }
+        let mut set = [false; 256];
+        while self.peek() != Some(b']') {
+            let lo = self.class_byte();
+            let hi = if self.peek() == Some(b'-') && self.src.get(self.pos + 1) != Some(&b']') {
+                self.bump();
+                self.class_byte()
+            } else {
+                lo
+            };
+            for b in lo..=hi {
+                set[b as usize] = true;
+            }
+        }
+        self.bump();
+        if negate {
+            for b in set.iter_mut() {
+                *b = !*b;
+            }
+        }
+        Regex::Set(Box::new(set))
+    }
+}
+
+// Thompson 构造的 NFA，边上是字节集合的编号
+#[derive(Default)]
+struct Nfa {
+    eps: Vec<Vec<usize>>,
+    edges: Vec<Vec<(usize, usize)>>,
+    accept: Vec<Option<usize>>,
+    sets: Vec<ByteSet>,
+}
+
+impl Nfa {
+    fn state(&mut self) -> usize {
+        self.eps.push(Vec::new());
+        self.edges.push(Vec::new());
+        self.accept.push(None);
+        self.eps.len() - 1
+    }
+
+    // 返回片段的 (入口, 出口)
+    fn build(&mut self, re: &Regex) -> (usize, usize) {
+        match re {
+            Regex::Set(set) => {
+                let (s, e) = (self.state(), self.state());
+                let id = match self.sets.iter().position(|x| x == set.as_ref()) {
+                    Some(id) => id,
+                    None => {
+                        self.sets.push(**set);
+                        self.sets.len() - 1
+                    }
+                };
+                self.edges[s].push((id, e));
+                (s, e)
+            }
+            Regex::Concat(items) => {
+                let s = self.state();
+                let mut end = s;
+                for item in items {
+                    let (is, ie) = self.build(item);
+                    self.eps[end].push(is);
+                    end = ie;
+                }
+                (s, end)
+            }
+            Regex::Alt(items) => {
+                let (s, e) = (self.state(), self.state());
+                for item in items {
+                    let (is, ie) = self.build(item);
+                    self.eps[s].push(is);
+                    self.eps[ie].push(e);
+                }
+                (s, e)
+            }
+            Regex::Star(inner) | Regex::Plus(inner) | Regex::Opt(inner) => {
+                let (s, e) = (self.state(), self.state());
+                let (is, ie) = self.build(inner);
+                self.eps[s].push(is);
+                self.eps[ie].push(e);
+                if !matches!(re, Regex::Plus(_)) {
+                    self.eps[s].push(e);
+                }
+                if !matches!(re, Regex::Opt(_)) {
+                    self.eps[ie].push(is);
+                }
+                (s, e)
+            }
+        }
+    }
+
+    fn closure(&self, states: impl IntoIterator<Item = usize>) -> BTreeSet<usize> {
+        let mut set = BTreeSet::new();
+        let mut stack: Vec<usize> = states.into_iter().collect();
+        while let Some(s) = stack.pop() {
+            if set.insert(s) {
+                stack.extend(self.eps[s].iter().copied());
+            }
+        }
+        set
+    }
+}
+
+pub struct Dfa {
+    // 字节到等价类的映射
+    pub byte_class: Vec<usize>,
+    pub classes: usize,
+    // trans[state][class]，0 号是死状态
+    pub trans: Vec<Vec<usize>>,
+    // 接受状态对应的规则下标
+    pub accept: Vec<Option<usize>>,
+    pub start: usize,
+}
+
+// 把 256 个字节按它们在所有字节集合中的归属分成等价类
+fn byte_classes(sets: &[ByteSet]) -> (Vec<usize>, usize) {
+    let mut ids: HashMap<Vec<bool>, usize> = HashMap::new();
+    let mut byte_class = Vec::with_capacity(256);
+    for b in 0..256 {
+        let key: Vec<bool> = sets.iter().map(|s| s[b]).collect();
+        let next = ids.len();
+        byte_class.push(*ids.entry(key).or_insert(next));
+    }
+    (byte_class, ids.len())
+}
+
+pub fn build_dfa(rules: &[&str]) -> Dfa {
+    let mut nfa = Nfa::default();
+    let start = nfa.state();
+    for (k, rule) in rules.iter().enumerate() {
+        let (s, e) = nfa.build(&RegexParser::parse(rule));
+        nfa.eps[start].push(s);
+        nfa.accept[e] = Some(k);
+    }
+    let (byte_class, classes) = byte_classes(&nfa.sets);
+    // 每个字节集合包含哪些等价类
+    let set_classes: Vec<Vec<bool>> = nfa
+        .sets
+        .iter()
+        .map(|set| {
+            let mut cs = vec![false; classes];
+            for b in 0..256 {
+                if set[b] {
+                    cs[byte_class[b]] = true;
+                }
+            }
+            cs
+        })
+        .collect();
+
+    // 子集构造，0 号是空集（死状态）
+    let mut subsets: Vec<BTreeSet<usize>> = vec![BTreeSet::new()];
+    let mut index: HashMap<BTreeSet<usize>, usize> = HashMap::new();
+    index.insert(BTreeSet::new(), 0);
+    let first = nfa.closure([start]);
+    index.insert(first.clone(), 1);
+    subsets.push(first);
+    let mut trans: Vec<Vec<usize>> = vec![vec![0; classes]];
+    let mut k = 1;
+    while k < subsets.len() {
+        let mut row = vec![0; classes];
+        for (c, slot) in row.iter_mut().enumerate() {
+            let targets = subsets[k].iter().flat_map(|&s| {
+                nfa.edges[s]
+                    .iter()
+                    .filter(|(set, _)| set_classes[*set][c])
+                    .map(|(_, t)| *t)
+            });
+            let next = nfa.closure(targets.collect::<Vec<_>>());
+            *slot = match index.get(&next) {
+                Some(&id) => id,
+                None => {
+                    index.insert(next.clone(), subsets.len());
+                    subsets.push(next);
+                    subsets.len() - 1
+                }
+            };
+        }
+        trans.push(row);
+        k += 1;
+    }
+    let accept = subsets
+        .iter()
+        .map(|set| set.iter().filter_map(|&s| nfa.accept[s]).min())
+        .collect();
+    minimize(Dfa {
+        byte_class,
+        classes,
+        trans,
+        accept,
+        start: 1,
+    })
+}
+
+// Moore 算法：按接受的规则初始划分，反复按转移细分直到稳定
+fn minimize(dfa: Dfa) -> Dfa {
+    let n = dfa.trans.len();
+    let mut group: Vec<usize> = {
+        let mut ids: HashMap<Option<usize>, usize> = HashMap::new();
+        dfa.accept
+            .iter()
+            .map(|a| {
+                let next = ids.len();
+                *ids.entry(*a).or_insert(next)
+            })
+            .collect()
+    };
+    loop {
+        let mut ids: HashMap<(usize, Vec<usize>), usize> = HashMap::new();
+        let next: Vec<usize> = (0..n)
+            .map(|s| {
+                let key = (group[s], dfa.trans[s].iter().map(|&t| group[t]).collect());
+                let len = ids.len();
+                *ids.entry(key).or_insert(len)
+            })
+            .collect();
+        let stable = ids.len() == group.iter().collect::<BTreeSet<_>>().len();
+        group = next;
+        if stable {
+            break;
+        }
+    }
+    // 重新编号，死状态所在的组排在 0 号
+    let mut order: Vec<usize> = vec![group[0]];
+    for &g in &group {
+        if !order.contains(&g) {
+            order.push(g);
+        }
+    }
+    let renum = |s: usize| order.iter().position(|&g| g == group[s]).unwrap();
+    let mut trans = vec![Vec::new(); order.len()];
+    let mut accept = vec![None; order.len()];
+    for s in 0..n {
+        let id = renum(s);
+        trans[id] = dfa.trans[s].iter().map(|&t| renum(t)).collect();
+        accept[id] = dfa.accept[s];
+    }
+    Dfa {
+        byte_class: dfa.byte_class,
+        classes: dfa.classes,
+        trans,
+        accept,
+        start: renum(dfa.start),
+    }
+}
+
+// 输出成 Rust 源码，rules 只用来打印规则名
+pub fn emit<R: Debug>(dfa: &Dfa, rules: &[R]) -> String {
+    let mut out = String::new();
+    let states = dfa.trans.len();
+    writeln!(out, "// 由 build.rs 根据 src/lexer/spec.rs 生成，不要手改").unwrap();
+    writeln!(out, "// {} 个状态，{} 个字节等价类", states, dfa.classes).unwrap();
+    writeln!(out, "pub const DFA_CLASSES: usize = {};", dfa.classes).unwrap();
+    writeln!(out, "pub const DFA_START: u16 = {};", dfa.start).unwrap();
+    writeln!(out, "pub const DFA_DEAD: u16 = 0;").unwrap();
+    writeln!(
+        out,
+        "pub static DFA_BYTE_CLASS: [u8; 256] = {:?};",
+        dfa.byte_class
+    )
+    .unwrap();
+    let flat: Vec<usize> = dfa.trans.iter().flatten().copied().collect();
+    writeln!(
+        out,
+        "pub static DFA_TRANSITIONS: [u16; {}] = {:?};",
+        flat.len(),
+        flat
+    )
+    .unwrap();
+    let accept: Vec<String> = dfa
+        .accept
+        .iter()
+        .map(|a| match a {
+            Some(k) => format!("Some(Rule::{:?})", rules[*k]),
+            None => String::from("None"),
+        })
+        .collect();
+    writeln!(
+        out,
+        "pub static DFA_ACCEPT: [Option<Rule>; {}] = [{}];",
+        states,
+        accept.join(", ")
+    )
+    .unwrap();
+    out
+}
@@ -0,0 +1,572 @@
+// 文法 -> LR(0) 项集族 -> 传播法求 LALR(1) 向前看符号 -> ACTION/GOTO 表
+// 只在 build.rs 里使用，生成的表见 src/parser/constant.rs
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt::{Debug, Write};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum Sym {
+    T(usize),
+    N(usize),
+}
+
+struct Production {
+    lhs: usize,
+    rhs: Vec<Sym>,
+    // 对应 GRAMMAR 里的第几条规则，增广产生式是 None
+    rule: Option<usize>,
+}
+
+pub struct Grammar {
+    // 终结符按第一次出现的顺序排列，最后一个是输入结束 $
+    terminals: Vec<String>,
+    // 最后一个是增广文法的开始符号
+    nonterminals: Vec<String>,
+    prods: Vec<Production>,
+}
+
+// (产生式, 点的位置)
+type Item = (usize, usize);
+
+const ERROR: &str = "error";
+
+fn is_class(word: &str) -> bool {
+    word.chars()
+        .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+}
+
+pub fn parse_grammar(rules: &[&str]) -> Grammar {
+    let mut nonterminals: Vec<String> = Vec::new();
+    let mut alternatives: Vec<(usize, usize, Vec<&str>)> = Vec::new();
+    for (k, rule) in rules.iter().enumerate() {
+        let (lhs, rhs) = rule
+            .split_once("->")
+            .unwrap_or_else(|| panic!("rule {:?}: missing ->", rule));
+        let lhs = lhs.trim();
+        let id = match nonterminals.iter().position(|n| n == lhs) {
+            Some(id) => id,
+            None => {
+                nonterminals.push(lhs.to_string());
+                nonterminals.len() - 1
+            }
+        };
+        let mut words = Vec::new();
+        for word in rhs.split_whitespace() {
+            if word == "|" {
+                alternatives.push((k, id, std::mem::take(&mut words)));
+            } else {
+                words.push(word);
+            }
+        }
+        alternatives.push((k, id, words));
+    }
+    // error 是出错恢复用的特殊终结符，总是排在最前面
+    let mut terminals: Vec<String> = vec![String::from(ERROR)];
+    let mut prods = Vec::new();
+    for (k, lhs, words) in alternatives {
+        let rhs = words
+            .iter()
+            .map(|word| {
+                let name =
+                    if let Some(s) = word.strip_prefix('\'').and_then(|w| w.strip_suffix('\'')) {
+                        s
+                    } else if is_class(word) || *word == ERROR {
+                        word
+                    } else {
+                        let n = nonterminals
+                            .iter()
+                            .position(|n| n == word)
+                            .unwrap_or_else(|| {
+                                panic!("rule {:?}: undefined nonterminal {}", rules[k], word)
+                            });
+                        return Sym::N(n);
+                    };
+                match terminals.iter().position(|t| t == name) {
+                    Some(t) => Sym::T(t),
+                    None => {
+                        terminals.push(name.to_string());
+                        Sym::T(terminals.len() - 1)
+                    }
+                }
+            })
+            .collect();
+        prods.push(Production {
+            lhs,
+            rhs,
+            rule: Some(k),
+        });
+    }
+    terminals.push(String::from("$"));
+    // 增广产生式 $accept -> 开始符号，放在 0 号
+    nonterminals.push(String::from("$accept"));
+    prods.insert(
+        0,
+        Production {
+            lhs: nonterminals.len() - 1,
+            rhs: vec![Sym::N(0)],
+            rule: None,
+        },
+    );
+    Grammar {
+        terminals,
+        nonterminals,
+        prods,
+    }
+}
+
+impl Grammar {
+    fn eof(&self) -> usize {
+        self.terminals.len() - 1
+    }
+
+    fn symbol_name(&self, sym: Sym) -> String {
+        match sym {
+            Sym::T(t) if is_class(&self.terminals[t]) || t == 0 => self.terminals[t].clone(),
+            Sym::T(t) => format!("'{}'", self.terminals[t]),
+            Sym::N(n) => self.nonterminals[n].clone(),
+        }
+    }
+
+    fn item_name(&self, (p, dot): Item) -> String {
+        let prod = &self.prods[p];
+        let mut s = format!("{} ->", self.nonterminals[prod.lhs]);
+        for (k, sym) in prod.rhs.iter().enumerate() {
+            if k == dot {
+                s.push_str(" .");
+            }
+            s.push(' ');
+            s.push_str(&self.symbol_name(*sym));
+        }
+        if dot == prod.rhs.len() {
+            s.push_str(" .");
+        }
+        s
+    }
+
+    fn prods_of(&self, n: usize) -> impl Iterator<Item = usize> + '_ {
+        (0..self.prods.len()).filter(move |&p| self.prods[p].lhs == n)
+    }
+
+    // 每个非终结符能否推出空串，以及它的 FIRST 集
+    fn first_sets(&self) -> (Vec<bool>, Vec<BTreeSet<usize>>) {
+        let n = self.nonterminals.len();
+        let mut nullable = vec![false; n];
+        let mut first = vec![BTreeSet::new(); n];
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for prod in &self.prods {
+                let mut all_nullable = true;
+                for sym in &prod.rhs {
+                    match *sym {
+                        Sym::T(t) => {
+                            changed |= first[prod.lhs].insert(t);
+                            all_nullable = false;
+                        }
+                        Sym::N(m) => {
+                            let add: Vec<usize> = first[m].iter().copied().collect();
+                            for t in add {
+                                changed |= first[prod.lhs].insert(t);
+                            }
+                            all_nullable = nullable[m];
+                        }
+                    }
+                    if !all_nullable {
+                        break;
+                    }
+                }
+                if all_nullable && !nullable[prod.lhs] {
+                    nullable[prod.lhs] = true;
+                    changed = true;
+                }
+            }
+        }
+        (nullable, first)
+    }
+}
+
+struct Closure<'g> {
+    grammar: &'g Grammar,
+    nullable: Vec<bool>,
+    first: Vec<BTreeSet<usize>>,
+}
+
+impl Closure<'_> {
+    fn lr0(&self, kernel: &[Item]) -> BTreeSet<Item> {
+        let mut set: BTreeSet<Item> = kernel.iter().copied().collect();
+        let mut stack: Vec<Item> = kernel.to_vec();
+        while let Some((p, dot)) = stack.pop() {
+            if let Some(Sym::N(n)) = self.grammar.prods[p].rhs.get(dot) {
+                for q in self.grammar.prods_of(*n) {
+                    if set.insert((q, 0)) {
+                        stack.push((q, 0));
+                    }
+                }
+            }
+        }
+        set
+    }
+
+    // LR(1) 闭包，每个项目带一个向前看符号集合
+    fn lr1(&self, kernel: Vec<(Item, BTreeSet<usize>)>) -> BTreeMap<Item, BTreeSet<usize>> {
+        let mut set: BTreeMap<Item, BTreeSet<usize>> = BTreeMap::new();
+        let mut stack = Vec::new();
+        for (item, la) in kernel {
+            set.entry(item).or_default().extend(la);
+            stack.push(item);
+        }
+        while let Some((p, dot)) = stack.pop() {
+            let rhs = &self.grammar.prods[p].rhs;
+            let Some(Sym::N(n)) = rhs.get(dot) else {
+                continue;
+            };
+            // FIRST(β a)
+            let mut la = BTreeSet::new();
+            let mut rest_nullable = true;
+            for sym in &rhs[dot + 1..] {
+                match *sym {
+                    Sym::T(t) => {
+                        la.insert(t);
+                        rest_nullable = false;
+                    }
+                    Sym::N(m) => {
+                        la.extend(self.first[m].iter().copied());
+                        rest_nullable = self.nullable[m];
+                    }
+                }
+                if !rest_nullable {
+                    break;
+                }
+            }
+            if rest_nullable {
+                la.extend(set[&(p, dot)].iter().copied());
+            }
+            for q in self.grammar.prods_of(*n) {
+                let is_new = !set.contains_key(&(q, 0));
+                let entry = set.entry((q, 0)).or_default();
+                let before = entry.len();
+                entry.extend(la.iter().copied());
+                if is_new || entry.len() != before {
+                    stack.push((q, 0));
+                }
+            }
+        }
+        set
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Act {
+    Error,
+    Shift(usize),
+    Reduce(usize),
+    Accept,
+}
+
+pub struct Tables {
+    pub action: Vec<Vec<Act>>,
+    pub goto: Vec<Vec<Option<usize>>>,
+}
+
+pub fn build_tables(grammar: &Grammar) -> Result<Tables, String> {
+    let (nullable, first) = grammar.first_sets();
+    let closure = Closure {
+        grammar,
+        nullable,
+        first,
+    };
+
+    // LR(0) 项集族，状态用核心项目表示
+    let mut kernels: Vec<Vec<Item>> = vec![vec![(0, 0)]];
+    let mut index: HashMap<Vec<Item>, usize> = HashMap::new();
+    index.insert(kernels[0].clone(), 0);
+    let mut trans: Vec<BTreeMap<Sym, usize>> = Vec::new();
+    let mut k = 0;
+    while k < kernels.len() {
+        let mut next: BTreeMap<Sym, Vec<Item>> = BTreeMap::new();
+        for (p, dot) in closure.lr0(&kernels[k]) {
+            if let Some(sym) = grammar.prods[p].rhs.get(dot) {
+                next.entry(*sym).or_default().push((p, dot + 1));
+            }
+        }
+        let mut row = BTreeMap::new();
+        for (sym, mut kernel) in next {
+            kernel.sort();
+            let target = match index.get(&kernel) {
+                Some(&id) => id,
+                None => {
+                    index.insert(kernel.clone(), kernels.len());
+                    kernels.push(kernel);
+                    kernels.len() - 1
+                }
+            };
+            row.insert(sym, target);
+        }
+        trans.push(row);
+        k += 1;
+    }
+
+    // 传播法：用一个不在文法里的符号 # 作向前看，算出自发生成的和需要传播的向前看符号
+    let dummy = grammar.terminals.len();
+    let mut lookahead: Vec<Vec<BTreeSet<usize>>> = kernels
+        .iter()
+        .map(|kernel| vec![BTreeSet::new(); kernel.len()])
+        .collect();
+    lookahead[0][0].insert(grammar.eof());
+    let mut propagate: Vec<((usize, usize), (usize, usize))> = Vec::new();
+    for (s, kernel) in kernels.iter().enumerate() {
+        for (i, item) in kernel.iter().enumerate() {
+            let set = closure.lr1(vec![(*item, BTreeSet::from([dummy]))]);
+            for ((p, dot), la) in set {
+                let Some(sym) = grammar.prods[p].rhs.get(dot) else {
+                    continue;
+                };
+                let t = trans[s][sym];
+                let j = kernels[t].binary_search(&(p, dot + 1)).unwrap();
+                for a in la {
+                    if a == dummy {
+                        propagate.push(((s, i), (t, j)));
+                    } else {
+                        lookahead[t][j].insert(a);
+                    }
+                }
+            }
+        }
+    }
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for &((s, i), (t, j)) in &propagate {
+            let add: Vec<usize> = lookahead[s][i]
+                .difference(&lookahead[t][j])
+                .copied()
+                .collect();
+            if !add.is_empty() {
+                lookahead[t][j].extend(add);
+                changed = true;
+            }
+        }
+    }
+
+    let mut action = vec![vec![Act::Error; grammar.terminals.len()]; kernels.len()];
+    let mut goto = vec![vec![None; grammar.nonterminals.len() - 1]; kernels.len()];
+    let mut report = String::new();
+    for (s, kernel) in kernels.iter().enumerate() {
+        let items = closure.lr1(
+            kernel
+                .iter()
+                .zip(&lookahead[s])
+                .map(|(item, la)| (*item, la.clone()))
+                .collect(),
+        );
+        // 每个格子里的动作以及产生它的项目，用来报告冲突
+        let mut cells: BTreeMap<usize, Vec<(Act, Item)>> = BTreeMap::new();
+        let mut conflicts = String::new();
+        for (&(p, dot), la) in &items {
+            match grammar.prods[p].rhs.get(dot) {
+                Some(Sym::T(t)) => cells
+                    .entry(*t)
+                    .or_default()
+                    .push((Act::Shift(trans[s][&Sym::T(*t)]), (p, dot))),
+                Some(Sym::N(_)) => {}
+                None if p == 0 => cells
+                    .entry(grammar.eof())
+                    .or_default()
+                    .push((Act::Accept, (p, dot))),
+                None => {
+                    for &a in la {
+                        cells.entry(a).or_default().push((Act::Reduce(p), (p, dot)));
+                    }
+                }
+            }
+        }
+        for (t, acts) in cells {
+            let mut kinds: Vec<Act> = Vec::new();
+            for (act, _) in &acts {
+                if !kinds.contains(act) {
+                    kinds.push(*act);
+                }
+            }
+            if kinds.len() > 1 {
+                let what = if kinds.iter().any(|a| matches!(a, Act::Shift(_))) {
+                    "shift/reduce"
+                } else {
+                    "reduce/reduce"
+                };
+                writeln!(
+                    conflicts,
+                    "{} conflict in state {} on {}:",
+                    what,
+                    s,
+                    grammar.symbol_name(Sym::T(t))
+                )
+                .unwrap();
+                for (act, item) in &acts {
+                    let verb = match act {
+                        Act::Shift(_) => "shift ",
+                        _ => "reduce",
+                    };
+                    writeln!(conflicts, "  {} {}", verb, grammar.item_name(*item)).unwrap();
+                }
+            }
+            action[s][t] = kinds[0];
+        }
+        // 冲突后面附上整个项集和每个项目的向前看符号
+        if !conflicts.is_empty() {
+            report.push_str(&conflicts);
+            writeln!(report, "item set of state {}:", s).unwrap();
+            for (item, la) in &items {
+                let la: Vec<String> = la.iter().map(|&a| grammar.symbol_name(Sym::T(a))).collect();
+                writeln!(
+                    report,
+                    "  {}    [{}]",
+                    grammar.item_name(*item),
+                    la.join(" ")
+                )
+                .unwrap();
+            }
+        }
+        for (sym, target) in &trans[s] {
+            if let Sym::N(n) = sym {
+                goto[s][*n] = Some(*target);
+            }
+        }
+    }
+    if report.is_empty() {
+        Ok(Tables { action, goto })
+    } else {
+        Err(report)
+    }
+}
+
+// 输出成 Rust 源码，prods 是 GRAMMAR 里每条规则的名字
+pub fn emit<P: Debug>(grammar: &Grammar, tables: &Tables, prods: &[P]) -> String {
+    let mut out = String::new();
+    let nterm = grammar.terminals.len();
+    // 不输出增广文法的开始符号
+    let nonterms = &grammar.nonterminals[..grammar.nonterminals.len() - 1];
+    let nstate = tables.action.len();
+    writeln!(
+        out,
+        "// 由 build.rs 根据 src/parser/grammar.rs 生成，不要手改"
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "// {} 个终结符，{} 个非终结符，{} 个产生式，{} 个状态",
+        nterm,
+        nonterms.len(),
+        grammar.prods.len() - 1,
+        nstate
+    )
+    .unwrap();
+    writeln!(out, "pub const NTERM: usize = {};", nterm).unwrap();
+    writeln!(out, "pub const NNONTERM: usize = {};", nonterms.len()).unwrap();
+    writeln!(out, "pub const NSTATE: usize = {};", nstate).unwrap();
+    writeln!(out, "pub const ERROR: usize = 0;").unwrap();
+    writeln!(out, "pub const EOF: usize = {};", grammar.eof()).unwrap();
+
+    // 终结符的写法，下标就是 ACTION 表的列号
+    writeln!(
+        out,
+        "pub static TERMINALS: [&str; NTERM] = {:?};",
+        grammar.terminals
+    )
+    .unwrap();
+    writeln!(out, "pub fn terminal(name: &str) -> Option<usize> {{").unwrap();
+    writeln!(out, "    match name {{").unwrap();
+    for (t, name) in grammar.terminals.iter().enumerate() {
+        writeln!(out, "        {:?} => Some({}),", name, t).unwrap();
+    }
+    writeln!(out, "        _ => None,\n    }}\n}}").unwrap();
+
+    writeln!(out, "#[derive(Debug, Clone, Copy, PartialEq, Eq)]").unwrap();
+    writeln!(out, "pub enum NonTerm {{").unwrap();
+    for name in nonterms {
+        writeln!(out, "    {},", name).unwrap();
+    }
+    writeln!(out, "}}").unwrap();
+
+    // 每个非终结符能以哪些终结符开头，报错时用来把一串终结符合并成“表达式”之类的说法
+    let (_, first) = grammar.first_sets();
+    writeln!(out, "pub static FIRST: [&[usize]; NNONTERM] = [").unwrap();
+    for set in &first[..nonterms.len()] {
+        let terms: Vec<usize> = set.iter().copied().filter(|&t| t != 0).collect();
+        writeln!(out, "    &{:?},", terms).unwrap();
+    }
+    writeln!(out, "];").unwrap();
+
+    // 规约时用到的左部和右部长度；一个 Prod 的几个候选长度必须相同
+    let mut info: Vec<Option<(usize, usize)>> = vec![None; prods.len()];
+    for prod in &grammar.prods[1..] {
+        let k = prod.rule.unwrap();
+        let this = (prod.lhs, prod.rhs.len());
+        if let Some(prev) = info[k]
+            && prev != this
+        {
+            panic!("alternatives of {:?} have different lengths", prods[k]);
+        }
+        info[k] = Some(this);
+    }
+    writeln!(out, "impl Prod {{").unwrap();
+    writeln!(
+        out,
+        "    pub fn lhs(self) -> NonTerm {{\n        match self {{"
+    )
+    .unwrap();
+    for (k, p) in prods.iter().enumerate() {
+        let (lhs, _) = info[k].unwrap();
+        writeln!(
+            out,
+            "            Prod::{:?} => NonTerm::{},",
+            p, nonterms[lhs]
+        )
+        .unwrap();
+    }
+    writeln!(out, "        }}\n    }}").unwrap();
+    writeln!(
+        out,
+        "    pub fn rhs_len(self) -> usize {{\n        match self {{"
+    )
+    .unwrap();
+    for (k, p) in prods.iter().enumerate() {
+        let (_, len) = info[k].unwrap();
+        writeln!(out, "            Prod::{:?} => {},", p, len).unwrap();
+    }
+    writeln!(out, "        }}\n    }}\n}}").unwrap();
+
+    writeln!(out, "pub static ACTION: [[Action; NTERM]; NSTATE] = [").unwrap();
+    for row in &tables.action {
+        let cells: Vec<String> = row
+            .iter()
+            .map(|act| match act {
+                Act::Error => String::from("Action::Error"),
+                Act::Accept => String::from("Action::Accept"),
+                Act::Shift(s) => format!("Action::Shift(State({}))", s),
+                Act::Reduce(p) => format!(
+                    "Action::Reduce(Prod::{:?})",
+                    prods[grammar.prods[*p].rule.unwrap()]
+                ),
+            })
+            .collect();
+        writeln!(out, "    [{}],", cells.join(", ")).unwrap();
+    }
+    writeln!(out, "];").unwrap();
+
+    writeln!(
+        out,
+        "pub static GOTO: [[Option<State>; NNONTERM]; NSTATE] = ["
+    )
+    .unwrap();
+    for row in &tables.goto {
+        let cells: Vec<String> = row
+            .iter()
+            .map(|g| match g {
+                Some(s) => format!("Some(State({}))", s),
+                None => String::from("None"),
+            })
+            .collect();
+        writeln!(out, "    [{}],", cells.join(", ")).unwrap();
+    }
+    writeln!(out, "];").unwrap();
+    out
+}
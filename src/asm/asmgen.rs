@@ -1,5 +1,8 @@
 use crate::ir::irgen::IrType;
-use std::collections::HashMap;
+use crate::preprocessor::helper::quote_file_name;
+use crate::utils::session::file_name;
+use crate::utils::types::Span;
+use std::collections::{HashMap, HashSet};
 
 fn is_hexadecimal(s: &str) -> bool {
     if let Some(rest) = s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
@@ -12,14 +15,116 @@ fn is_decimal(s: &str) -> bool {
     s.parse::<i64>().is_ok()
 }
 
+// 能直接当 I 型指令立即数的常量：andi/ori/xori 是 12 位有符号数，移位量是 0 到 31
+fn imm_operand(op: &str, value: &str) -> bool {
+    match value.parse::<i64>() {
+        Ok(v) if matches!(op, "<<" | ">>" | ">>>") => (0..32).contains(&v),
+        Ok(v) => (-2048..2048).contains(&v),
+        Err(_) => false,
+    }
+}
+
+// 读写 sp 加偏移处的内存，偏移超出 12 位立即数时先把地址算到 addr 里
+fn stack_access(asm: &mut String, op: &str, reg: &str, off: usize, addr: &str) {
+    if off < 2048 {
+        *asm += &format!("    {} {}, {}(sp)\n", op, reg, off);
+    } else {
+        *asm += &format!("    li {}, {}\n", addr, off);
+        *asm += &format!("    add {}, sp, {}\n", addr, addr);
+        *asm += &format!("    {} {}, 0({})\n", op, reg, addr);
+    }
+}
+
+// sp 加上或减去栈帧大小，超出 12 位立即数时借 t0 装进去
+fn adjust_sp(asm: &mut String, delta: i64) {
+    if (-2048..2048).contains(&delta) {
+        *asm += &format!("    addi sp, sp, {}\n", delta);
+    } else {
+        *asm += &format!("    li t0, {}\n", delta);
+        *asm += "    add sp, sp, t0\n";
+    }
+}
+
+// 变量放在哪：寄存器、栈上相对 sp 的偏移或者数据段里的标签
+struct Regs {
+    reg_map: HashMap<String, String>,
+    slots: HashMap<String, usize>,
+    globals: HashSet<String>,
+    // 在内存里的一字节变量按字节读写，和通过指针的读写一致
+    bytes: HashSet<String>,
+}
+
+impl Regs {
+    // 常量和栈上的变量先装进 scratch 寄存器，寄存器里的变量直接用
+    fn operand(&self, asm: &mut String, value: &str, scratch: &str) -> String {
+        if is_decimal(value) {
+            *asm += &format!("    li {}, {}\n", scratch, value);
+            scratch.to_string()
+        } else if let Some(off) = self.slots.get(value) {
+            let op = if self.bytes.contains(value) {
+                "lb"
+            } else {
+                "lw"
+            };
+            stack_access(asm, op, scratch, *off, scratch);
+            scratch.to_string()
+        } else if self.globals.contains(value) {
+            let op = if self.bytes.contains(value) {
+                "lb"
+            } else {
+                "lw"
+            };
+            *asm += &format!("    la {}, {}\n", scratch, value);
+            *asm += &format!("    {} {}, 0({})\n", op, scratch, scratch);
+            scratch.to_string()
+        } else {
+            self.reg_map.get(value).unwrap().clone()
+        }
+    }
+
+    // 写结果用的寄存器，栈上和数据段里的变量先写到 t0
+    fn dest(&self, name: &str) -> String {
+        match self.reg_map.get(name) {
+            Some(reg) => reg.clone(),
+            None => String::from("t0"),
+        }
+    }
+
+    fn spill(&self, asm: &mut String, name: &str) {
+        if let Some(off) = self.slots.get(name) {
+            let op = if self.bytes.contains(name) {
+                "sb"
+            } else {
+                "sw"
+            };
+            stack_access(asm, op, "t0", *off, "t1");
+        } else if self.globals.contains(name) {
+            let op = if self.bytes.contains(name) {
+                "sb"
+            } else {
+                "sw"
+            };
+            *asm += &format!("    la t1, {}\n", name);
+            *asm += &format!("    {} t0, 0(t1)\n", op);
+        }
+    }
+}
+
 pub fn asm_gen(irs: &[IrType]) -> String {
     let mut asm = String::new();
     let mut functions = Vec::new();
     let mut i = 0;
+    // 全局变量的名字和大小，包括 extern 声明的在别的文件里分配的变量
+    let globals: Vec<(String, usize)> = irs
+        .iter()
+        .filter(|ir| ir.op == "GLOBAL" || ir.op == "EXTERN")
+        .map(|ir| (ir.src1.clone(), ir.src2.parse().unwrap()))
+        .collect();
 
     while i < irs.len() {
         if irs[i].op == "FUNC" {
             let func_name = irs[i].src1.clone();
+            let is_static = irs[i].src2 == "static";
             let mut params = Vec::new();
             let mut locals = Vec::new();
             let mut body = Vec::new();
@@ -33,12 +138,21 @@ pub fn asm_gen(irs: &[IrType]) -> String {
                         params.push(ir.src1.clone());
                     }
                 } else {
-                    for var in [&ir.rd, &ir.src1, &ir.src2] {
+                    // 跳转的目标和被调用的函数名不是变量
+                    let vars = match ir.op.as_str() {
+                        "LABEL" | "JMP" => vec![],
+                        "JZ" | "JNZ" | "ARG" | "RET" | "JTABLE" => vec![&ir.src1],
+                        "TABLE" => vec![],
+                        "CALL" => vec![&ir.rd],
+                        _ => vec![&ir.rd, &ir.src1, &ir.src2],
+                    };
+                    for var in vars {
                         if !var.is_empty()
                             && !is_decimal(var)
                             && !is_hexadecimal(var)
                             && !params.contains(var)
                             && !locals.contains(var)
+                            && !globals.iter().any(|(name, _)| name == var)
                         {
                             locals.push(var.clone());
                         }
@@ -46,150 +160,357 @@ pub fn asm_gen(irs: &[IrType]) -> String {
                 }
                 i += 1;
             }
-            functions.push((func_name, params, locals, body));
+            functions.push((func_name, is_static, params, locals, body));
         }
         i += 1;
     }
 
+    // 调试信息：用到的源文件
+    let mut files: Vec<u32> = irs
+        .iter()
+        .map(|ir| ir.span.file)
+        .filter(|f| *f != 0)
+        .collect();
+    files.sort();
+    files.dedup();
+    for file in files {
+        asm += &format!(".file {} {}\n", file, quote_file_name(&file_name(file)));
+    }
     asm += ".section .text\n";
-    asm += ".globl _start\n";
-    asm += "_start:\n";
-    asm += "    li sp, 0x80009000\n";
-    asm += "    call main\n";
-    asm += "    ebreak\n";
-
-    for (func_name, params, locals, body) in functions {
-        let mut reg_map: HashMap<String, String> = HashMap::new();
-        let mut stack_offset = 0;
-        let mut reg_idx = 0;
-        let offset = (locals.len() * 4 + 15) / 16 * 16;
-        let mut off = offset - 4;
+    // 多个文件链接在一起时，只有定义了 main 的文件带启动代码
+    if functions.iter().any(|(name, ..)| name == "main") {
+        asm += ".globl _start\n";
+        asm += "_start:\n";
+        asm += "    li sp, 0x80009000\n";
+        asm += "    call main\n";
+        asm += "    ebreak\n";
+    }
+
+    // switch 的跳转表，(表的标签, 每一项跳到的标签)
+    let mut tables: Vec<(String, Vec<String>)> = Vec::new();
+    for (func_name, is_static, params, locals, body) in functions {
         let all_regs = [
             "t2", "t3", "t4", "t5", "t6", "s0", "s1", "s2", "s3", "s4", "s5", "s6", "s7", "s8",
             "s9", "s10", "s11",
         ];
+        let names: Vec<&String> = params.iter().chain(locals.iter()).collect();
+        // 取过地址的变量（包括数组）必须在内存里，每次读写都经过栈，ADDR 的 src2 是它占的字节数
+        let addressed: HashMap<&String, usize> = body
+            .iter()
+            .filter(|ir| ir.op == "ADDR")
+            .map(|ir| (&ir.src1, ir.src2.parse().unwrap()))
+            .collect();
+        let mut reg_names: Vec<&String> = Vec::new();
+        let mut slot_names: Vec<(&String, usize)> = Vec::new();
+        for name in &names {
+            match addressed.get(name) {
+                Some(size) => slot_names.push((name, size.div_ceil(4) * 4)),
+                None if reg_names.len() < all_regs.len() => reg_names.push(name),
+                None => slot_names.push((name, 4)),
+            }
+        }
+        // 栈帧从高到低依次是 ra、放在栈上的变量、调用函数时保存寄存器的区域
+        let slot_size: usize = slot_names.iter().map(|(_, size)| size).sum();
+        let offset = (4 + slot_size + 4 * reg_names.len()).div_ceil(16) * 16;
+        let mut regs = Regs {
+            reg_map: HashMap::new(),
+            slots: HashMap::new(),
+            globals: globals.iter().map(|(name, _)| name.clone()).collect(),
+            bytes: body
+                .iter()
+                .filter(|ir| ir.op == "ADDR" && ir.src2 == "1")
+                .map(|ir| ir.src1.clone())
+                .chain(
+                    globals
+                        .iter()
+                        .filter(|(_, size)| *size == 1)
+                        .map(|(name, _)| name.clone()),
+                )
+                .collect(),
+        };
+        for (k, name) in reg_names.iter().enumerate() {
+            regs.reg_map
+                .insert(name.to_string(), all_regs[k].to_string());
+        }
+        let mut top = offset - 4;
+        for (name, size) in &slot_names {
+            top -= size;
+            regs.slots.insert(name.to_string(), top);
+        }
+        let mut arg_idx = 0;
 
+        // static 函数只在这个文件里可见，其他的导出给别的文件调用
+        if !is_static {
+            asm += &format!(".globl {}\n", func_name);
+        }
         asm += &format!("{}:\n", func_name);
-        asm += &format!("    addi sp, sp, -{}\n", offset);
-        asm += &format!("    sw ra, {}(sp)\n", off);
+        adjust_sp(&mut asm, -(offset as i64));
+        stack_access(&mut asm, "sw", "ra", offset - 4, "t0");
 
         for (i, param) in params.iter().enumerate() {
-            let preg = format!("a{}", i);
-            let reg = all_regs[reg_idx % all_regs.len()];
-            reg_map.insert(param.clone(), reg.to_string());
-            asm += &format!("    mv {}, {}\n", reg, preg);
-            reg_idx += 1;
-        }
-
-        for local in locals.clone() {
-            if !reg_map.contains_key(&local) {
-                let reg = all_regs[reg_idx % all_regs.len()];
-                reg_map.insert(local.clone(), reg.to_string());
-                reg_idx += 1;
+            match regs.reg_map.get(param) {
+                Some(reg) => asm += &format!("    mv {}, a{}\n", reg, i),
+                None => {
+                    let op = if regs.bytes.contains(param) {
+                        "sb"
+                    } else {
+                        "sw"
+                    };
+                    stack_access(&mut asm, op, &format!("a{}", i), regs.slots[param], "t0");
+                }
             }
         }
 
+        let mut last_loc = Span::default();
         for ir in body {
+            // 源码位置变化时输出 .loc，汇编和调试器可以对应回源码
+            // PARAM 和 LABEL 不产生指令，不需要位置
+            if ir.span.file != 0
+                && !matches!(ir.op.as_str(), "PARAM" | "LABEL" | "TABLE")
+                && (ir.span.file, ir.span.line, ir.span.col)
+                    != (last_loc.file, last_loc.line, last_loc.col)
+            {
+                asm += &format!(
+                    "    .loc {} {} {}\n",
+                    ir.span.file, ir.span.line, ir.span.col
+                );
+                last_loc = ir.span;
+            }
+            // 结果放在栈上的变量先算到 t0 里，最后再写回去
+            let dst = regs.dest(&ir.rd);
             match ir.op.as_str() {
-                "MOV" => {
-                    let dst = reg_map.get(&ir.rd).unwrap();
+                "MOV" | "=" => {
                     if is_decimal(&ir.src1) {
                         asm += &format!("    li {}, {}\n", dst, ir.src1);
                     } else {
-                        let src = reg_map.get(&ir.src1).unwrap();
+                        let src = regs.operand(&mut asm, &ir.src1, "t0");
                         asm += &format!("    mv {}, {}\n", dst, src);
                     }
                 }
-                "+" | "-" | "*" | "/" => {
+                // 一元运算 src2 为空
+                "!" | "-" | "~" if ir.src2.is_empty() => {
+                    let src = regs.operand(&mut asm, &ir.src1, "t0");
+                    let op = match ir.op.as_str() {
+                        "!" => "seqz",
+                        "-" => "neg",
+                        _ => "not",
+                    };
+                    asm += &format!("    {} {}, {}\n", op, dst, src);
+                }
+                // 第二个操作数是放得进立即数字段的常量时用 I 型指令
+                "&" | "^" | "|" | "<<" | ">>" | ">>>" if imm_operand(&ir.op, &ir.src2) => {
+                    let op = match ir.op.as_str() {
+                        "&" => "andi",
+                        "^" => "xori",
+                        "|" => "ori",
+                        "<<" => "slli",
+                        ">>" => "srai",
+                        _ => "srli",
+                    };
+                    let src1 = regs.operand(&mut asm, &ir.src1, "t0");
+                    asm += &format!("    {} {}, {}, {}\n", op, dst, src1, ir.src2);
+                }
+                "+" | "-" | "*" | "/" | "%" | "<<" | ">>" | ">>>" | "&" | "^" | "|" => {
                     let op = match ir.op.as_str() {
                         "+" => "add",
                         "-" => "sub",
                         "*" => "mul",
                         "/" => "div",
+                        "%" => "rem",
+                        "<<" => "sll",
+                        ">>" => "sra",
+                        ">>>" => "srl",
+                        "&" => "and",
+                        "^" => "xor",
+                        "|" => "or",
                         _ => unreachable!(),
                     };
-                    let dst = reg_map.get(&ir.rd).unwrap();
-                    let src1 = if is_decimal(&ir.src1) {
-                        asm += "    li t0, ";
-                        asm += &ir.src1;
-                        asm += "\n";
-                        "t0"
-                    } else {
-                        reg_map.get(&ir.src1).unwrap()
-                    };
-                    let src2 = if is_decimal(&ir.src2) {
-                        asm += "    li t1, ";
-                        asm += &ir.src2;
-                        asm += "\n";
-                        "t1"
-                    } else {
-                        reg_map.get(&ir.src2).unwrap()
-                    };
+                    let src1 = regs.operand(&mut asm, &ir.src1, "t0");
+                    let src2 = regs.operand(&mut asm, &ir.src2, "t1");
                     asm += &format!("    {} {}, {}, {}\n", op, dst, src1, src2);
                 }
+                // 栈上变量的地址，全局变量用标签
+                "ADDR" => match regs.slots.get(&ir.src1) {
+                    Some(off) if *off < 2048 => asm += &format!("    addi {}, sp, {}\n", dst, off),
+                    Some(off) => {
+                        asm += &format!("    li {}, {}\n", dst, off);
+                        asm += &format!("    add {}, sp, {}\n", dst, dst);
+                    }
+                    None => asm += &format!("    la {}, {}\n", dst, ir.src1),
+                },
+                "LOAD" | "LOADB" => {
+                    let addr = regs.operand(&mut asm, &ir.src1, "t0");
+                    let op = if ir.op == "LOAD" { "lw" } else { "lb" };
+                    asm += &format!("    {} {}, 0({})\n", op, dst, addr);
+                }
+                // src1 是要写的值，src2 是地址
+                "STORE" | "STOREB" => {
+                    let value = regs.operand(&mut asm, &ir.src1, "t0");
+                    let addr = regs.operand(&mut asm, &ir.src2, "t1");
+                    let op = if ir.op == "STORE" { "sw" } else { "sb" };
+                    asm += &format!("    {} {}, 0({})\n", op, value, addr);
+                }
                 "RET" => {
                     if !ir.src1.is_empty() {
                         if is_decimal(&ir.src1) {
                             asm += &format!("    li a0, {}\n", ir.src1);
                         } else {
-                            let r = reg_map.get(&ir.src1).unwrap();
+                            let r = regs.operand(&mut asm, &ir.src1, "t0");
                             asm += &format!("    mv a0, {}\n", r);
                         };
                     }
-                    asm += &format!("    lw ra, {}(sp)\n", offset - 4);
-                    asm += &format!("    addi sp, sp, {}\n", offset);
+                    stack_access(&mut asm, "lw", "ra", offset - 4, "t0");
+                    adjust_sp(&mut asm, offset as i64);
                     asm += "    ret\n";
                 }
                 "CALL" => {
-                    for local in locals.clone() {
-                        let reg_name = reg_map.get(&local).unwrap();
-                        off -= 4;
-                        asm += &format!("    sw {}, {}(sp)\n", reg_name, off);
+                    // 被调用的函数不保存寄存器，调用前把用到的寄存器都存到栈帧底部
+                    for (k, name) in reg_names.iter().enumerate() {
+                        asm += &format!("    sw {}, {}(sp)\n", regs.reg_map[*name], 4 * k);
                     }
                     asm += &format!("    call {}\n", ir.src1);
-                    for local in locals.clone().iter().rev() {
-                        let reg_name = reg_map.get(local).unwrap();
-                        asm += &format!("    lw {}, {}(sp)\n", reg_name, off);
-                        off += 4;
+                    for (k, name) in reg_names.iter().enumerate().rev() {
+                        asm += &format!("    lw {}, {}(sp)\n", regs.reg_map[*name], 4 * k);
                     }
                     if !ir.rd.is_empty() {
-                        let dst = reg_map.get(&ir.rd).unwrap();
                         asm += &format!("    mv {}, a0\n", dst);
                     }
+                    arg_idx = 0;
                 }
                 "ARG" => {
-                    let idx = stack_offset;
-                    let src = reg_map.get(&ir.src1).unwrap();
-                    asm += &format!("    mv a{}, {}\n", idx, src);
-                    stack_offset += 1;
+                    let areg = format!("a{}", arg_idx);
+                    let src = regs.operand(&mut asm, &ir.src1, &areg);
+                    if src != areg {
+                        asm += &format!("    mv {}, {}\n", areg, src);
+                    }
+                    arg_idx += 1;
                 }
                 "JMP" => {
                     asm += &format!("    j {}\n", ir.rd);
                 }
                 "JNZ" => {
-                    let cond = reg_map.get(&ir.src1).unwrap();
+                    let cond = regs.operand(&mut asm, &ir.src1, "t0");
                     asm += &format!("    bnez {}, {}\n", cond, ir.rd);
                 }
+                "JZ" => {
+                    let cond = regs.operand(&mut asm, &ir.src1, "t0");
+                    asm += &format!("    beqz {}, {}\n", cond, ir.rd);
+                }
                 "LABEL" => {
                     asm += &format!("{}:\n", ir.src1);
                 }
+                "JTABLE" => {
+                    let index = regs.operand(&mut asm, &ir.src1, "t0");
+                    asm += &format!("    la t1, {}\n", ir.rd);
+                    asm += &format!("    slli t0, {}, 2\n", index);
+                    asm += "    add t1, t1, t0\n";
+                    asm += "    lw t1, 0(t1)\n";
+                    asm += "    jr t1\n";
+                }
+                "TABLE" => match tables.last_mut() {
+                    Some((table, targets)) if *table == ir.rd => targets.push(ir.src1.clone()),
+                    _ => tables.push((ir.rd.clone(), vec![ir.src1.clone()])),
+                },
                 "<" => {
-                    let dst = reg_map.get(&ir.rd).unwrap();
-                    let left = reg_map.get(&ir.src1).unwrap();
+                    let left = regs.operand(&mut asm, &ir.src1, "t0");
                     if is_decimal(&ir.src2) {
                         asm += &format!("    slti {}, {}, {}\n", dst, left, ir.src2);
                     } else {
-                        let right = reg_map.get(&ir.src2).unwrap();
+                        let right = regs.operand(&mut asm, &ir.src2, "t1");
                         asm += &format!("    slt {}, {}, {}\n", dst, left, right);
                     }
                 }
-                "=" => {
-                    let src = reg_map.get(&ir.src1).unwrap();
-                    let rd = reg_map.get(&ir.rd).unwrap();
-                    asm += &format!("    mv {}, {}\n", rd, src);
+                "==" | "!=" | ">" | "<=" | ">=" | "&&" | "||" => {
+                    let left = regs.operand(&mut asm, &ir.src1, "t0");
+                    let right = regs.operand(&mut asm, &ir.src2, "t1");
+                    match ir.op.as_str() {
+                        "==" | "!=" => {
+                            asm += &format!("    xor {}, {}, {}\n", dst, left, right);
+                            let set = if ir.op == "==" { "seqz" } else { "snez" };
+                            asm += &format!("    {} {}, {}\n", set, dst, dst);
+                        }
+                        ">" => asm += &format!("    slt {}, {}, {}\n", dst, right, left),
+                        "<=" => {
+                            asm += &format!("    slt {}, {}, {}\n", dst, right, left);
+                            asm += &format!("    xori {}, {}, 1\n", dst, dst);
+                        }
+                        ">=" => {
+                            asm += &format!("    slt {}, {}, {}\n", dst, left, right);
+                            asm += &format!("    xori {}, {}, 1\n", dst, dst);
+                        }
+                        // 两边都已经求过值，只需要把结果规整成 0/1
+                        "&&" => {
+                            asm += &format!("    snez t0, {}\n", left);
+                            asm += &format!("    snez t1, {}\n", right);
+                            asm += &format!("    and {}, t0, t1\n", dst);
+                        }
+                        _ => {
+                            asm += &format!("    or {}, {}, {}\n", dst, left, right);
+                            asm += &format!("    snez {}, {}\n", dst, dst);
+                        }
+                    }
                 }
                 _ => {}
             }
+            if !matches!(
+                ir.op.as_str(),
+                "JMP"
+                    | "JZ"
+                    | "JNZ"
+                    | "LABEL"
+                    | "RET"
+                    | "ARG"
+                    | "JTABLE"
+                    | "TABLE"
+                    | "STORE"
+                    | "STOREB"
+            ) {
+                regs.spill(&mut asm, &ir.rd);
+            }
+        }
+    }
+    if !tables.is_empty() {
+        asm += ".section .rodata\n";
+        asm += ".align 2\n";
+        for (table, targets) in tables {
+            asm += &format!("{}:\n", table);
+            for target in targets {
+                asm += &format!("    .word {}\n", target);
+            }
+        }
+    }
+    // 有初始值的全局变量放在 .data，其他的初始化为 0，放在 .bss，extern 声明的不在这里分配
+    let mut inits: HashMap<&String, Vec<&IrType>> = HashMap::new();
+    for ir in irs.iter().filter(|ir| ir.op == "INIT") {
+        inits.entry(&ir.src1).or_default().push(ir);
+    }
+    let (data, bss): (Vec<&IrType>, Vec<&IrType>) = irs
+        .iter()
+        .filter(|ir| ir.op == "GLOBAL")
+        .partition(|ir| inits.contains_key(&ir.src1));
+    for (section, defined) in [(".data", data), (".bss", bss)] {
+        if defined.is_empty() {
+            continue;
+        }
+        asm += &format!(".section {}\n", section);
+        for ir in defined {
+            let (name, size) = (&ir.src1, &ir.src2);
+            if ir.rd != "static" {
+                asm += &format!(".globl {}\n", name);
+            }
+            asm += ".align 2\n";
+            asm += &format!("{}:\n", name);
+            // 初始值按元素的宽度写，后面没写的补 0
+            let mut used = 0;
+            for init in inits.get(name).into_iter().flatten() {
+                let directive = if init.rd == "1" { ".byte" } else { ".word" };
+                asm += &format!("    {} {}\n", directive, init.src2);
+                used += init.rd.parse::<usize>().unwrap_or(4);
+            }
+            let size = size.parse::<usize>().unwrap_or(0);
+            if used < size {
+                asm += &format!("    .zero {}\n", size - used);
+            }
         }
     }
     asm
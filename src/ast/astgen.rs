@@ -1,11 +1,19 @@
 use super::{
     helper::{
-        build_symbol_table, check_entry_func, check_types, get_current_func, get_current_scope_num,
-        print_symbol_table, set_current_func, update_current_scope_num,
+        GlobalDecl, add_case_label, build_symbol_table, check_labels, check_types, complete_struct,
+        conditional_type, decay, declare_global, define_label, define_struct, element_type,
+        enter_loop, enter_switch, get_current_func, get_current_scope_num, global_decl, in_loop,
+        in_switch, is_integer, is_lvalue, is_null_pointer_pair, is_unsigned, leave_loop,
+        leave_switch, lookup_struct, print_symbol_table, remove_scope_structs,
+        remove_scope_symbols, semantic_error, semantic_error_with_notes, semantic_warning,
+        set_current_func, string_label, struct_layout, type_name, type_size,
+        update_current_scope_num, use_label,
     },
-    types::{ASTNode, StructType, Type, VarDec},
+    types::{ASTNode, StructType, Type, VarDec, int_value},
 };
-use crate::{parser::parse::CSTNode, utils::helper::symbol_is_literal};
+use crate::lexer::lexer::Token;
+use crate::parser::parse::CSTNode;
+use crate::utils::types::{NumType, Span, TokenKind};
 use std::vec;
 
 pub fn ast_gen(cst: &Vec<CSTNode>) -> Vec<ASTNode> {
@@ -14,10 +22,6 @@ pub fn ast_gen(cst: &Vec<CSTNode>) -> Vec<ASTNode> {
         let ast_node = ASTNode::from_cst(node);
         ast.push(ast_node.optimal());
     }
-    if !check_entry_func() {
-        println!("No entry function main is defined");
-        unreachable!()
-    }
     print_symbol_table();
     ast
 }
@@ -25,12 +29,13 @@ pub fn ast_gen(cst: &Vec<CSTNode>) -> Vec<ASTNode> {
 impl ASTNode {
     pub fn from_cst(node: &CSTNode) -> ASTNode {
         match node {
-            CSTNode::Program(boxed) => {
+            CSTNode::Program(boxed, _) => {
                 let mut items = Vec::new();
                 collect_extdefs(boxed, &mut items);
-                ASTNode::Program { items }
+                ASTNode::Program { items, span: node.span() }
             }
             CSTNode::ExtDef {
+                storage,
                 spec,
                 fun_dec,
                 compst,
@@ -38,31 +43,44 @@ impl ASTNode {
                 ..
                 // sepa, 分隔符号是否需要记录到 ast 中
             } => {
+                let storage = extract_storage(storage, node.span());
                 let ret_type = extract_spec(spec);
                 match (fun_dec, compst, ext_dec_list) {
                     (Some(func), Some(compst), None) => {
-                        let (name, params) = extract_fundec(func);
-                        let var_info = VarDec {
-                            var_type: ret_type.clone(),
-                            var_name: name.clone(),
-                            init: None
-                        };
-                        build_symbol_table(get_current_func(),&var_info, get_current_scope_num(), Some(params.clone()));
+                        let (name, params, ret_type) = extract_fundec(func, ret_type.clone());
+                        declare_function(&name, &params, &ret_type, storage.as_deref(), true, func.span());
+                        // 形参只在函数体里可见，原型里的形参不进符号表
+                        // 重复定义的函数已经报过错，清掉前一个函数体里的名字接着检查
+                        remove_scope_symbols(name.clone(), 1);
+                        for param in &params {
+                            if param.var_name.is_empty() {
+                                semantic_error(param.span, String::from("parameter name omitted"));
+                            } else {
+                                build_symbol_table(get_current_func(), param, get_current_scope_num() + 1, None);
+                            }
+                        }
                         let body_block = ASTNode::from_cst(compst);
+                        check_labels();
                         ASTNode::FuncDef {
                             name,
                             params,
                             ret_type,
                             body: Box::new(body_block),
+                            span: node.span(),
                         }
                     }
+                    (Some(func), None, None) => {
+                        let (name, params, ret_type) = extract_fundec(func, ret_type);
+                        declare_function(&name, &params, &ret_type, storage.as_deref(), false, func.span());
+                        ASTNode::FuncDecl { name, params, ret_type, span: node.span() }
+                    }
                     (None, None, Some(list)) => {
                         let mut items = Vec::new();
-                        collect_extdecs(ret_type.clone(),list, &mut items);
-                        ASTNode::VarDecl(items)
+                        collect_extdecs(ret_type.clone(), storage.as_deref(), list, &mut items);
+                        ASTNode::VarDecl(items, node.span())
                     }
                     (None, None, None) => {
-                        ASTNode::Specifier(Box::new(ret_type))
+                        ASTNode::Specifier(Box::new(ret_type), node.span())
                     }
                     _ => unreachable!()
                 }
@@ -77,11 +95,12 @@ impl ASTNode {
                 if let Some(defs) = def_list {
                     let mut list = Vec::new();
                     collect_defs(defs, &mut list);
-                    stmts.push(ASTNode::VarDecl(list));
+                    stmts.push(ASTNode::VarDecl(list, defs.span()));
                 }
                 collect_stmts(stmt_list, &mut stmts);
+                remove_scope_structs(get_current_scope_num());
                 update_current_scope_num(get_current_scope_num()-1);
-                ASTNode::Block { stmts }
+                ASTNode::Block { stmts, span: node.span() }
             }
             CSTNode::MatchedStmt {
                 if_stmt,
@@ -93,18 +112,25 @@ impl ASTNode {
                 ..
             } => {
                 let cond = expression.as_ref().map(|e| Box::new(ASTNode::from_cst(e)));
+                // while 的循环体也放在 matched_stmt_fore 里
+                if while_stmt.is_some() {
+                    enter_loop();
+                }
                 let then_br = matched_stmt_fore.as_ref().map(|e| Box::new(ASTNode::from_cst(e)));
+                if while_stmt.is_some() {
+                    leave_loop();
+                }
                 let else_br = matched_stmt_back.as_ref().map(|e| Box::new(ASTNode::from_cst(e)));
                 match (if_stmt, while_stmt, normal_stmt) {
                     (Some(_), None, None) => {
                         let cond_ = cond.unwrap();
                         let then_br_ = then_br.unwrap();
-                        ASTNode::If { cond: cond_, then_br: then_br_, else_br }
+                        ASTNode::If { cond: cond_, then_br: then_br_, else_br, span: node.span() }
                     }
                     (None, Some(_), None) => {
                         let cond_ = cond.unwrap();
                         let body_ = then_br.unwrap();
-                        ASTNode::While { cond: cond_, body: body_ }
+                        ASTNode::While { cond: cond_, body: body_, span: node.span() }
                     }
                     (None, None, Some(stmt)) => {
                         ASTNode::from_cst(stmt)
@@ -127,28 +153,112 @@ impl ASTNode {
             } => {
                 let cond = expression.as_ref().map(|e| Box::new(ASTNode::from_cst(e)));
                 if while_stmt.is_some() {
+                    enter_loop();
                     let body = ASTNode::from_cst(unmatched_stmt.clone().unwrap().as_ref());
-                    ASTNode::While { cond: cond.unwrap(), body: Box::new(body) }
+                    leave_loop();
+                    ASTNode::While { cond: cond.unwrap(), body: Box::new(body), span: node.span() }
                 } else if if_stmt.is_some() && else_stmt.is_some() {
                     let then_br = matched_stmt.as_ref().map(|e| Box::new(ASTNode::from_cst(e)));
                     let else_br = unmatched_stmt.as_ref().map(|e| Box::new(ASTNode::from_cst(e)));
-                    ASTNode::If { cond: cond.unwrap(), then_br: then_br.unwrap(), else_br }
+                    ASTNode::If { cond: cond.unwrap(), then_br: then_br.unwrap(), else_br, span: node.span() }
                 } else if if_stmt.is_some() && !else_stmt.is_some() {
                     let then_br = stmt.as_ref().map(|e| Box::new(ASTNode::from_cst(e)));
-                    ASTNode::If { cond: cond.unwrap(), then_br: then_br.unwrap(), else_br: None }
+                    ASTNode::If { cond: cond.unwrap(), then_br: then_br.unwrap(), else_br: None, span: node.span() }
                 } else {
                     unreachable!()
                 }
             },
+            CSTNode::ForStmt { for_init, cond, step, body, .. } => {
+                // 第一部分声明的变量只在循环里可见
+                update_current_scope_num(get_current_scope_num()+1);
+                let init = match for_init.as_ref() {
+                    CSTNode::ForInit { def: Some(def), .. } => Some(Box::new(ASTNode::from_cst(def))),
+                    CSTNode::ForInit { expression: Some(expr), .. } => Some(Box::new(ASTNode::from_cst(expr))),
+                    _ => None,
+                };
+                let cond = extract_for_expr(cond);
+                let step = extract_for_expr(step);
+                enter_loop();
+                let body = ASTNode::from_cst(body);
+                leave_loop();
+                remove_scope_symbols(get_current_func(), get_current_scope_num());
+                remove_scope_structs(get_current_scope_num());
+                update_current_scope_num(get_current_scope_num()-1);
+                ASTNode::For { init, cond, step, body: Box::new(body), span: node.span() }
+            }
+            CSTNode::SwitchStmt { expression, body, .. } => {
+                let cond = ASTNode::from_cst(expression);
+                enter_switch();
+                let body = ASTNode::from_cst(body);
+                leave_switch();
+                ASTNode::Switch { cond: Box::new(cond), body: Box::new(body), span: node.span() }
+            }
+            CSTNode::DoWhileStmt { body, expression, .. } => {
+                enter_loop();
+                let body = ASTNode::from_cst(body);
+                leave_loop();
+                let cond = ASTNode::from_cst(expression);
+                ASTNode::DoWhile { body: Box::new(body), cond: Box::new(cond), span: node.span() }
+            }
+            // goto 的标号
+            CSTNode::LabeledStmt { label, stmt, .. } if label != "case" && label != "default" => {
+                if let Some(prev) = define_label(label, node.span()) {
+                    semantic_error_with_notes(node.span(), format!("redefinition of label '{}'", label), vec![format!("{}: note: previous definition is here", prev)]);
+                }
+                let body = ASTNode::from_cst(stmt);
+                ASTNode::Label { name: label.clone(), body: Box::new(body), span: node.span() }
+            }
+            CSTNode::LabeledStmt { label, expression, stmt, .. } => {
+                // case 的值折叠之后必须是一个整数常量
+                let value = expression.as_ref().map(|expr| {
+                    let folded = ASTNode::from_cst(expr).optimal();
+                    match &folded {
+                        ASTNode::Literal(tok) if int_value(tok).is_some() => int_value(tok).unwrap(),
+                        _ => {
+                            semantic_error(expr.span(), String::from("case label does not reduce to an integer constant"));
+                            0
+                        }
+                    }
+                });
+                if !in_switch() {
+                    semantic_error(node.span(), format!("'{}' label not within a switch statement", label));
+                } else if let Some(prev) = add_case_label(value, node.span()) {
+                    let (message, note) = match value {
+                        Some(v) => (format!("duplicate case value '{}'", v), "previously used here"),
+                        None => (String::from("multiple default labels in one switch"), "this is the first default label"),
+                    };
+                    semantic_error_with_notes(node.span(), message, vec![format!("{}: note: {}", prev, note)]);
+                }
+                let body = ASTNode::from_cst(stmt);
+                ASTNode::Case { value, body: Box::new(body), span: node.span() }
+            }
             CSTNode::NormalStmt {
                 return_stmt,
+                jump_stmt,
+                label,
                 expression,
                 compst,
                 ..
             } => {
-                if return_stmt.is_some() {
+                if let Some(label) = label {
+                    // 标号可以在 goto 后面定义，到函数结束时再检查
+                    use_label(label, node.span());
+                    ASTNode::Goto(label.clone(), node.span())
+                } else if let Some(jump) = jump_stmt {
+                    // break 也可以跳出 switch
+                    if jump == "break" && !in_loop() && !in_switch() {
+                        semantic_error(node.span(), String::from("'break' statement not in loop or switch statement"));
+                    } else if jump == "continue" && !in_loop() {
+                        semantic_error(node.span(), String::from("'continue' statement not in loop"));
+                    }
+                    if jump == "break" {
+                        ASTNode::Break(node.span())
+                    } else {
+                        ASTNode::Continue(node.span())
+                    }
+                } else if return_stmt.is_some() {
                     let expr = expression.as_ref().map(|e| Box::new(ASTNode::from_cst(e)));
-                    ASTNode::Return { expr }
+                    ASTNode::Return { expr, span: node.span() }
                 } else if let Some(value) = expression {
                     ASTNode::from_cst(value.as_ref())
                 } else {
@@ -159,79 +269,178 @@ impl ASTNode {
             CSTNode::Def { spec, dec_list, .. } => {
                 let var_type = extract_spec(spec);
                 let var_decs = collect_decs(var_type, dec_list);
-                ASTNode::VarDecl(var_decs)
+                ASTNode::VarDecl(var_decs, node.span())
+            }
+            CSTNode::Comma { assign, comma_prime, .. } => {
+                let lhs = ASTNode::from_cst(assign);
+                if let Some(prime) = comma_prime {
+                    extract_comma(&lhs, prime)
+                } else {
+                    ASTNode::BinaryOp { op: None, lhs: Box::new(lhs), rhs: None, span: node.span() }
+                }
             }
             CSTNode::Assign {
-                logical_or,
+                conditional,
                 assign_prime,
+                ..
             } => {
-                let lhs = ASTNode::from_cst(logical_or);
+                let lhs = ASTNode::from_cst(conditional);
                 if let Some(prime) = assign_prime {
                     extract_assign(&lhs, prime)
                 } else {
-                    ASTNode::BinaryOp { op: None, lhs: Box::new(lhs), rhs: None }
+                    ASTNode::BinaryOp { op: None, lhs: Box::new(lhs), rhs: None, span: node.span() }
                 }
             }
-            CSTNode::LogicalOr { logical_and, logical_or_prime } => {
+            CSTNode::Conditional { logical_or, expression: None, .. } => ASTNode::from_cst(logical_or),
+            CSTNode::Conditional { logical_or, expression: Some(expression), conditional: Some(conditional), .. } => {
+                let cond = ASTNode::from_cst(logical_or);
+                let then_expr = ASTNode::from_cst(expression);
+                let else_expr = ASTNode::from_cst(conditional);
+                check_conditional_arms(&then_expr, &else_expr, node.span());
+                ASTNode::Ternary { cond: Box::new(cond), then_expr: Box::new(then_expr), else_expr: Box::new(else_expr), span: node.span() }
+            }
+            CSTNode::LogicalOr { logical_and, logical_or_prime, .. } => {
                 let lhs = ASTNode::from_cst(logical_and);
                 if let Some(prime) = logical_or_prime {
                     extract_logical_or(&lhs, prime)
                 } else {
-                    ASTNode::BinaryOp { op: None, lhs: Box::new(lhs), rhs: None }
+                    ASTNode::BinaryOp { op: None, lhs: Box::new(lhs), rhs: None, span: node.span() }
                 }
             }
-            CSTNode::LogicalAnd { equality, logical_and_prime } => {
-                let lhs = ASTNode::from_cst(equality);
+            CSTNode::LogicalAnd { bit_or, logical_and_prime, .. } => {
+                let lhs = ASTNode::from_cst(bit_or);
                 if let Some(prime) = logical_and_prime {
                     extract_logical_and(&lhs, prime)
                 } else {
-                    ASTNode::BinaryOp { op: None, lhs: Box::new(lhs), rhs: None }
+                    ASTNode::BinaryOp { op: None, lhs: Box::new(lhs), rhs: None, span: node.span() }
+                }
+            }
+            CSTNode::BitOr { bit_xor, bit_or_prime, .. } => {
+                let lhs = ASTNode::from_cst(bit_xor);
+                if let Some(prime) = bit_or_prime {
+                    extract_bit_or(&lhs, prime)
+                } else {
+                    ASTNode::BinaryOp { op: None, lhs: Box::new(lhs), rhs: None, span: node.span() }
+                }
+            }
+            CSTNode::BitXor { bit_and, bit_xor_prime, .. } => {
+                let lhs = ASTNode::from_cst(bit_and);
+                if let Some(prime) = bit_xor_prime {
+                    extract_bit_xor(&lhs, prime)
+                } else {
+                    ASTNode::BinaryOp { op: None, lhs: Box::new(lhs), rhs: None, span: node.span() }
                 }
             }
-            CSTNode::Equality { comparison, equality_prime } => {
+            CSTNode::BitAnd { equality, bit_and_prime, .. } => {
+                let lhs = ASTNode::from_cst(equality);
+                if let Some(prime) = bit_and_prime {
+                    extract_bit_and(&lhs, prime)
+                } else {
+                    ASTNode::BinaryOp { op: None, lhs: Box::new(lhs), rhs: None, span: node.span() }
+                }
+            }
+            CSTNode::Equality { comparison, equality_prime, .. } => {
                 let lhs = ASTNode::from_cst(comparison);
                 if let Some(prime) = equality_prime {
                     extract_equality(&lhs, prime)
                 } else {
-                    ASTNode::BinaryOp { op: None, lhs: Box::new(lhs), rhs: None }
+                    ASTNode::BinaryOp { op: None, lhs: Box::new(lhs), rhs: None, span: node.span() }
                 }
             }
-            CSTNode::Comparison { term, comparison_prime } => {
-                let lhs = ASTNode::from_cst(term);
+            CSTNode::Comparison { shift, comparison_prime, .. } => {
+                let lhs = ASTNode::from_cst(shift);
                 if let Some(prime) = comparison_prime {
                     extract_comparison(&lhs, prime)
                 } else {
-                    ASTNode::BinaryOp { op: None, lhs: Box::new(lhs), rhs: None }
+                    ASTNode::BinaryOp { op: None, lhs: Box::new(lhs), rhs: None, span: node.span() }
                 }
             }
-            CSTNode::Term {factor, term_prime } => {
+            CSTNode::Shift { term, shift_prime, .. } => {
+                let lhs = ASTNode::from_cst(term);
+                if let Some(prime) = shift_prime {
+                    extract_shift(&lhs, prime)
+                } else {
+                    ASTNode::BinaryOp { op: None, lhs: Box::new(lhs), rhs: None, span: node.span() }
+                }
+            }
+            CSTNode::Term {factor, term_prime, .. } => {
                 let lhs = ASTNode::from_cst(factor);
                 if let Some(prime) = term_prime {
                     extract_term(&lhs, prime)
                 } else {
-                    ASTNode::BinaryOp { op: None, lhs: Box::new(lhs), rhs: None }
+                    ASTNode::BinaryOp { op: None, lhs: Box::new(lhs), rhs: None, span: node.span() }
                 }
             }
-            CSTNode::Factor { unary, factor_prime } => {
+            CSTNode::Factor { unary, factor_prime, .. } => {
                 let lhs = ASTNode::from_cst(unary);
                 if let Some(prime) = factor_prime {
                     extract_factor(&lhs, prime)
                 } else {
-                    ASTNode::BinaryOp { op: None, lhs: Box::new(lhs), rhs: None }
+                    ASTNode::BinaryOp { op: None, lhs: Box::new(lhs), rhs: None, span: node.span() }
                 }
             }
-            CSTNode::Unary { op, unary } => {
+            CSTNode::Unary { op, unary, .. } => {
                 if let Some(value) = op {
                     let expr = ASTNode::from_cst(unary);
-                    ASTNode::UnaryOp { op: value.clone(), expr: Box::new(expr) }
+                    check_lvalue_operand(value, &expr);
+                    if value != "&" {
+                        check_struct_operand(value, &expr);
+                    }
+                    if value == "*" {
+                        extract_deref(expr, node.span())
+                    } else if let Some(step) = pointer_step(value, &expr, true, node.span()) {
+                        step
+                    } else {
+                        ASTNode::UnaryOp { op: value.clone(), expr: Box::new(expr), span: node.span() }
+                    }
                 } else {
                     ASTNode::from_cst(unary)
                 }
             }
+            CSTNode::Postfix { primary, op, .. } => {
+                let expr = ASTNode::from_cst(primary);
+                check_lvalue_operand(op, &expr);
+                check_struct_operand(op, &expr);
+                if let Some(step) = pointer_step(op, &expr, false, node.span()) {
+                    step
+                } else {
+                    ASTNode::PostfixOp { op: op.clone(), expr: Box::new(expr), span: node.span() }
+                }
+            }
+            // a[i] 就是 *(a + i)
+            CSTNode::Index { primary, expression, .. } => {
+                let base = ASTNode::from_cst(primary);
+                let index = ASTNode::from_cst(expression);
+                let is_pointer = |node: &ASTNode| matches!(ASTNode::get_ast_type(node).map(decay), Some(Type::Pointer(_)));
+                if !is_pointer(&base) && !is_pointer(&index) {
+                    semantic_error(node.span(), String::from("subscripted value is neither array nor pointer"));
+                    return base;
+                }
+                let addr = pointer_arith("+", &base, &index).unwrap();
+                extract_deref(addr, node.span())
+            }
+            // p->x 就是 (*p).x
+            CSTNode::Member { primary, op, id, span } => {
+                let base = ASTNode::from_cst(primary);
+                let base = if op == "->" {
+                    match ASTNode::get_ast_type(&base).map(decay) {
+                        Some(Type::Pointer(inner)) if matches!(*inner, Type::CustomizedType(_)) => extract_deref(base, *span),
+                        other => {
+                            if let Some(ty) = other {
+                                semantic_error(*span, format!("invalid type argument of '->' (have '{}')", type_name(&ty)));
+                            }
+                            return int_literal(0, *span);
+                        }
+                    }
+                } else {
+                    base
+                };
+                extract_member(base, id, *span)
+            }
             CSTNode::Primary {
                 symbol: Some(value), ..
             } => {
-                if symbol_is_literal(&value.types) {
+                if value.kind.is_literal() {
                     ASTNode::Literal(value.clone())
                 } else {
                     ASTNode::Ident(value.clone())
@@ -243,9 +452,22 @@ impl ASTNode {
             } => {
                 extract_fcall(fcall)
             }
-            CSTNode::Expression(expr) => {
+            // 括号里的表达式
+            CSTNode::Primary {
+                expression: Some(expr),
+                ..
+            } => {
+                ASTNode::from_cst(expr)
+            }
+            CSTNode::Expression(expr, _) => {
                 ASTNode::from_cst(expr)
             }
+            // 没有 else 的 if，分支是 Stmt
+            CSTNode::Stmt { .. } => extract_stmt(node),
+            CSTNode::InitList { items, span } => ASTNode::InitList {
+                items: items.iter().map(ASTNode::from_cst).collect(),
+                span: *span,
+            },
             other => {
                 unimplemented!("未处理的 CSTNode: {:?}", other)
             }
@@ -260,6 +482,7 @@ fn collect_decs(var_type: Type, node: &CSTNode) -> Vec<VarDec> {
         CSTNode::DecList { dec, dec_list, .. } => {
             let mut items: Vec<VarDec> = vec![];
             let var_dec = extract_dec(var_type.clone(), dec);
+            check_complete_type(&var_dec);
             items.push(var_dec.clone());
             build_symbol_table(get_current_func(), &var_dec, get_current_scope_num(), None);
             if let Some(list) = dec_list {
@@ -279,14 +502,7 @@ fn collect_paradec(node: &CSTNode, items: &mut Vec<VarDec>) {
         CSTNode::VarList {
             para_dec, var_list, ..
         } => {
-            let param = extract_param(para_dec);
-            build_symbol_table(
-                get_current_func(),
-                &param,
-                get_current_scope_num() + 1,
-                None,
-            );
-            items.push(param);
+            items.push(extract_param(para_dec));
             if let Some(list) = var_list {
                 collect_paradec(list, items);
             }
@@ -294,8 +510,52 @@ fn collect_paradec(node: &CSTNode, items: &mut Vec<VarDec>) {
         _ => unreachable!(),
     }
 }
+// 写在前面的 extern 和 static 只能有一个
+fn extract_storage(storage: &[String], span: Span) -> Option<String> {
+    if let [first, second, ..] = storage {
+        let message = if first == second {
+            format!("duplicate '{}'", second)
+        } else {
+            String::from("multiple storage classes in declaration specifiers")
+        };
+        semantic_error(span, message);
+    }
+    storage.first().cloned()
+}
+// 函数第一次声明时放进符号表，之后的声明和定义只检查是否和前面的一致
+fn declare_function(
+    name: &str,
+    params: &[VarDec],
+    ret_type: &Type,
+    storage: Option<&str>,
+    defined: bool,
+    span: Span,
+) {
+    let first = global_decl(name).is_none();
+    declare_global(
+        name,
+        GlobalDecl {
+            ty: ret_type.clone(),
+            params: Some(params.iter().map(|p| p.var_type.clone()).collect()),
+            is_static: storage == Some("static"),
+            defined,
+            init: None,
+            span,
+        },
+        storage,
+    );
+    if first {
+        let var_info = VarDec {
+            var_type: ret_type.clone(),
+            var_name: name.to_string(),
+            init: None,
+            span,
+        };
+        build_symbol_table(String::new(), &var_info, 0, Some(params.to_vec()));
+    }
+}
 // TODO:
-fn collect_extdecs(var_type: Type, node: &CSTNode, items: &mut Vec<VarDec>) {
+fn collect_extdecs(var_type: Type, storage: Option<&str>, node: &CSTNode, items: &mut Vec<VarDec>) {
     match node {
         CSTNode::ExtDecList {
             var_dec,
@@ -303,9 +563,35 @@ fn collect_extdecs(var_type: Type, node: &CSTNode, items: &mut Vec<VarDec>) {
             ..
         } => {
             let var_dec = extract_dec(var_type.clone(), var_dec);
+            // extern 声明的变量在别的文件里分配，类型可以还不完整
+            if storage != Some("extern") || var_dec.init.is_some() {
+                check_complete_type(&var_dec);
+            }
+            let init = var_dec
+                .init
+                .as_ref()
+                .and_then(|init| global_initializer(&var_dec, init));
+            // 同一个变量可以声明好几次，只在第一次放进符号表
+            let first = global_decl(&var_dec.var_name).is_none();
+            declare_global(
+                &var_dec.var_name,
+                GlobalDecl {
+                    ty: var_dec.var_type.clone(),
+                    params: None,
+                    is_static: storage == Some("static"),
+                    // 带初始值的 extern 声明也是定义
+                    defined: storage != Some("extern") || var_dec.init.is_some(),
+                    init,
+                    span: var_dec.span,
+                },
+                storage,
+            );
+            if first {
+                build_symbol_table(String::new(), &var_dec, get_current_scope_num(), None);
+            }
             items.push(var_dec);
             if let Some(list) = ext_dec_list {
-                collect_extdecs(var_type.clone(), list, items);
+                collect_extdecs(var_type.clone(), storage, list, items);
             } else {
                 // nothing
             }
@@ -315,11 +601,143 @@ fn collect_extdecs(var_type: Type, node: &CSTNode, items: &mut Vec<VarDec>) {
         }
     }
 }
+// 全局变量的初始值在编译时算出来写进数据段，类型不对或者不是常量时报错
+fn global_initializer(var: &VarDec, init: &ASTNode) -> Option<Vec<String>> {
+    // 数组的初始值已经摊平，类型也检查过了
+    if let ASTNode::InitList { items, .. } = init {
+        let elem = element_type(&var.var_type);
+        let values: Vec<Option<String>> = items
+            .iter()
+            .map(|item| constant_initializer(elem, item))
+            .collect();
+        let mut values: Vec<String> = values.into_iter().collect::<Option<_>>()?;
+        // 末尾补的 0 不用写出来，全是 0 的数组和没有初始值一样放进 .bss
+        while values.last().is_some_and(|value| value == "0") {
+            values.pop();
+        }
+        return Some(values);
+    }
+    // 标量的初始值在 flatten_initializer 里检查过类型
+    let init = init.clone().optimal();
+    ASTNode::get_ast_type(&init)?;
+    if let Type::CustomizedType(_) = &var.var_type {
+        semantic_error(init.span(), String::from("invalid initializer"));
+        return None;
+    }
+    constant_initializer(&var.var_type, &init).map(|value| vec![value])
+}
+// 初始化和赋值时右边要能转换成左边的类型，整数类型之间可以转换，指针和整数不能混用
+fn conversion_error(ty: &Type, value: &ASTNode, assigning: bool) -> Option<String> {
+    let value_type = decay(ASTNode::get_ast_type(value)?);
+    let var_name = type_name(ty);
+    let value_name = type_name(&value_type);
+    let what = if assigning {
+        "assignment to"
+    } else {
+        "initialization of"
+    };
+    match (ty, &value_type) {
+        (Type::Pointer(_), _) if is_null_pointer_pair(&Some(ty.clone()), value) => None,
+        (Type::Pointer(_), Type::BaseType(_)) => Some(format!(
+            "{} '{}' from '{}' makes pointer from integer without a cast",
+            what, var_name, value_name
+        )),
+        (Type::Pointer(_), Type::Pointer(_)) if value_type != *ty => Some(format!(
+            "{} '{}' from incompatible pointer type '{}'",
+            what, var_name, value_name
+        )),
+        (Type::BaseType(_), Type::Pointer(_)) => Some(format!(
+            "{} '{}' from '{}' makes integer from pointer without a cast",
+            what, var_name, value_name
+        )),
+        (Type::CustomizedType(_), _) | (_, Type::CustomizedType(_)) if value_type != *ty => {
+            Some(if assigning {
+                format!(
+                    "incompatible types when assigning to type '{}' from type '{}'",
+                    var_name, value_name
+                )
+            } else {
+                format!(
+                    "incompatible types when initializing type '{}' using type '{}'",
+                    var_name, value_name
+                )
+            })
+        }
+        _ => None,
+    }
+}
+// 编译时算出初始值，算不出来时报错
+fn constant_initializer(ty: &Type, init: &ASTNode) -> Option<String> {
+    ASTNode::get_ast_type(init)?;
+    let Some(value) = constant_value(init) else {
+        semantic_error(
+            init.span(),
+            String::from("initializer element is not constant"),
+        );
+        return None;
+    };
+    // char 只占一个字节，截掉高位
+    match value.parse::<i32>() {
+        Ok(v) if type_size(ty) == 1 => Some((v as u8).to_string()),
+        _ => Some(value),
+    }
+}
+// 整数常量，或者全局变量和字符串常量的地址加减常量，其他的在编译时算不出来
+fn constant_value(expr: &ASTNode) -> Option<String> {
+    match expr {
+        ASTNode::Literal(Token {
+            kind: TokenKind::StrLit(bytes),
+            ..
+        }) => Some(string_label(bytes)),
+        ASTNode::Literal(tok) => int_value(tok).map(|v| v.to_string()),
+        ASTNode::UnaryOp { op, expr, .. } if op == "&" => constant_address(expr),
+        // 数组名就是它的地址
+        ASTNode::Ident(_) if matches!(ASTNode::get_ast_type(expr), Some(Type::Array(..))) => {
+            constant_address(expr)
+        }
+        ASTNode::BinaryOp {
+            op: Some(op),
+            lhs,
+            rhs: Some(rhs),
+            ..
+        } if op == "+" || op == "-" => {
+            let base = constant_value(lhs)?;
+            let ASTNode::Literal(tok) = rhs.as_ref() else {
+                return None;
+            };
+            // 偏移按 32 位回绕，负的偏移写成 arr-4 而不是 arr+-4
+            let offset = int_value(tok)?;
+            let offset = if op == "-" {
+                offset.wrapping_neg()
+            } else {
+                offset
+            };
+            Some(format!("{}{:+}", base, offset))
+        }
+        _ => None,
+    }
+}
+// 全局变量的地址，数组元素和成员的地址在变量的地址上加偏移
+fn constant_address(expr: &ASTNode) -> Option<String> {
+    match expr {
+        ASTNode::Ident(tok)
+            if global_decl(&tok.to_string()).is_some_and(|decl| decl.params.is_none()) =>
+        {
+            Some(tok.to_string())
+        }
+        ASTNode::Deref { expr, .. } => constant_value(expr),
+        ASTNode::Member { expr, offset, .. } => {
+            Some(format!("{}+{}", constant_address(expr)?, offset))
+        }
+        _ => None,
+    }
+}
 fn collect_extdefs(node: &CSTNode, items: &mut Vec<ASTNode>) {
     match node {
         CSTNode::ExtDefList {
             ext_def,
             ext_def_list,
+            ..
         } => {
             items.push(ASTNode::from_cst(ext_def));
             if let Some(list) = ext_def_list {
@@ -339,23 +757,28 @@ fn extract_dec(var_type: Type, node: &CSTNode) -> VarDec {
             var_dec,
             op,
             expression,
+            ..
         } => {
-            let var_name = extract_vardec(var_dec);
+            let (var_name, var_type) = extract_vardec(var_dec, var_type);
             if let Some(value) = op {
                 if value.eq("=") {
                     let node = expression.clone().unwrap();
                     let init = ASTNode::from_cst(&node.clone());
-                    VarDec {
+                    let mut var = VarDec {
                         var_type,
                         var_name,
                         init: Some(init),
-                    }
+                        span: var_dec.span(),
+                    };
+                    flatten_initializer(&mut var);
+                    var
                 } else {
-                    println!("unexpected op");
+                    println!("{}: unexpected op", var_dec.span());
                     VarDec {
                         var_type: Type::BaseType(String::new()),
                         var_name: String::new(),
                         init: None,
+                        span: var_dec.span(),
                     }
                 }
             } else {
@@ -363,39 +786,253 @@ fn extract_dec(var_type: Type, node: &CSTNode) -> VarDec {
                     var_type,
                     var_name,
                     init: None,
+                    span: var_dec.span(),
                 }
             }
         }
         _ => unreachable!(),
     }
 }
-fn extract_vardec(node: &CSTNode) -> String {
+// 花括号初始值按 C 的规则摊平成数组每个元素的初始值，内层的花括号可以省略，
+// 没写的元素补 0，不写长度的数组按初始值的个数定下长度
+fn flatten_initializer(var: &mut VarDec) {
+    if let (Type::Array(elem, len), Some(init)) = (&var.var_type, &var.init)
+        && let Some(bytes) = string_initializer(elem, init)
+    {
+        let (elem, span) = (elem.clone(), init.span());
+        let mut leaves = Vec::new();
+        let count = fill_string((*len > 0).then_some(*len), &bytes, &mut leaves, span);
+        var.var_type = Type::Array(elem, count);
+        var.init = Some(ASTNode::InitList {
+            items: leaves,
+            span,
+        });
+        return;
+    }
+    let Some(ASTNode::InitList { items, span }) = &var.init else {
+        match (&var.var_type, &var.init) {
+            (Type::Array(..), Some(init)) => {
+                semantic_error(init.span(), String::from("invalid initializer"));
+                var.init = None;
+            }
+            (Type::BaseType(_) | Type::Pointer(_), Some(init)) => {
+                var.init = Some(scalar_initializer(&var.var_type, init));
+            }
+            _ => {}
+        }
+        return;
+    };
+    let (items, span) = (items.clone(), *span);
+    var.init = match var.var_type.clone() {
+        Type::Array(elem, len) => {
+            let mut pos = 0;
+            let mut leaves = Vec::new();
+            let count = fill_array(
+                &elem,
+                (len > 0).then_some(len),
+                &items,
+                &mut pos,
+                &mut leaves,
+                span,
+            );
+            check_excess(&items, pos, "array");
+            var.var_type = Type::Array(elem, count);
+            Some(ASTNode::InitList {
+                items: leaves,
+                span,
+            })
+        }
+        // 标量外面也可以套花括号
+        Type::BaseType(_) | Type::Pointer(_) => Some(scalar_initializer(
+            &var.var_type,
+            &ASTNode::InitList { items, span },
+        )),
+        Type::CustomizedType(_) => {
+            semantic_error(span, String::from("invalid initializer"));
+            None
+        }
+    };
+}
+// 按顺序填 len 个元素，len 是 None 时填到初始值用完为止，返回元素个数
+fn fill_array(
+    elem: &Type,
+    len: Option<usize>,
+    items: &[ASTNode],
+    pos: &mut usize,
+    leaves: &mut Vec<ASTNode>,
+    span: Span,
+) -> usize {
+    let start = leaves.len();
+    let mut count = 0;
+    while len.is_none_or(|len| count < len) && *pos < items.len() {
+        fill_element(elem, items, pos, leaves, span);
+        count += 1;
+    }
+    let count = len.unwrap_or(count);
+    leaves.resize(start + count * leaf_count(elem), int_literal(0, span));
+    count
+}
+// 元素是数组时，有自己的花括号就在花括号里填，没有就接着用外层的初始值
+fn fill_element(
+    ty: &Type,
+    items: &[ASTNode],
+    pos: &mut usize,
+    leaves: &mut Vec<ASTNode>,
+    span: Span,
+) {
+    if let Type::Array(elem, len) = ty
+        && let Some(bytes) = string_initializer(elem, &items[*pos])
+    {
+        fill_string(Some(*len), &bytes, leaves, items[*pos].span());
+        *pos += 1;
+        return;
+    }
+    match (ty, &items[*pos]) {
+        (Type::Array(elem, len), ASTNode::InitList { items: inner, span }) => {
+            *pos += 1;
+            let mut inner_pos = 0;
+            fill_array(elem, Some(*len), inner, &mut inner_pos, leaves, *span);
+            check_excess(inner, inner_pos, "array");
+        }
+        (Type::Array(elem, len), _) => {
+            fill_array(elem, Some(*len), items, pos, leaves, span);
+        }
+        (_, item) => {
+            *pos += 1;
+            leaves.push(scalar_initializer(ty, item));
+        }
+    }
+}
+// char 数组可以用字符串初始化，字符串外面也可以套一层花括号
+fn string_initializer(elem: &Type, init: &ASTNode) -> Option<Vec<u8>> {
+    if *elem != Type::BaseType(String::from("char")) {
+        return None;
+    }
+    match init {
+        ASTNode::Literal(Token {
+            kind: TokenKind::StrLit(bytes),
+            ..
+        }) => Some(bytes.clone()),
+        ASTNode::InitList { items, .. } if items.len() == 1 => string_initializer(elem, &items[0]),
+        // 只有一个操作数的表达式层
+        ASTNode::BinaryOp { op: None, lhs, .. } => string_initializer(elem, lhs),
+        _ => None,
+    }
+}
+// 把字符串拆成一个个字符，放得下的时候带上结尾的 '\0'，返回元素个数
+fn fill_string(len: Option<usize>, bytes: &[u8], leaves: &mut Vec<ASTNode>, span: Span) -> usize {
+    if len.is_some_and(|len| bytes.len() > len) {
+        semantic_warning(
+            span,
+            String::from("initializer-string for array of 'char' is too long"),
+        );
+    }
+    let chars: Vec<ASTNode> = bytes
+        .iter()
+        .chain([&0])
+        .map(|&b| {
+            ASTNode::Literal(Token {
+                kind: TokenKind::CharLit(b as i8 as i64),
+                span,
+            })
+        })
+        .collect();
+    let mut pos = 0;
+    fill_array(
+        &Type::BaseType(String::from("char")),
+        len,
+        &chars,
+        &mut pos,
+        leaves,
+        span,
+    )
+}
+// 数组元素或者标量的初始值，最多套一层花括号
+fn scalar_initializer(ty: &Type, init: &ASTNode) -> ASTNode {
+    let init = match init {
+        ASTNode::InitList { items, .. } => {
+            check_excess(items, 1, "scalar");
+            &items[0]
+        }
+        other => other,
+    };
+    if let ASTNode::InitList { span, .. } = init {
+        semantic_error(*span, String::from("braces around scalar initializer"));
+        return int_literal(0, *span);
+    }
+    let init = init.clone().optimal();
+    let message = match ty {
+        Type::CustomizedType(_) => Some(String::from("invalid initializer")),
+        ty => conversion_error(ty, &init, false),
+    };
+    if let Some(message) = message {
+        semantic_error(init.span(), message);
+    }
+    init
+}
+fn check_excess(items: &[ASTNode], used: usize, what: &str) {
+    if let Some(extra) = items.get(used) {
+        semantic_error(
+            extra.span(),
+            format!("excess elements in {} initializer", what),
+        );
+    }
+}
+// 数组里标量元素的个数
+fn leaf_count(ty: &Type) -> usize {
+    match ty {
+        Type::Array(elem, len) => len * leaf_count(elem),
+        _ => 1,
+    }
+}
+// 声明符从外往里拆，每拆一层 '*' 类型就多一层指针
+fn extract_vardec(node: &CSTNode, var_type: Type) -> (String, Type) {
     match node {
         CSTNode::VarDec {
             id,
+            star,
             var_dec,
-            lt,
-            rt,
             literal,
+            ..
         } => {
-            let mut var_name = String::new();
             if let Some(value) = id {
-                var_name.push_str(value);
-                var_name
+                (value.clone(), var_type)
+            } else if star.is_some() {
+                let pointer = Type::Pointer(Box::new(var_type));
+                match var_dec {
+                    Some(value) => extract_vardec(value, pointer),
+                    // 原型里没有名字的形参
+                    None => (String::new(), pointer),
+                }
             } else if let Some(value) = var_dec {
-                let id_ = extract_vardec(value);
-                let literal_value = literal
-                    .clone()
-                    .unwrap_or_default()
-                    .parse::<u32>()
-                    .expect("数组的长度必须是正整数");
-                var_name.push_str(&id_);
-                var_name.push_str(&lt.clone().unwrap_or_default());
-                var_name.push_str(&literal_value.to_string());
-                var_name.push_str(&rt.clone().unwrap_or_default());
-                var_name
+                // a[2][3] 最外层是 [3]，先包成 3 个元素的数组，再往里包 [2]
+                // 没写长度时记为 0，只有形参可以这样写
+                let Some(literal) = literal else {
+                    return extract_vardec(value, Type::Array(Box::new(var_type), 0));
+                };
+                // 长度不对时报错，按 1 个元素接着往下编译
+                let (len, error) = match literal.parse::<usize>() {
+                    Err(_) => (1, Some("has non-integer type")),
+                    Ok(0) => (1, Some("is zero")),
+                    // ILP32 下对象最大 2^31 - 1 个字节
+                    Ok(len) if len.saturating_mul(type_size(&var_type)) > i32::MAX as usize => {
+                        (1, Some("is too large"))
+                    }
+                    Ok(len) => (len, None),
+                };
+                let (name, ty) = extract_vardec(value, Type::Array(Box::new(var_type), len));
+                if let Some(error) = error {
+                    let what = if name.is_empty() {
+                        String::from("unnamed array")
+                    } else {
+                        format!("array '{}'", name)
+                    };
+                    semantic_error(node.span(), format!("size of {} {}", what, error));
+                }
+                (name, ty)
             } else {
-                unreachable!()
+                // 原型里只写了类型的形参
+                (String::new(), var_type)
             }
         }
         _ => unreachable!(),
@@ -403,29 +1040,56 @@ fn extract_vardec(node: &CSTNode) -> String {
 }
 fn extract_param(node: &CSTNode) -> VarDec {
     match node {
-        CSTNode::ParaDec { spec, var_dec } => {
-            let var_type = extract_spec(spec);
-            let var_name = extract_vardec(var_dec);
-            VarDec {
-                var_type,
+        CSTNode::ParaDec { spec, var_dec, .. } => {
+            let (var_name, var_type) = extract_vardec(var_dec, extract_spec(spec));
+            // 数组形参实际上是指针
+            let param = VarDec {
+                var_type: decay(var_type),
                 var_name,
                 init: None,
+                span: var_dec.span(),
+            };
+            check_complete_type(&param);
+            if matches!(param.var_type, Type::CustomizedType(_)) {
+                semantic_error(
+                    param.span,
+                    format!(
+                        "parameter '{}' has struct type, pass a pointer to it instead",
+                        param.var_name
+                    ),
+                );
             }
+            param
         }
         _ => unreachable!(),
     }
 }
 // TODO:
-fn extract_fundec(node: &CSTNode) -> (String, Vec<VarDec>) {
+fn extract_fundec(node: &CSTNode, ret_type: Type) -> (String, Vec<VarDec>, Type) {
     match node {
-        CSTNode::FunDec { id, var_list, .. } => {
+        CSTNode::FunDec {
+            id,
+            var_list,
+            pointers,
+            ..
+        } => {
             set_current_func(id.clone());
+            let ret_type = (0..*pointers).fold(ret_type, |ty, _| Type::Pointer(Box::new(ty)));
+            if matches!(ret_type, Type::CustomizedType(_)) {
+                semantic_error(
+                    node.span(),
+                    format!(
+                        "function '{}' returns a struct, return a pointer to it instead",
+                        id
+                    ),
+                );
+            }
             let mut params: Vec<VarDec> = vec![];
             if let Some(list) = var_list {
                 collect_paradec(list, &mut params);
-                (id.clone(), params)
+                (id.clone(), params, ret_type)
             } else {
-                (id.clone(), params)
+                (id.clone(), params, ret_type)
             }
         }
         _ => unreachable!(),
@@ -434,14 +1098,30 @@ fn extract_fundec(node: &CSTNode) -> (String, Vec<VarDec>) {
 fn extract_struct_sepc(node: &CSTNode) -> StructType {
     match node {
         CSTNode::StructSpecifier { id, def_list, .. } => {
+            let tag = id.clone().unwrap_or_default();
+            let Some(list) = def_list else {
+                // 只写了标签，用已经定义的；还没定义的先当作不完整的类型
+                return lookup_struct(&tag).unwrap_or(StructType {
+                    id: tag,
+                    field: vec![],
+                });
+            };
             let mut items: Vec<VarDec> = vec![];
-            if let Some(list) = def_list {
-                collect_defs(list, &mut items);
-            }
-            StructType {
-                id: id.clone().unwrap_or_default(),
+            collect_fields(list, &mut items);
+            let st = StructType {
+                id: tag,
                 field: items,
+            };
+            if !define_struct(&st) {
+                semantic_error(
+                    node.span(),
+                    format!(
+                        "redefinition of '{}'",
+                        type_name(&Type::CustomizedType(st.clone()))
+                    ),
+                );
             }
+            st
         }
         _ => unreachable!(),
     }
@@ -451,6 +1131,7 @@ fn extract_spec(spec: &CSTNode) -> Type {
         CSTNode::Specifier {
             specifier_type,
             struct_specifier,
+            ..
         } => {
             if let Some(value) = specifier_type {
                 Type::BaseType(value.clone())
@@ -464,10 +1145,46 @@ fn extract_spec(spec: &CSTNode) -> Type {
         _ => unreachable!(),
     }
 }
+// 结构体的成员不进符号表，成员名只在结构体里不能重复
+fn collect_fields(node: &CSTNode, items: &mut Vec<VarDec>) {
+    match node {
+        CSTNode::DefList { def, def_list, .. } => {
+            let CSTNode::Def { spec, dec_list, .. } = def.as_ref() else {
+                unreachable!()
+            };
+            let var_type = extract_spec(spec);
+            let mut list = Some(dec_list.as_ref());
+            while let Some(CSTNode::DecList { dec, dec_list, .. }) = list {
+                let field = extract_dec(var_type.clone(), dec);
+                if field.init.is_some() {
+                    semantic_error(
+                        field.span,
+                        format!("member '{}' cannot have an initializer", field.var_name),
+                    );
+                }
+                if items.iter().any(|item| item.var_name == field.var_name) {
+                    semantic_error(field.span, format!("duplicate member '{}'", field.var_name));
+                } else if incomplete_type(&field.var_type) {
+                    semantic_error(
+                        field.span,
+                        format!("field '{}' has incomplete type", field.var_name),
+                    );
+                } else {
+                    items.push(field);
+                }
+                list = dec_list.as_deref();
+            }
+            if let Some(list) = def_list {
+                collect_fields(list, items);
+            }
+        }
+        _ => unreachable!(),
+    }
+}
 // BUG:
 fn collect_defs(node: &CSTNode, items: &mut Vec<VarDec>) {
     match node {
-        CSTNode::DefList { def, def_list } => {
+        CSTNode::DefList { def, def_list, .. } => {
             let mut var_dec_list = extract_def(def);
             items.append(&mut var_dec_list);
             if let Some(list) = def_list {
@@ -488,7 +1205,9 @@ fn collect_defs(node: &CSTNode, items: &mut Vec<VarDec>) {
 // TODO:
 fn collect_stmts(node: &CSTNode, items: &mut Vec<ASTNode>) {
     match node {
-        CSTNode::StmtList { stmt, stmt_list } => {
+        CSTNode::StmtList {
+            stmt, stmt_list, ..
+        } => {
             items.push(extract_stmt(stmt));
             if let Some(list) = stmt_list {
                 collect_stmts(list, items);
@@ -502,6 +1221,7 @@ fn extract_stmt(node: &CSTNode) -> ASTNode {
         CSTNode::Stmt {
             unmatched_stmt,
             matched_stmt,
+            ..
         } => match (unmatched_stmt, matched_stmt) {
             (Some(stmt), None) => ASTNode::from_cst(stmt),
             (None, Some(stmt)) => ASTNode::from_cst(stmt),
@@ -512,6 +1232,12 @@ fn extract_stmt(node: &CSTNode) -> ASTNode {
         _ => unreachable!(),
     }
 }
+fn extract_for_expr(node: &CSTNode) -> Option<Box<ASTNode>> {
+    match node {
+        CSTNode::ForExpr(expr, _) => expr.as_ref().map(|e| Box::new(ASTNode::from_cst(e))),
+        _ => unreachable!(),
+    }
+}
 fn extract_def(node: &CSTNode) -> Vec<VarDec> {
     match node {
         CSTNode::Def { spec, dec_list, .. } => {
@@ -529,6 +1255,7 @@ fn colloct_arguments(node: &CSTNode) -> Vec<ASTNode> {
         CSTNode::Arguments {
             arguments_tail,
             expression,
+            ..
         } => {
             let mut args: Vec<ASTNode> = vec![];
             args.push(ASTNode::from_cst(expression));
@@ -547,68 +1274,443 @@ fn extract_fcall(fcall: &CSTNode) -> ASTNode {
     match fcall {
         CSTNode::FunCall { id, arguments, .. } => {
             if let Some(args) = arguments {
+                let args = colloct_arguments(args);
+                check_call(id, &args, fcall.span());
                 ASTNode::Call {
                     func: id.clone(),
-                    args: colloct_arguments(args),
+                    args,
+                    span: fcall.span(),
                 }
             } else {
+                check_call(id, &[], fcall.span());
                 ASTNode::Call {
                     func: id.clone(),
                     args: vec![],
+                    span: fcall.span(),
                 }
             }
         }
         _ => unreachable!(),
     }
 }
+// 调用的函数必须先声明，实参的个数和类型要和形参一样
+fn check_call(func: &str, args: &[ASTNode], span: Span) {
+    let Some(decl) = global_decl(func) else {
+        semantic_error(span, format!("implicit declaration of function '{}'", func));
+        return;
+    };
+    let Some(params) = &decl.params else {
+        semantic_error(span, format!("called object '{}' is not a function", func));
+        return;
+    };
+    if args.len() != params.len() {
+        let what = if args.len() > params.len() {
+            "many"
+        } else {
+            "few"
+        };
+        semantic_error_with_notes(
+            span,
+            format!("too {} arguments to function '{}'", what, func),
+            vec![format!("{}: note: declared here", decl.span)],
+        );
+        return;
+    }
+    for (i, (arg, param)) in args.iter().zip(params).enumerate() {
+        let Some(arg_type) = ASTNode::get_ast_type(arg).map(decay) else {
+            continue;
+        };
+        // 整数类型之间和赋值一样可以转换
+        if arg_type != *param
+            && !(is_integer(&arg_type) && is_integer(param))
+            && !is_null_pointer_pair(&Some(param.clone()), arg)
+        {
+            semantic_error_with_notes(
+                arg.span(),
+                format!("incompatible type for argument {} of '{}'", i + 1, func),
+                vec![format!(
+                    "{}: note: expected '{}' but argument is of type '{}'",
+                    decl.span,
+                    type_name(param),
+                    type_name(&arg_type)
+                )],
+            );
+        }
+    }
+}
+// ++、-- 和取地址的操作数必须是左值
+fn check_lvalue_operand(op: &str, expr: &ASTNode) {
+    let what = match op {
+        "++" => "increment",
+        "--" => "decrement",
+        "&" => "unary '&'",
+        _ => return,
+    };
+    // 数组名是左值但不能修改
+    let is_array = matches!(ASTNode::get_ast_type(expr), Some(Type::Array(..)));
+    if !is_lvalue(expr) || (is_array && op != "&") {
+        semantic_error(expr.span(), format!("lvalue required as {} operand", what));
+    }
+}
+
+// 只有形参的最外层可以不写长度，其他地方的数组长度都要写
+// 变量和数组元素的结构体类型必须已经定义，指向的结构体可以还没定义
+fn check_complete_type(var: &VarDec) {
+    let mut ty = &var.var_type;
+    loop {
+        match ty {
+            Type::Array(_, 0) => {
+                semantic_error(
+                    var.span,
+                    format!("array size missing in '{}'", var.var_name),
+                );
+                return;
+            }
+            Type::Array(elem, _) | Type::Pointer(elem) => ty = elem,
+            _ => break,
+        }
+    }
+    if incomplete_type(&var.var_type) {
+        semantic_error(
+            var.span,
+            format!("storage size of '{}' isn't known", var.var_name),
+        );
+    }
+}
+
+// 没有定义的结构体，或者元素是这种结构体的数组
+fn incomplete_type(ty: &Type) -> bool {
+    match ty {
+        Type::CustomizedType(st) => complete_struct(st).field.is_empty(),
+        Type::Array(elem, _) => incomplete_type(elem),
+        _ => false,
+    }
+}
+
+// 结构体只能整个赋值和取成员，不能参与运算
+fn check_struct_operand(op: &str, expr: &ASTNode) {
+    if let Some(ty @ Type::CustomizedType(_)) = ASTNode::get_ast_type(expr) {
+        semantic_error(
+            expr.span(),
+            format!("invalid operand to '{}' (have '{}')", op, type_name(&ty)),
+        );
+    }
+}
+
+fn check_struct_operands(op: &str, lhs: &ASTNode, rhs: &ASTNode) {
+    let lhs_type = ASTNode::get_ast_type(lhs);
+    let rhs_type = ASTNode::get_ast_type(rhs);
+    if let (Some(lhs_type), Some(rhs_type)) = (lhs_type, rhs_type)
+        && (matches!(lhs_type, Type::CustomizedType(_))
+            || matches!(rhs_type, Type::CustomizedType(_)))
+    {
+        semantic_error(
+            lhs.span().to(rhs.span()),
+            format!(
+                "invalid operands to binary {} (have '{}' and '{}')",
+                op,
+                type_name(&lhs_type),
+                type_name(&rhs_type)
+            ),
+        );
+    }
+}
+
+// 两边的类型要能一起运算，不能时报错，接着检查后面的表达式
+fn check_binary_operands(op: &str, lhs: &ASTNode, rhs: &ASTNode) {
+    check_struct_operands(op, lhs, rhs);
+    if check_types(lhs, rhs) {
+        return;
+    }
+    let (Some(lhs_type), Some(rhs_type)) = (
+        ASTNode::get_ast_type(lhs).map(decay),
+        ASTNode::get_ast_type(rhs).map(decay),
+    ) else {
+        return;
+    };
+    let comparison = matches!(op, "==" | "!=" | "<" | ">" | "<=" | ">=");
+    let message = match (&lhs_type, &rhs_type) {
+        (Type::Pointer(_), Type::Pointer(_)) if comparison => {
+            String::from("comparison of distinct pointer types lacks a cast")
+        }
+        (Type::Pointer(_), _) | (_, Type::Pointer(_)) if comparison => {
+            String::from("comparison between pointer and integer")
+        }
+        _ => format!(
+            "invalid operands to binary {} (have '{}' and '{}')",
+            op,
+            type_name(&lhs_type),
+            type_name(&rhs_type)
+        ),
+    };
+    semantic_error(lhs.span().to(rhs.span()), message);
+}
+
+// 按名字找到成员，偏移由结构体的布局算出来，出错时换成 0 接着检查后面的表达式
+fn extract_member(base: ASTNode, field: &str, span: Span) -> ASTNode {
+    let st = match ASTNode::get_ast_type(&base) {
+        Some(Type::CustomizedType(st)) => complete_struct(&st),
+        other => {
+            if other.is_some() {
+                semantic_error(
+                    span,
+                    format!(
+                        "request for member '{}' in something not a structure or union",
+                        field
+                    ),
+                );
+            }
+            return int_literal(0, span);
+        }
+    };
+    let struct_name = type_name(&Type::CustomizedType(st.clone()));
+    if st.field.is_empty() {
+        semantic_error(
+            span,
+            format!("invalid use of undefined type '{}'", struct_name),
+        );
+        return int_literal(0, span);
+    }
+    let (offsets, ..) = struct_layout(&st);
+    match st.field.iter().position(|f| f.var_name == field) {
+        Some(index) => ASTNode::Member {
+            expr: Box::new(base),
+            field: field.to_string(),
+            offset: offsets[index],
+            ty: st.field[index].var_type.clone(),
+            span,
+        },
+        None => {
+            semantic_error(
+                span,
+                format!("'{}' has no member named '{}'", struct_name, field),
+            );
+            int_literal(0, span)
+        }
+    }
+}
+
+// 只能对指针解引用
+fn extract_deref(expr: ASTNode, span: Span) -> ASTNode {
+    let ty = match ASTNode::get_ast_type(&expr).map(decay) {
+        Some(Type::Pointer(inner)) => match *inner {
+            Type::CustomizedType(st) => Type::CustomizedType(complete_struct(&st)),
+            inner => inner,
+        },
+        other => {
+            if let Some(ty) = &other {
+                semantic_error(
+                    span,
+                    format!(
+                        "invalid type argument of unary '*' (have '{}')",
+                        type_name(ty)
+                    ),
+                );
+            }
+            other.unwrap_or(Type::BaseType(String::from("int")))
+        }
+    };
+    ASTNode::Deref {
+        expr: Box::new(expr),
+        ty,
+        span,
+    }
+}
+fn int_literal(value: usize, span: Span) -> ASTNode {
+    ASTNode::Literal(Token {
+        kind: TokenKind::IntLit(value as u64, NumType::Int),
+        span,
+    })
+}
+// 整数乘上元素大小变成字节数，或者字节数除以元素大小变成元素个数
+fn scale(node: ASTNode, op: &str, size: usize) -> ASTNode {
+    if size == 1 {
+        return node;
+    }
+    let span = node.span();
+    ASTNode::BinaryOp {
+        op: Some(op.to_string()),
+        lhs: Box::new(node),
+        rhs: Some(Box::new(int_literal(size, span))),
+        span,
+    }
+}
+// 指针加减整数按元素大小缩放，两个指针相减得到元素个数，不是指针运算时返回 None
+fn pointer_arith(op: &str, lhs: &ASTNode, rhs: &ASTNode) -> Option<ASTNode> {
+    let span = lhs.span().to(rhs.span());
+    let binary = |lhs: ASTNode, rhs: ASTNode| ASTNode::BinaryOp {
+        op: Some(op.to_string()),
+        lhs: Box::new(lhs),
+        rhs: Some(Box::new(rhs)),
+        span,
+    };
+    let lhs_type = decay(ASTNode::get_ast_type(lhs)?);
+    let rhs_type = decay(ASTNode::get_ast_type(rhs)?);
+    match (&lhs_type, &rhs_type) {
+        (Type::Pointer(elem), Type::Pointer(_)) if op == "-" && lhs_type == rhs_type => Some(
+            scale(binary(lhs.clone(), rhs.clone()), "/", type_size(elem)),
+        ),
+        (Type::Pointer(elem), Type::BaseType(_)) => Some(binary(
+            lhs.clone(),
+            scale(rhs.clone(), "*", type_size(elem)),
+        )),
+        // 整数加指针时把指针换到左边，表达式的类型取左边的
+        (Type::BaseType(_), Type::Pointer(elem)) if op == "+" => Some(binary(
+            rhs.clone(),
+            scale(lhs.clone(), "*", type_size(elem)),
+        )),
+        (Type::Pointer(_), _) | (_, Type::Pointer(_)) => {
+            semantic_error(
+                span,
+                format!(
+                    "invalid operands to binary {} (have '{}' and '{}')",
+                    op,
+                    type_name(&lhs_type),
+                    type_name(&rhs_type)
+                ),
+            );
+            Some(binary(lhs.clone(), rhs.clone()))
+        }
+        _ => None,
+    }
+}
+// 指针的 ++ 和 -- 移动一个元素，换成 += 和 -=，后缀的再减回去得到原来的值
+fn pointer_step(op: &str, expr: &ASTNode, prefix: bool, span: Span) -> Option<ASTNode> {
+    if op != "++" && op != "--" {
+        return None;
+    }
+    let Some(Type::Pointer(elem)) = ASTNode::get_ast_type(expr) else {
+        return None;
+    };
+    let size = type_size(&elem);
+    if size == 1 {
+        return None;
+    }
+    let (assign_op, undo_op) = if op == "++" { ("+=", "-") } else { ("-=", "+") };
+    let step = ASTNode::BinaryOp {
+        op: Some(assign_op.to_string()),
+        lhs: Box::new(expr.clone()),
+        rhs: Some(Box::new(int_literal(size, span))),
+        span,
+    };
+    if prefix {
+        Some(step)
+    } else {
+        Some(ASTNode::BinaryOp {
+            op: Some(undo_op.to_string()),
+            lhs: Box::new(step),
+            rhs: Some(Box::new(int_literal(size, span))),
+            span,
+        })
+    }
+}
+
 fn extract_assign(lhs: &ASTNode, node: &CSTNode) -> ASTNode {
     match node {
         CSTNode::AssignPrime {
             op,
-            logical_or,
+            conditional,
             assign_prime,
+            ..
         } => {
-            if let Some(prime) = assign_prime {
-                let rhs = extract_assign(&ASTNode::from_cst(logical_or), prime);
-                if !check_types(lhs, &rhs) {
-                    println!("lhs's types is different from rhs, {:?} {:?}", lhs, rhs);
-                    unreachable!();
-                }
-                ASTNode::BinaryOp {
-                    op: Some(op.clone()),
-                    lhs: Box::new(lhs.clone()),
-                    rhs: Some(Box::new(rhs)),
-                }
+            if !is_lvalue(lhs) {
+                semantic_error(
+                    lhs.span(),
+                    String::from("lvalue required as left operand of assignment"),
+                );
+            } else if matches!(ASTNode::get_ast_type(lhs), Some(Type::Array(..))) {
+                semantic_error(
+                    lhs.span(),
+                    String::from("assignment to expression with array type"),
+                );
+            }
+            let rhs = if let Some(prime) = assign_prime {
+                extract_assign(&ASTNode::from_cst(conditional), prime)
             } else {
-                let rhs = ASTNode::from_cst(logical_or);
-                if !check_types(lhs, &rhs) {
-                    println!("lhs's types is different from rhs, {:?} {:?}", lhs, rhs);
-                    unreachable!();
+                ASTNode::from_cst(conditional)
+            };
+            if op != "=" {
+                check_struct_operands(op, lhs, &rhs);
+            }
+            // 指针的 += 和 -= 按元素大小缩放
+            let rhs = match ASTNode::get_ast_type(lhs) {
+                Some(Type::Pointer(elem)) if op == "+=" || op == "-=" => {
+                    scale(rhs, "*", type_size(&elem))
                 }
-                ASTNode::BinaryOp {
-                    op: Some(op.clone()),
-                    lhs: Box::new(lhs.clone()),
-                    rhs: Some(Box::new(rhs)),
+                Some(ty) => {
+                    if let Some(message) = conversion_error(&ty, &rhs, true) {
+                        semantic_error(rhs.span(), message);
+                    }
+                    rhs
                 }
+                None => rhs,
+            };
+            ASTNode::BinaryOp {
+                span: lhs.span().to(rhs.span()),
+                op: Some(op.clone()),
+                lhs: Box::new(lhs.clone()),
+                rhs: Some(Box::new(rhs)),
             }
         }
         _ => unreachable!(),
     }
 }
+// 逗号左边的值丢掉，整个表达式的类型和值都是最右边的操作数
+fn extract_comma(lhs: &ASTNode, node: &CSTNode) -> ASTNode {
+    match node {
+        CSTNode::CommaPrime {
+            op,
+            assign,
+            comma_prime,
+            ..
+        } => {
+            let rhs = ASTNode::from_cst(assign);
+            let ast_node = ASTNode::BinaryOp {
+                span: lhs.span().to(rhs.span()),
+                op: Some(op.clone()),
+                lhs: Box::new(lhs.clone()),
+                rhs: Some(Box::new(rhs)),
+            };
+            if let Some(prime) = comma_prime {
+                extract_comma(&ast_node, prime)
+            } else {
+                ast_node
+            }
+        }
+        _ => unreachable!(),
+    }
+}
+// 条件运算符的两个分支要能转换成同一个类型
+fn check_conditional_arms(then_expr: &ASTNode, else_expr: &ASTNode, span: Span) {
+    if let (Some(then_ty), Some(else_ty)) = (
+        ASTNode::get_ast_type(then_expr),
+        ASTNode::get_ast_type(else_expr),
+    ) && conditional_type(then_expr, else_expr).is_none()
+    {
+        semantic_error(
+            span,
+            format!(
+                "type mismatch in conditional expression ('{}' and '{}')",
+                type_name(&then_ty),
+                type_name(&else_ty)
+            ),
+        );
+    }
+}
+// && 和 || 的两边只要是标量就行，指针和整数可以混用
 fn extract_logical_or(lhs: &ASTNode, node: &CSTNode) -> ASTNode {
     match node {
         CSTNode::LogicalOrPrime {
             op,
             logical_and,
             logical_or_prime,
+            ..
         } => {
             if let Some(prime) = logical_or_prime {
                 let rhs = ASTNode::from_cst(logical_and);
-                if !check_types(lhs, &rhs) {
-                    println!("lhs's types is different from rhs, {:?} {:?}", lhs, rhs);
-                    unreachable!();
-                }
+                check_struct_operands(op, lhs, &rhs);
                 let ast_node = ASTNode::BinaryOp {
+                    span: lhs.span().to(rhs.span()),
                     op: Some(op.clone()),
                     lhs: Box::new(lhs.clone()),
                     rhs: Some(Box::new(rhs)),
@@ -616,11 +1718,9 @@ fn extract_logical_or(lhs: &ASTNode, node: &CSTNode) -> ASTNode {
                 extract_logical_or(&ast_node, prime)
             } else {
                 let rhs = ASTNode::from_cst(logical_and);
-                if !check_types(lhs, &rhs) {
-                    println!("lhs's types is different from rhs, {:?} {:?}", lhs, rhs);
-                    unreachable!();
-                }
+                check_struct_operands(op, lhs, &rhs);
                 ASTNode::BinaryOp {
+                    span: lhs.span().to(rhs.span()),
                     op: Some(op.clone()),
                     lhs: Box::new(lhs.clone()),
                     rhs: Some(Box::new(rhs)),
@@ -636,28 +1736,121 @@ fn extract_logical_and(lhs: &ASTNode, node: &CSTNode) -> ASTNode {
     match node {
         CSTNode::LogicalAndPrime {
             op,
-            equality,
+            bit_or,
             logical_and_prime,
+            ..
         } => {
             if let Some(prime) = logical_and_prime {
-                let rhs = ASTNode::from_cst(equality);
-                if !check_types(lhs, &rhs) {
-                    println!("lhs's types is different from rhs, {:?} {:?}", lhs, rhs);
-                    unreachable!();
-                }
+                let rhs = ASTNode::from_cst(bit_or);
+                check_struct_operands(op, lhs, &rhs);
                 let ast_node = ASTNode::BinaryOp {
+                    span: lhs.span().to(rhs.span()),
                     op: Some(op.clone()),
                     lhs: Box::new(lhs.clone()),
                     rhs: Some(Box::new(rhs)),
                 };
                 extract_logical_and(&ast_node, prime)
             } else {
-                let rhs = ASTNode::from_cst(equality);
-                if !check_types(lhs, &rhs) {
-                    println!("lhs's types is different from rhs, {:?} {:?}", lhs, rhs);
-                    unreachable!();
+                let rhs = ASTNode::from_cst(bit_or);
+                check_struct_operands(op, lhs, &rhs);
+                ASTNode::BinaryOp {
+                    span: lhs.span().to(rhs.span()),
+                    op: Some(op.clone()),
+                    lhs: Box::new(lhs.clone()),
+                    rhs: Some(Box::new(rhs)),
                 }
+            }
+        }
+        _ => unreachable!(),
+    }
+}
+fn extract_bit_or(lhs: &ASTNode, node: &CSTNode) -> ASTNode {
+    match node {
+        CSTNode::BitOrPrime {
+            op,
+            bit_xor,
+            bit_or_prime,
+            ..
+        } => {
+            if let Some(prime) = bit_or_prime {
+                let rhs = ASTNode::from_cst(bit_xor);
+                check_binary_operands(op, lhs, &rhs);
+                let ast_node = ASTNode::BinaryOp {
+                    span: lhs.span().to(rhs.span()),
+                    op: Some(op.clone()),
+                    lhs: Box::new(lhs.clone()),
+                    rhs: Some(Box::new(rhs)),
+                };
+                extract_bit_or(&ast_node, prime)
+            } else {
+                let rhs = ASTNode::from_cst(bit_xor);
+                check_binary_operands(op, lhs, &rhs);
+                ASTNode::BinaryOp {
+                    span: lhs.span().to(rhs.span()),
+                    op: Some(op.clone()),
+                    lhs: Box::new(lhs.clone()),
+                    rhs: Some(Box::new(rhs)),
+                }
+            }
+        }
+        _ => unreachable!(),
+    }
+}
+fn extract_bit_xor(lhs: &ASTNode, node: &CSTNode) -> ASTNode {
+    match node {
+        CSTNode::BitXorPrime {
+            op,
+            bit_and,
+            bit_xor_prime,
+            ..
+        } => {
+            if let Some(prime) = bit_xor_prime {
+                let rhs = ASTNode::from_cst(bit_and);
+                check_binary_operands(op, lhs, &rhs);
+                let ast_node = ASTNode::BinaryOp {
+                    span: lhs.span().to(rhs.span()),
+                    op: Some(op.clone()),
+                    lhs: Box::new(lhs.clone()),
+                    rhs: Some(Box::new(rhs)),
+                };
+                extract_bit_xor(&ast_node, prime)
+            } else {
+                let rhs = ASTNode::from_cst(bit_and);
+                check_binary_operands(op, lhs, &rhs);
+                ASTNode::BinaryOp {
+                    span: lhs.span().to(rhs.span()),
+                    op: Some(op.clone()),
+                    lhs: Box::new(lhs.clone()),
+                    rhs: Some(Box::new(rhs)),
+                }
+            }
+        }
+        _ => unreachable!(),
+    }
+}
+fn extract_bit_and(lhs: &ASTNode, node: &CSTNode) -> ASTNode {
+    match node {
+        CSTNode::BitAndPrime {
+            op,
+            equality,
+            bit_and_prime,
+            ..
+        } => {
+            if let Some(prime) = bit_and_prime {
+                let rhs = ASTNode::from_cst(equality);
+                check_binary_operands(op, lhs, &rhs);
+                let ast_node = ASTNode::BinaryOp {
+                    span: lhs.span().to(rhs.span()),
+                    op: Some(op.clone()),
+                    lhs: Box::new(lhs.clone()),
+                    rhs: Some(Box::new(rhs)),
+                };
+                extract_bit_and(&ast_node, prime)
+            } else {
+                let rhs = ASTNode::from_cst(equality);
+                check_binary_operands(op, lhs, &rhs);
                 ASTNode::BinaryOp {
+                    span: lhs.span().to(rhs.span()),
                     op: Some(op.clone()),
                     lhs: Box::new(lhs.clone()),
                     rhs: Some(Box::new(rhs)),
@@ -673,14 +1866,13 @@ fn extract_equality(lhs: &ASTNode, node: &CSTNode) -> ASTNode {
             op,
             comparison,
             equality_prime,
+            ..
         } => {
             if let Some(prime) = equality_prime {
                 let rhs = ASTNode::from_cst(comparison);
-                if !check_types(lhs, &rhs) {
-                    println!("lhs's types is different from rhs, {:?} {:?}", lhs, rhs);
-                    unreachable!();
-                }
+                check_binary_operands(op, lhs, &rhs);
                 let ast_node = ASTNode::BinaryOp {
+                    span: lhs.span().to(rhs.span()),
                     op: Some(op.clone()),
                     lhs: Box::new(lhs.clone()),
                     rhs: Some(Box::new(rhs)),
@@ -688,11 +1880,9 @@ fn extract_equality(lhs: &ASTNode, node: &CSTNode) -> ASTNode {
                 extract_equality(&ast_node, prime)
             } else {
                 let rhs = ASTNode::from_cst(comparison);
-                if !check_types(lhs, &rhs) {
-                    println!("lhs's types is different from rhs, {:?} {:?}", lhs, rhs);
-                    unreachable!();
-                }
+                check_binary_operands(op, lhs, &rhs);
                 ASTNode::BinaryOp {
+                    span: lhs.span().to(rhs.span()),
                     op: Some(op.clone()),
                     lhs: Box::new(lhs.clone()),
                     rhs: Some(Box::new(rhs)),
@@ -706,28 +1896,25 @@ fn extract_comparison(lhs: &ASTNode, node: &CSTNode) -> ASTNode {
     match node {
         CSTNode::ComparisonPrime {
             op,
-            term,
+            shift,
             comparison_prime,
+            ..
         } => {
             if let Some(prime) = comparison_prime {
-                let rhs = ASTNode::from_cst(term);
-                if !check_types(lhs, &rhs) {
-                    println!("lhs's types is different from rhs, {:?} {:?}", lhs, rhs);
-                    unreachable!();
-                }
+                let rhs = ASTNode::from_cst(shift);
+                check_binary_operands(op, lhs, &rhs);
                 let ast_node = ASTNode::BinaryOp {
+                    span: lhs.span().to(rhs.span()),
                     op: Some(op.clone()),
                     lhs: Box::new(lhs.clone()),
                     rhs: Some(Box::new(rhs)),
                 };
                 extract_comparison(&ast_node, prime)
             } else {
-                let rhs = ASTNode::from_cst(term);
-                if !check_types(lhs, &rhs) {
-                    println!("lhs's types is different from rhs, {:?} {:?}", lhs, rhs);
-                    unreachable!();
-                }
+                let rhs = ASTNode::from_cst(shift);
+                check_binary_operands(op, lhs, &rhs);
                 ASTNode::BinaryOp {
+                    span: lhs.span().to(rhs.span()),
                     op: Some(op.clone()),
                     lhs: Box::new(lhs.clone()),
                     rhs: Some(Box::new(rhs)),
@@ -737,36 +1924,60 @@ fn extract_comparison(lhs: &ASTNode, node: &CSTNode) -> ASTNode {
         _ => unreachable!(),
     }
 }
+// 移位的两个操作数各自做整型提升，结果类型只看左边，两边不用一样但都要是整数
+fn extract_shift(lhs: &ASTNode, node: &CSTNode) -> ASTNode {
+    match node {
+        CSTNode::ShiftPrime {
+            op,
+            term,
+            shift_prime,
+            ..
+        } => {
+            let rhs = ASTNode::from_cst(term);
+            check_binary_operands(op, lhs, &rhs);
+            // uint 的右移是逻辑右移，用 >>> 和算术右移区分开
+            let op = if op == ">>" && is_unsigned(lhs) {
+                String::from(">>>")
+            } else {
+                op.clone()
+            };
+            let ast_node = ASTNode::BinaryOp {
+                span: lhs.span().to(rhs.span()),
+                op: Some(op),
+                lhs: Box::new(lhs.clone()),
+                rhs: Some(Box::new(rhs)),
+            };
+            if let Some(prime) = shift_prime {
+                extract_shift(&ast_node, prime)
+            } else {
+                ast_node
+            }
+        }
+        _ => unreachable!(),
+    }
+}
 fn extract_term(lhs: &ASTNode, node: &CSTNode) -> ASTNode {
     match node {
         CSTNode::TermPrime {
             op,
             factor,
             term_prime,
+            ..
         } => {
-            if let Some(prime) = term_prime {
-                let rhs = ASTNode::from_cst(factor);
-                if !check_types(lhs, &rhs) {
-                    println!("lhs's types is different from rhs, {:?} {:?}", lhs, rhs);
-                    unreachable!();
-                }
-                let ast_node = ASTNode::BinaryOp {
-                    op: Some(op.clone()),
-                    lhs: Box::new(lhs.clone()),
-                    rhs: Some(Box::new(rhs)),
-                };
-                extract_term(&ast_node, prime)
-            } else {
-                let rhs = ASTNode::from_cst(factor);
-                if !check_types(lhs, &rhs) {
-                    println!("lhs's types is different from rhs, {:?} {:?}", lhs, rhs);
-                    unreachable!();
-                }
+            let rhs = ASTNode::from_cst(factor);
+            let ast_node = pointer_arith(op, lhs, &rhs).unwrap_or_else(|| {
+                check_binary_operands(op, lhs, &rhs);
                 ASTNode::BinaryOp {
+                    span: lhs.span().to(rhs.span()),
                     op: Some(op.clone()),
                     lhs: Box::new(lhs.clone()),
                     rhs: Some(Box::new(rhs)),
                 }
+            });
+            if let Some(prime) = term_prime {
+                extract_term(&ast_node, prime)
+            } else {
+                ast_node
             }
         }
         _ => unreachable!(),
@@ -778,14 +1989,13 @@ fn extract_factor(lhs: &ASTNode, node: &CSTNode) -> ASTNode {
             op,
             unary,
             factor_prime,
+            ..
         } => {
             if let Some(prime) = factor_prime {
                 let rhs = ASTNode::from_cst(unary);
-                if !check_types(lhs, &rhs) {
-                    println!("lhs's types is different from rhs, {:?} {:?}", lhs, rhs);
-                    unreachable!();
-                }
+                check_binary_operands(op, lhs, &rhs);
                 let ast_node = ASTNode::BinaryOp {
+                    span: lhs.span().to(rhs.span()),
                     op: Some(op.clone()),
                     lhs: Box::new(lhs.clone()),
                     rhs: Some(Box::new(rhs)),
@@ -793,11 +2003,9 @@ fn extract_factor(lhs: &ASTNode, node: &CSTNode) -> ASTNode {
                 extract_factor(&ast_node, prime)
             } else {
                 let rhs = ASTNode::from_cst(unary);
-                if !check_types(lhs, &rhs) {
-                    println!("lhs's types is different from rhs, {:?} {:?}", lhs, rhs);
-                    unreachable!();
-                }
+                check_binary_operands(op, lhs, &rhs);
                 ASTNode::BinaryOp {
+                    span: lhs.span().to(rhs.span()),
                     op: Some(op.clone()),
                     lhs: Box::new(lhs.clone()),
                     rhs: Some(Box::new(rhs)),
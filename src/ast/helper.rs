@@ -1,6 +1,7 @@
 use super::{SymbolInfo, SymbolKey};
 use crate::ast::DEFAULT_OFFSET;
-use crate::ast::types::{ASTNode, Type, VarDec};
+use crate::ast::types::{ASTNode, SemanticError, StructType, Type, VarDec, int_value};
+use crate::utils::types::Span;
 use once_cell::sync::Lazy;
 use std::collections::HashMap;
 use std::fmt;
@@ -12,15 +13,51 @@ static FUNC_SYMBOL_TABLE: Lazy<Mutex<HashMap<String, HashMap<SymbolKey, SymbolIn
     Lazy::new(|| Mutex::new(HashMap::new()));
 pub static GLOBAL_SCOPE: Lazy<Mutex<usize>> = Lazy::new(|| Mutex::new(0));
 pub static CURRENT_FUNC: Lazy<Mutex<String>> = Lazy::new(|| Mutex::new(String::new()));
+// 当前语句外面套了几层循环
+pub static LOOP_DEPTH: Lazy<Mutex<usize>> = Lazy::new(|| Mutex::new(0));
+// 外层每个 switch 里已经出现的 case 值和位置，最内层在最后，default 的值为 None
+type CaseLabels = Vec<(Option<i32>, Span)>;
+static SWITCH_CASES: Lazy<Mutex<Vec<CaseLabels>>> = Lazy::new(|| Mutex::new(Vec::new()));
+// 当前函数里定义的标号和 goto 用到的标号，函数结束时检查
+#[derive(Default)]
+struct FuncLabels {
+    defined: Vec<(String, Span)>,
+    used: Vec<(String, Span)>,
+}
+static LABELS: Lazy<Mutex<FuncLabels>> = Lazy::new(|| Mutex::new(FuncLabels::default()));
+static SEMANTIC_ERRORS: Lazy<Mutex<Vec<SemanticError>>> = Lazy::new(|| Mutex::new(Vec::new()));
+// 结构体标签和变量名不在一个名字空间里，按 (所在函数, 作用域, 标签) 记录，全局的函数名为空
+type TagKey = (String, usize, String);
+static STRUCT_TAGS: Lazy<Mutex<HashMap<TagKey, StructType>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+// 定义过的结构体，按出现的顺序，-fdump-record-layouts 用
+static STRUCT_DEFS: Lazy<Mutex<Vec<StructType>>> = Lazy::new(|| Mutex::new(Vec::new()));
+// 顶层声明过的名字，检查前后的声明是否一致，后端据此决定导出符号还是引用别的文件里的
+#[derive(Debug, Clone)]
+pub struct GlobalDecl {
+    pub ty: Type,
+    // 函数才有，形参的类型
+    pub params: Option<Vec<Type>>,
+    pub is_static: bool,
+    // 函数写了函数体，变量没有写 extern 或者有初始值
+    pub defined: bool,
+    // 变量在编译时算出来的初始值，整数或者 符号+偏移，数组每个元素一个，末尾的 0 省掉
+    pub init: Option<Vec<String>>,
+    pub span: Span,
+}
+static GLOBAL_DECLS: Lazy<Mutex<HashMap<String, GlobalDecl>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+// 字符串常量的内容，下标就是标签 .LC 后面的编号
+static STRINGS: Lazy<Mutex<Vec<Vec<u8>>>> = Lazy::new(|| Mutex::new(Vec::new()));
 
 impl ASTNode {
     pub fn print_tree(&self, indent: usize) -> String {
         let indent_str = "  ".repeat(indent);
         match self {
-            ASTNode::Specifier(ty) => {
+            ASTNode::Specifier(ty, _) => {
                 format!("{}Specifier: {}", indent_str, Self::print_type(ty))
             }
-            ASTNode::Program { items } => {
+            ASTNode::Program { items, .. } => {
                 let items_str: Vec<String> = items
                     .iter()
                     .map(|item| item.print_tree(indent + 1))
@@ -32,6 +69,7 @@ impl ASTNode {
                 params,
                 ret_type,
                 body,
+                ..
             } => {
                 let params_str: Vec<String> = params
                     .iter()
@@ -49,14 +87,34 @@ impl ASTNode {
                     body.print_tree(indent + 1)
                 )
             }
-            ASTNode::VarDecl(vars) => {
+            ASTNode::FuncDecl {
+                name,
+                params,
+                ret_type,
+                ..
+            } => {
+                let params_str: Vec<String> = params
+                    .iter()
+                    .map(|param| format!("{}  {}", indent_str, Self::print_vardec(param)))
+                    .collect();
+                format!(
+                    "{}FuncDecl: {}\n{}  ReturnType: {}\n{}  Parameters:\n{}",
+                    indent_str,
+                    name,
+                    indent_str,
+                    Self::print_type(ret_type),
+                    indent_str,
+                    params_str.join("\n")
+                )
+            }
+            ASTNode::VarDecl(vars, _) => {
                 let vars_str: Vec<String> = vars
                     .iter()
                     .map(|var| format!("{}  {}", indent_str, Self::print_vardec(var)))
                     .collect();
                 format!("{}VarDecl:\n{}", indent_str, vars_str.join("\n"))
             }
-            ASTNode::Block { stmts } => {
+            ASTNode::Block { stmts, .. } => {
                 let stmts_str: Vec<String> = stmts
                     .iter()
                     .map(|stmt| stmt.print_tree(indent + 1))
@@ -67,6 +125,7 @@ impl ASTNode {
                 cond,
                 then_br,
                 else_br,
+                ..
             } => {
                 let else_str = match else_br {
                     Some(else_branch) => format!(
@@ -86,7 +145,24 @@ impl ASTNode {
                     else_str
                 )
             }
-            ASTNode::While { cond, body } => {
+            ASTNode::Ternary {
+                cond,
+                then_expr,
+                else_expr,
+                ..
+            } => {
+                format!(
+                    "{}Ternary:\n{}  Condition:\n{}\n{}  Then:\n{}\n{}  Else:\n{}",
+                    indent_str,
+                    indent_str,
+                    cond.print_tree(indent + 2),
+                    indent_str,
+                    then_expr.print_tree(indent + 2),
+                    indent_str,
+                    else_expr.print_tree(indent + 2)
+                )
+            }
+            ASTNode::While { cond, body, .. } => {
                 format!(
                     "{}While:\n{}  Condition:\n{}\n{}  Body:\n{}",
                     indent_str,
@@ -96,11 +172,73 @@ impl ASTNode {
                     body.print_tree(indent + 2)
                 )
             }
-            ASTNode::Return { expr } => match expr {
+            ASTNode::DoWhile { body, cond, .. } => {
+                format!(
+                    "{}DoWhile:\n{}  Body:\n{}\n{}  Condition:\n{}",
+                    indent_str,
+                    indent_str,
+                    body.print_tree(indent + 2),
+                    indent_str,
+                    cond.print_tree(indent + 2)
+                )
+            }
+            ASTNode::For {
+                init,
+                cond,
+                step,
+                body,
+                ..
+            } => {
+                let part = |n: &Option<Box<ASTNode>>| match n {
+                    Some(n) => n.print_tree(indent + 2),
+                    None => format!("{}    None", indent_str),
+                };
+                format!(
+                    "{}For:\n{}  Init:\n{}\n{}  Condition:\n{}\n{}  Step:\n{}\n{}  Body:\n{}",
+                    indent_str,
+                    indent_str,
+                    part(init),
+                    indent_str,
+                    part(cond),
+                    indent_str,
+                    part(step),
+                    indent_str,
+                    body.print_tree(indent + 2)
+                )
+            }
+            ASTNode::Switch { cond, body, .. } => {
+                format!(
+                    "{}Switch:\n{}  Condition:\n{}\n{}  Body:\n{}",
+                    indent_str,
+                    indent_str,
+                    cond.print_tree(indent + 2),
+                    indent_str,
+                    body.print_tree(indent + 2)
+                )
+            }
+            ASTNode::Case { value, body, .. } => {
+                let label = match value {
+                    Some(v) => format!("Case: {}", v),
+                    None => String::from("Default"),
+                };
+                format!("{}{}\n{}", indent_str, label, body.print_tree(indent + 1))
+            }
+            ASTNode::Break(_) => format!("{}Break", indent_str),
+            ASTNode::Continue(_) => format!("{}Continue", indent_str),
+            ASTNode::Goto(label, _) => format!("{}Goto: {}", indent_str, label),
+            ASTNode::Label { name, body, .. } => {
+                format!(
+                    "{}Label: {}\n{}",
+                    indent_str,
+                    name,
+                    body.print_tree(indent + 1)
+                )
+            }
+            ASTNode::Return { expr, .. } => match expr {
                 Some(e) => format!("{}Return:\n{}", indent_str, e.print_tree(indent + 1)),
                 None => format!("{}Return: None", indent_str),
             },
-            ASTNode::BinaryOp { op, lhs, rhs } => {
+            ASTNode::BinaryOp { op, lhs, rhs, .. } => {
                 let op_str = op.as_ref().map_or("None".to_string(), |s| s.clone());
                 let rhs_str = match rhs {
                     Some(r) => r.print_tree(indent + 2),
@@ -116,7 +254,7 @@ impl ASTNode {
                     rhs_str
                 )
             }
-            ASTNode::UnaryOp { op, expr } => {
+            ASTNode::UnaryOp { op, expr, .. } => {
                 format!(
                     "{}UnaryOp: {}\n{}",
                     indent_str,
@@ -124,7 +262,37 @@ impl ASTNode {
                     expr.print_tree(indent + 1)
                 )
             }
-            ASTNode::Call { func, args } => {
+            ASTNode::Deref { expr, ty, .. } => {
+                format!(
+                    "{}Deref: {}\n{}",
+                    indent_str,
+                    Self::print_type(ty),
+                    expr.print_tree(indent + 1)
+                )
+            }
+            ASTNode::Member {
+                expr,
+                field,
+                offset,
+                ..
+            } => {
+                format!(
+                    "{}Member: {} (offset {})\n{}",
+                    indent_str,
+                    field,
+                    offset,
+                    expr.print_tree(indent + 1)
+                )
+            }
+            ASTNode::PostfixOp { op, expr, .. } => {
+                format!(
+                    "{}PostfixOp: {}\n{}",
+                    indent_str,
+                    op,
+                    expr.print_tree(indent + 1)
+                )
+            }
+            ASTNode::Call { func, args, .. } => {
                 let args_str: Vec<String> =
                     args.iter().map(|arg| arg.print_tree(indent + 2)).collect();
                 format!(
@@ -135,6 +303,13 @@ impl ASTNode {
                     args_str.join("\n")
                 )
             }
+            ASTNode::InitList { items, .. } => {
+                let items_str: Vec<String> = items
+                    .iter()
+                    .map(|item| item.print_tree(indent + 1))
+                    .collect();
+                format!("{}InitList:\n{}", indent_str, items_str.join("\n"))
+            }
             ASTNode::Literal(value) => {
                 format!("{}Literal: {:?}", indent_str, value)
             }
@@ -148,9 +323,11 @@ impl ASTNode {
         match ty {
             Type::BaseType(name) => name.clone(),
             Type::CustomizedType(st) => {
-                let fields: Vec<String> = st.field.iter().map(|f| Self::print_vardec(f)).collect();
+                let fields: Vec<String> = st.field.iter().map(Self::print_vardec).collect();
                 format!("struct {} {{\n  {}\n}}", st.id, fields.join("\n  "))
             }
+            Type::Pointer(inner) => format!("{} *", Self::print_type(inner)),
+            Type::Array(elem, len) => format!("{} [{}]", Self::print_type(elem), len),
         }
     }
 
@@ -186,25 +363,24 @@ pub fn build_symbol_table(
         var_type: symbol.var_type.clone(),
         var_name: symbol.var_name.clone(),
         init: init_,
+        span: symbol.span,
     };
     let value: SymbolInfo = (var_dec, scope, params.clone(), DEFAULT_OFFSET);
     if func_name.is_empty() || params.is_some() {
         let sym = look_up_symbol_table(symbol.var_name.clone(), scope);
         if sym.is_some() && sym.unwrap().1 == scope {
-            println!("redefine {:?}", symbol);
+            println!("{}: redefine {:?}", symbol.span, symbol.var_name);
             unreachable!();
         }
         let mut table = SYMBOL_TABLE.lock().unwrap();
         table.insert(key, value);
     } else {
         if look_up_func_symbol_table(symbol.var_name.clone(), scope, func_name.clone()).is_some() {
-            println!("redefine {:?}", symbol);
+            println!("{}: redefine {:?}", symbol.span, symbol.var_name);
             unreachable!();
         }
         let mut func_symbol_table = FUNC_SYMBOL_TABLE.lock().unwrap();
-        let symbol_table = func_symbol_table
-            .entry(func_name.clone())
-            .or_insert_with(|| HashMap::new());
+        let symbol_table = func_symbol_table.entry(func_name.clone()).or_default();
         symbol_table.insert(key, value);
     }
 }
@@ -233,23 +409,83 @@ pub fn travel_symbol_table(
     scope: usize,
     func_name: String,
 ) -> Option<SymbolInfo> {
-    let result: Option<SymbolInfo> = SYMBOL_TABLE
+    // 局部变量从里往外找，都没有再找全局的，局部变量可以遮住同名的全局变量
+    let mut i = scope;
+    loop {
+        let ret = look_up_func_symbol_table(var_name.clone(), i, func_name.clone());
+        if ret.is_some() {
+            return ret;
+        }
+        if i == 0 {
+            break;
+        }
+        i -= 1;
+    }
+    SYMBOL_TABLE
         .lock()
         .unwrap()
         .iter()
         .filter(|((v, s), _)| v.eq(var_name) && *s <= scope)
         .max_by_key(|((_, s), _)| *s)
-        .map(|(_, v)| v.clone());
-    if result.is_some() {
-        return result;
+        .map(|(_, v)| v.clone())
+}
+
+// 离开作用域时删掉其中（包括更深层）声明的局部变量，之后同一层可以再声明同名变量
+pub fn remove_scope_symbols(func_name: String, scope: usize) {
+    let mut func_symbol_table = FUNC_SYMBOL_TABLE.lock().unwrap();
+    if let Some(symbol_table) = func_symbol_table.get_mut(&func_name) {
+        symbol_table.retain(|(_, s), _| *s < scope);
     }
-    let mut i = scope;
-    loop {
-        let ret = look_up_func_symbol_table(var_name.clone(), i, func_name.clone());
-        if i == 0 || ret.is_some() {
-            return ret;
+}
+
+fn tag_key(scope: usize, tag: &str) -> TagKey {
+    let func = if scope == 0 {
+        String::new()
+    } else {
+        get_current_func()
+    };
+    (func, scope, tag.to_string())
+}
+
+// 在当前作用域定义结构体，同一个作用域里同一个标签只能定义一次
+pub fn define_struct(st: &StructType) -> bool {
+    if !st.id.is_empty() {
+        let key = tag_key(get_current_scope_num(), &st.id);
+        let mut tags = STRUCT_TAGS.lock().unwrap();
+        if tags.get(&key).is_some_and(|prev| !prev.field.is_empty()) {
+            return false;
         }
-        i -= 1;
+        tags.insert(key, st.clone());
+    }
+    STRUCT_DEFS.lock().unwrap().push(st.clone());
+    true
+}
+
+// 从当前作用域往外找结构体标签
+pub fn lookup_struct(tag: &str) -> Option<StructType> {
+    let keys: Vec<TagKey> = (0..=get_current_scope_num())
+        .rev()
+        .map(|scope| tag_key(scope, tag))
+        .collect();
+    let tags = STRUCT_TAGS.lock().unwrap();
+    keys.iter().find_map(|key| tags.get(key).cloned())
+}
+
+// 离开作用域时删掉其中定义的结构体标签
+pub fn remove_scope_structs(scope: usize) {
+    let func = get_current_func();
+    STRUCT_TAGS
+        .lock()
+        .unwrap()
+        .retain(|(f, s, _), _| *f != func || *s < scope);
+}
+
+// 成员还不知道的结构体到标签表里找定义
+pub fn complete_struct(st: &StructType) -> StructType {
+    if st.field.is_empty() && !st.id.is_empty() {
+        lookup_struct(&st.id).unwrap_or_else(|| st.clone())
+    } else {
+        st.clone()
     }
 }
 
@@ -263,6 +499,203 @@ pub fn update_current_scope_num(next: usize) {
     *scope = next;
 }
 
+pub fn enter_loop() {
+    let mut depth = LOOP_DEPTH.lock().unwrap();
+    *depth += 1;
+}
+
+pub fn leave_loop() {
+    let mut depth = LOOP_DEPTH.lock().unwrap();
+    *depth -= 1;
+}
+
+pub fn in_loop() -> bool {
+    *LOOP_DEPTH.lock().unwrap() > 0
+}
+
+pub fn enter_switch() {
+    SWITCH_CASES.lock().unwrap().push(Vec::new());
+}
+
+pub fn leave_switch() {
+    SWITCH_CASES.lock().unwrap().pop();
+}
+
+pub fn in_switch() -> bool {
+    !SWITCH_CASES.lock().unwrap().is_empty()
+}
+
+// 把标号记到最内层的 switch 里，同一个值之前出现过时返回之前的位置
+pub fn add_case_label(value: Option<i32>, span: Span) -> Option<Span> {
+    let mut switches = SWITCH_CASES.lock().unwrap();
+    let cases = switches.last_mut()?;
+    if let Some((_, prev)) = cases.iter().find(|(v, _)| *v == value) {
+        return Some(*prev);
+    }
+    cases.push((value, span));
+    None
+}
+
+// 定义标号，同一个函数里已经定义过时返回之前的位置
+pub fn define_label(name: &str, span: Span) -> Option<Span> {
+    let mut labels = LABELS.lock().unwrap();
+    if let Some((_, prev)) = labels.defined.iter().find(|(n, _)| n == name) {
+        return Some(*prev);
+    }
+    labels.defined.push((name.to_string(), span));
+    None
+}
+
+pub fn use_label(name: &str, span: Span) {
+    LABELS.lock().unwrap().used.push((name.to_string(), span));
+}
+
+// 函数结束时 goto 到没定义的标号是错误，定义了没用到的标号给出警告
+pub fn check_labels() {
+    let labels = std::mem::take(&mut *LABELS.lock().unwrap());
+    for (name, span) in &labels.used {
+        if !labels.defined.iter().any(|(n, _)| n == name) {
+            semantic_error(*span, format!("use of undeclared label '{}'", name));
+        }
+    }
+    for (name, span) in &labels.defined {
+        if !labels.used.iter().any(|(n, _)| n == name) {
+            semantic_warning(*span, format!("label '{}' defined but not used", name));
+        }
+    }
+}
+
+pub fn semantic_error(span: Span, message: String) {
+    semantic_error_with_notes(span, message, Vec::new());
+}
+
+pub fn semantic_error_with_notes(span: Span, message: String, notes: Vec<String>) {
+    let mut errors = SEMANTIC_ERRORS.lock().unwrap();
+    errors.push(SemanticError {
+        span,
+        message,
+        notes,
+        warning: false,
+    });
+}
+
+pub fn semantic_warning(span: Span, message: String) {
+    let mut errors = SEMANTIC_ERRORS.lock().unwrap();
+    errors.push(SemanticError {
+        span,
+        message,
+        notes: Vec::new(),
+        warning: true,
+    });
+}
+
+// 记下一个顶层声明，和前面同名的声明不一致时报错，storage 是写在前面的 extern 或 static
+pub fn declare_global(name: &str, decl: GlobalDecl, storage: Option<&str>) {
+    let mut decls = GLOBAL_DECLS.lock().unwrap();
+    let Some(prev) = decls.get(name).cloned() else {
+        decls.insert(name.to_string(), decl);
+        return;
+    };
+    let error = if prev.params.is_some() != decl.params.is_some() {
+        Some(format!("'{}' redeclared as different kind of symbol", name))
+    } else if prev.ty != decl.ty || prev.params != decl.params {
+        Some(format!(
+            "conflicting types for '{}'; have '{}'",
+            name,
+            decl_type_name(&decl)
+        ))
+    } else if prev.defined && decl.defined && decl.params.is_some()
+        || prev.init.is_some() && decl.init.is_some()
+    {
+        Some(format!("redefinition of '{}'", name))
+    } else if storage == Some("static") && !prev.is_static {
+        Some(format!(
+            "static declaration of '{}' follows non-static declaration",
+            name
+        ))
+    } else if storage.is_none() && decl.params.is_none() && prev.is_static {
+        // 函数不写存储类时沿用前面的 static，变量不行
+        Some(format!(
+            "non-static declaration of '{}' follows static declaration",
+            name
+        ))
+    } else {
+        None
+    };
+    if let Some(message) = error {
+        let what = if prev.defined {
+            "definition"
+        } else {
+            "declaration"
+        };
+        let note = format!(
+            "{}: note: previous {} of '{}' with type '{}'",
+            prev.span,
+            what,
+            name,
+            decl_type_name(&prev)
+        );
+        semantic_error_with_notes(decl.span, message, vec![note]);
+        return;
+    }
+    // 先声明后定义的，之后的诊断指向定义
+    let span = if decl.defined && !prev.defined || decl.init.is_some() && prev.init.is_none() {
+        decl.span
+    } else {
+        prev.span
+    };
+    decls.insert(
+        name.to_string(),
+        GlobalDecl {
+            is_static: prev.is_static,
+            defined: prev.defined || decl.defined,
+            init: prev.init.or(decl.init),
+            span,
+            ..decl
+        },
+    );
+}
+
+pub fn global_decl(name: &str) -> Option<GlobalDecl> {
+    GLOBAL_DECLS.lock().unwrap().get(name).cloned()
+}
+
+// 函数的类型和 gcc 一样写成 int(int, char *)
+fn decl_type_name(decl: &GlobalDecl) -> String {
+    match &decl.params {
+        Some(params) if params.is_empty() => format!("{}(void)", type_name(&decl.ty)),
+        Some(params) => format!(
+            "{}({})",
+            type_name(&decl.ty),
+            params.iter().map(type_name).collect::<Vec<_>>().join(", ")
+        ),
+        None => type_name(&decl.ty),
+    }
+}
+
+// 字符串常量放在数据段里，内容一样的共用一个标签
+pub fn string_label(bytes: &[u8]) -> String {
+    let mut strings = STRINGS.lock().unwrap();
+    let index = match strings.iter().position(|s| s == bytes) {
+        Some(index) => index,
+        None => {
+            strings.push(bytes.to_vec());
+            strings.len() - 1
+        }
+    };
+    format!(".LC{}", index)
+}
+
+// 取出用到的所有字符串常量，按标签的编号排好
+pub fn take_strings() -> Vec<Vec<u8>> {
+    std::mem::take(&mut *STRINGS.lock().unwrap())
+}
+
+// 取出目前为止的所有语义错误
+pub fn take_semantic_errors() -> Vec<SemanticError> {
+    std::mem::take(&mut *SEMANTIC_ERRORS.lock().unwrap())
+}
+
 pub fn set_current_func(func_name: String) {
     let mut name = CURRENT_FUNC.lock().unwrap();
     *name = func_name;
@@ -275,17 +708,224 @@ pub fn get_current_func() -> String {
 pub fn check_types(lhs: &ASTNode, rhs: &ASTNode) -> bool {
     let lhs_type = ASTNode::get_ast_type(lhs);
     let rhs_type = ASTNode::get_ast_type(rhs);
-    if lhs_type != rhs_type {
-        println!("lhs'type {:?}, rhs'type {:?}", lhs_type, rhs_type);
-        println!(
-            "lhs->{:?} rhs->{:?}",
-            lhs.clone().optimal(),
-            rhs.clone().optimal()
-        );
-        print_symbol_table();
-        false
+    // 结构体操作数已经报过错，不再比较
+    let is_struct = |ty: &Option<Type>| matches!(ty, Some(Type::CustomizedType(_)));
+    if is_struct(&lhs_type) || is_struct(&rhs_type) {
+        return true;
+    }
+    // 整数操作数先做整型提升和一般算术转换，char 和 int 可以混用
+    let both_integer =
+        matches!((&lhs_type, &rhs_type), (Some(l), Some(r)) if is_integer(l) && is_integer(r));
+    both_integer
+        || is_null_pointer_pair(&lhs_type, rhs)
+        || is_null_pointer_pair(&rhs_type, lhs)
+        || lhs_type.map(decay) == rhs_type.map(decay)
+}
+
+// char、bool、int 和 uint 都是整数类型
+pub fn is_integer(ty: &Type) -> bool {
+    matches!(ty, Type::BaseType(name) if matches!(name.as_str(), "char" | "bool" | "int" | "uint"))
+}
+
+// 整型提升：比 int 窄的类型提升成 int
+pub fn promote(ty: Type) -> Type {
+    match &ty {
+        Type::BaseType(name) if name == "char" || name == "bool" => {
+            Type::BaseType(String::from("int"))
+        }
+        _ => ty,
+    }
+}
+
+// 一般算术转换：两边提升之后有一边是 uint 时结果是 uint，否则是 int
+pub fn arith_type(lhs: Type, rhs: Type) -> Type {
+    let (lhs, rhs) = (promote(lhs), promote(rhs));
+    if rhs == Type::BaseType(String::from("uint")) {
+        rhs
     } else {
-        true
+        lhs
+    }
+}
+
+// 条件运算符的结果类型：两个分支都是整数时做一般算术转换，指针可以和空指针常量混用，
+// 其他情况两边的类型要一样，不一样时返回 None
+pub fn conditional_type(then_expr: &ASTNode, else_expr: &ASTNode) -> Option<Type> {
+    let then_ty = decay(ASTNode::get_ast_type(then_expr)?);
+    let else_ty = decay(ASTNode::get_ast_type(else_expr)?);
+    if is_integer(&then_ty) && is_integer(&else_ty) {
+        Some(arith_type(then_ty, else_ty))
+    } else if then_ty == else_ty || is_null_pointer_pair(&Some(then_ty.clone()), else_expr) {
+        Some(then_ty)
+    } else if is_null_pointer_pair(&Some(else_ty.clone()), then_expr) {
+        Some(else_ty)
+    } else {
+        None
+    }
+}
+
+// 一边是指针，另一边是空指针常量
+pub fn is_null_pointer_pair(ty: &Option<Type>, other: &ASTNode) -> bool {
+    matches!(ty.clone().map(decay), Some(Type::Pointer(_))) && is_null_pointer_constant(other)
+}
+
+// 表达式里的数组转换成指向第一个元素的指针
+pub fn decay(ty: Type) -> Type {
+    match ty {
+        Type::Array(elem, _) => Type::Pointer(elem),
+        other => other,
+    }
+}
+
+// 诊断信息里的类型名
+pub fn type_name(ty: &Type) -> String {
+    match ty {
+        Type::BaseType(name) => name.clone(),
+        Type::CustomizedType(st) if st.id.is_empty() => String::from("struct (anonymous)"),
+        Type::CustomizedType(st) => format!("struct {}", st.id),
+        // 和 gcc 一样写成 int ** 而不是 int * *
+        Type::Pointer(inner) => match inner.as_ref() {
+            Type::Pointer(_) => format!("{}*", type_name(inner)),
+            Type::Array(..) => {
+                let (elem, dims) = array_dims(inner);
+                format!("{} (*){}", type_name(elem), dims)
+            }
+            _ => format!("{} *", type_name(inner)),
+        },
+        // int[2][3]、int *[3]
+        Type::Array(..) => {
+            let (elem, dims) = array_dims(ty);
+            format!("{}{}", type_name(elem), dims)
+        }
+    }
+}
+
+// 多维数组最里面的元素类型和每一维的长度
+fn array_dims(ty: &Type) -> (&Type, String) {
+    match ty {
+        Type::Array(elem, len) => {
+            let (inner, dims) = array_dims(elem);
+            (inner, format!("[{}]{}", len, dims))
+        }
+        other => (other, String::new()),
+    }
+}
+
+// 数组一层层剥掉之后的元素类型
+pub fn element_type(ty: &Type) -> &Type {
+    match ty {
+        Type::Array(elem, _) => element_type(elem),
+        other => other,
+    }
+}
+
+// 类型占的字节数，指针运算按指向类型的大小缩放
+pub fn type_size(ty: &Type) -> usize {
+    match ty {
+        Type::BaseType(name) => match name.as_str() {
+            "char" | "bool" => 1,
+            "double" => 8,
+            _ => 4,
+        },
+        Type::CustomizedType(st) => struct_layout(st).1,
+        Type::Pointer(_) => 4,
+        Type::Array(elem, len) => type_size(elem) * len,
+    }
+}
+
+// ILP32 的对齐：基本类型和指针按自己的大小对齐，数组按元素对齐，结构体按成员里最大的对齐
+pub fn type_align(ty: &Type) -> usize {
+    match ty {
+        Type::BaseType(_) | Type::Pointer(_) => type_size(ty),
+        Type::Array(elem, _) => type_align(elem),
+        Type::CustomizedType(st) => struct_layout(st).2,
+    }
+}
+
+// 成员依次摆放，偏移补齐到成员的对齐，整个结构体的大小补齐到结构体的对齐
+// 返回每个成员的偏移、结构体的大小和对齐
+pub fn struct_layout(st: &StructType) -> (Vec<usize>, usize, usize) {
+    let st = complete_struct(st);
+    let mut offsets = Vec::new();
+    let mut size: usize = 0;
+    let mut align = 1;
+    for field in &st.field {
+        let field_align = type_align(&field.var_type);
+        size = size.div_ceil(field_align) * field_align;
+        offsets.push(size);
+        size += type_size(&field.var_type);
+        align = align.max(field_align);
+    }
+    (offsets, size.div_ceil(align) * align, align)
+}
+
+// 和 clang 的 -fdump-record-layouts 一样的格式，嵌套的结构体展开到里面的成员
+pub fn dump_record_layouts() -> String {
+    let defs = STRUCT_DEFS.lock().unwrap().clone();
+    let mut out = String::new();
+    for st in &defs {
+        let (_, size, align) = struct_layout(st);
+        out += "\n*** Dumping AST Record Layout\n";
+        dump_record(st, None, 0, 0, &mut out);
+        out += &format!("{:>10} | [sizeof={}, align={}]\n", "", size, align);
+    }
+    out
+}
+
+fn dump_record(st: &StructType, name: Option<&str>, base: usize, depth: usize, out: &mut String) {
+    let indent = "  ".repeat(depth);
+    let ty = Type::CustomizedType(st.clone());
+    match name {
+        Some(name) => *out += &format!("{:>10} | {}{} {}\n", base, indent, type_name(&ty), name),
+        None => *out += &format!("{:>10} | {}{}\n", base, indent, type_name(&ty)),
+    }
+    let (offsets, ..) = struct_layout(st);
+    for (field, offset) in st.field.iter().zip(offsets) {
+        match &field.var_type {
+            Type::CustomizedType(inner) => dump_record(
+                &complete_struct(inner),
+                Some(&field.var_name),
+                base + offset,
+                depth + 1,
+                out,
+            ),
+            ty => {
+                *out += &format!(
+                    "{:>10} | {}  {} {}\n",
+                    base + offset,
+                    indent,
+                    type_name(ty),
+                    field.var_name
+                )
+            }
+        }
+    }
+}
+
+// 值为 0 的整数常量，可以赋给任意指针，也可以和指针比较
+pub fn is_null_pointer_constant(node: &ASTNode) -> bool {
+    match node.clone().optimal() {
+        ASTNode::Literal(tok) => int_value(&tok) == Some(0),
+        _ => false,
+    }
+}
+
+pub fn is_unsigned(node: &ASTNode) -> bool {
+    ASTNode::get_ast_type(node) == Some(Type::BaseType(String::from("uint")))
+}
+
+// 能出现在赋值左边、能做 ++ 和 -- 的操作数的表达式：变量、解引用和左值的成员
+pub fn is_lvalue(node: &ASTNode) -> bool {
+    match node {
+        ASTNode::Ident(_) | ASTNode::Deref { .. } => true,
+        ASTNode::Member { expr, .. } => is_lvalue(expr),
+        // 括号和没有运算符的 BinaryOp 只是一层包装
+        ASTNode::BinaryOp {
+            op: None,
+            lhs,
+            rhs: None,
+            ..
+        } => is_lvalue(lhs),
+        _ => false,
     }
 }
 
@@ -303,9 +943,3 @@ pub fn print_symbol_table() {
         }
     }
 }
-
-pub fn check_entry_func() -> bool {
-    let table = SYMBOL_TABLE.lock().unwrap();
-    let key: SymbolKey = (String::from("main"), 0);
-    table.get(&key).cloned().is_some()
-}
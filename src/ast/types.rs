@@ -1,77 +1,248 @@
 use crate::lexer::lexer::Token;
-use crate::utils::types::PhraseType;
+use crate::utils::types::{NumType, Span, TokenKind};
+use std::fmt;
 
 use super::helper::{
-    get_current_func, get_current_scope_num, look_up_symbol_table, travel_symbol_table,
+    arith_type, conditional_type, decay, get_current_func, get_current_scope_num, is_integer,
+    look_up_symbol_table, promote, travel_symbol_table,
 };
 
+// 常量折叠时字面量的整数值
+pub fn int_value(tok: &Token) -> Option<i32> {
+    match tok.kind {
+        TokenKind::CharLit(v) => Some(v as i32),
+        TokenKind::IntLit(v, _) => Some(v as i32),
+        _ => None,
+    }
+}
+
+// 语义分析发现的错误和警告，收集起来在 ast_gen 结束后按发现的顺序统一报告
 #[derive(Debug, Clone, PartialEq)]
+pub struct SemanticError {
+    pub span: Span,
+    pub message: String,
+    pub notes: Vec<String>,
+    // 警告照常打印，但不算错误
+    pub warning: bool,
+}
+
+impl fmt::Display for SemanticError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let level = if self.warning { "warning" } else { "error" };
+        write!(f, "{}: {}: {}", self.span, level, self.message)?;
+        for note in &self.notes {
+            write!(f, "\n{}", note)?;
+        }
+        Ok(())
+    }
+}
+
+// field 为空表示还没定义的结构体，比如结构体里指向自己的指针
+#[derive(Debug, Clone)]
 pub struct StructType {
     pub id: String,
     pub field: Vec<VarDec>,
 }
+
+// 有标签的结构体按标签区分，不管成员是不是已经知道
+impl PartialEq for StructType {
+    fn eq(&self, other: &Self) -> bool {
+        if self.id.is_empty() && other.id.is_empty() {
+            self.field == other.field
+        } else {
+            self.id == other.id
+        }
+    }
+}
 #[derive(Debug, Clone, PartialEq)]
 pub enum Type {
     BaseType(String),
     CustomizedType(StructType),
+    // 指向的类型
+    Pointer(Box<Type>),
+    // 元素类型和元素个数，多维数组是数组的数组
+    Array(Box<Type>, usize),
 }
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone)]
 pub struct VarDec {
     pub var_type: Type,
     pub var_name: String,
     pub init: Option<ASTNode>,
+    pub span: Span,
+}
+
+// 位置不参与比较，同一个结构体在不同地方声明仍然是同一个类型
+impl PartialEq for VarDec {
+    fn eq(&self, other: &Self) -> bool {
+        self.var_type == other.var_type
+            && self.var_name == other.var_name
+            && self.init == other.init
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum ASTNode {
     // —— 程序、外部定义 ——
-    Specifier(Box<Type>),
+    Specifier(Box<Type>, Span),
     Program {
         items: Vec<ASTNode>, // 全部顶层声明／定义
+        span: Span,
     },
     FuncDef {
         name: String,
         params: Vec<VarDec>,
         ret_type: Type,
         body: Box<ASTNode>, // Block
+        span: Span,
     },
-    VarDecl(Vec<VarDec>),
+    // 函数原型，没有函数体
+    FuncDecl {
+        name: String,
+        params: Vec<VarDec>,
+        ret_type: Type,
+        span: Span,
+    },
+    VarDecl(Vec<VarDec>, Span),
 
     // —— 语句 ——
     Block {
         stmts: Vec<ASTNode>,
+        span: Span,
     },
     If {
         cond: Box<ASTNode>,
         then_br: Box<ASTNode>,
         else_br: Option<Box<ASTNode>>,
+        span: Span,
     },
     While {
         cond: Box<ASTNode>,
         body: Box<ASTNode>,
+        span: Span,
+    },
+    // 先执行一次循环体再判断条件
+    DoWhile {
+        body: Box<ASTNode>,
+        cond: Box<ASTNode>,
+        span: Span,
+    },
+    // init 是声明或表达式，cond 省略时一直循环
+    For {
+        init: Option<Box<ASTNode>>,
+        cond: Option<Box<ASTNode>>,
+        step: Option<Box<ASTNode>>,
+        body: Box<ASTNode>,
+        span: Span,
+    },
+    Switch {
+        cond: Box<ASTNode>,
+        body: Box<ASTNode>,
+        span: Span,
+    },
+    // case 标号的值在语义分析时已经算出来，default 为 None
+    Case {
+        value: Option<i32>,
+        body: Box<ASTNode>,
+        span: Span,
     },
     Return {
         expr: Option<Box<ASTNode>>,
+        span: Span,
+    },
+    Break(Span),
+    Continue(Span),
+    // goto 的标号在整个函数里有效
+    Goto(String, Span),
+    Label {
+        name: String,
+        body: Box<ASTNode>,
+        span: Span,
     },
     // —— 表达式 ——
     BinaryOp {
         op: Option<String>,
         lhs: Box<ASTNode>,
         rhs: Option<Box<ASTNode>>,
+        span: Span,
+    },
+    // cond ? then_expr : else_expr，只对选中的分支求值
+    Ternary {
+        cond: Box<ASTNode>,
+        then_expr: Box<ASTNode>,
+        else_expr: Box<ASTNode>,
+        span: Span,
     },
     UnaryOp {
         op: String,
         expr: Box<ASTNode>,
+        span: Span,
+    },
+    // 一元 *，ty 是指针指向的类型，决定读写内存的宽度
+    Deref {
+        expr: Box<ASTNode>,
+        ty: Type,
+        span: Span,
+    },
+    // 结构体成员，expr 是整个结构体，offset 是成员相对结构体开头的字节数
+    Member {
+        expr: Box<ASTNode>,
+        field: String,
+        offset: usize,
+        ty: Type,
+        span: Span,
+    },
+    // 后缀的 ++ 和 --，值是修改之前的值
+    PostfixOp {
+        op: String,
+        expr: Box<ASTNode>,
+        span: Span,
     },
     Call {
         func: String,
         args: Vec<ASTNode>,
+        span: Span,
+    },
+    // 花括号初始值，声明里的已经按数组元素摊平，没写的元素补了 0
+    InitList {
+        items: Vec<ASTNode>,
+        span: Span,
     },
     Literal(Token),
     Ident(Token),
 }
 
 impl ASTNode {
+    pub fn span(&self) -> Span {
+        match self {
+            ASTNode::Specifier(_, span)
+            | ASTNode::VarDecl(_, span)
+            | ASTNode::Break(span)
+            | ASTNode::Continue(span)
+            | ASTNode::Goto(_, span) => *span,
+            ASTNode::Program { span, .. }
+            | ASTNode::FuncDef { span, .. }
+            | ASTNode::FuncDecl { span, .. }
+            | ASTNode::Block { span, .. }
+            | ASTNode::If { span, .. }
+            | ASTNode::While { span, .. }
+            | ASTNode::DoWhile { span, .. }
+            | ASTNode::For { span, .. }
+            | ASTNode::Switch { span, .. }
+            | ASTNode::Case { span, .. }
+            | ASTNode::Label { span, .. }
+            | ASTNode::Return { span, .. }
+            | ASTNode::BinaryOp { span, .. }
+            | ASTNode::Ternary { span, .. }
+            | ASTNode::UnaryOp { span, .. }
+            | ASTNode::Deref { span, .. }
+            | ASTNode::Member { span, .. }
+            | ASTNode::PostfixOp { span, .. }
+            | ASTNode::Call { span, .. }
+            | ASTNode::InitList { span, .. } => *span,
+            ASTNode::Literal(tok) | ASTNode::Ident(tok) => tok.span,
+        }
+    }
+
     pub fn optimal(self) -> ASTNode {
         match self {
             // 冗余 BinaryOp: op == None，只有左节点，递归剥离
@@ -79,34 +250,47 @@ impl ASTNode {
                 op: None,
                 lhs,
                 rhs: None,
+                ..
             } => lhs.optimal(),
             // 常量折叠
             ASTNode::BinaryOp {
                 op: Some(ref op),
                 lhs,
                 rhs: Some(rhs),
+                span,
             } => {
                 let lhs = lhs.optimal();
                 let rhs = rhs.optimal();
                 if let (ASTNode::Literal(l), ASTNode::Literal(r)) = (&lhs, &rhs) {
-                    // 只支持整数
-                    if let (Ok(lv), Ok(rv)) = (l.value.parse::<i32>(), r.value.parse::<i32>()) {
+                    // 只支持整数和字符常量
+                    if let (Some(lv), Some(rv)) = (int_value(l), int_value(r)) {
                         let result = match op.as_str() {
-                            "+" => Some(lv + rv),
-                            "-" => Some(lv - rv),
-                            "*" => Some(lv * rv),
-                            "/" => Some(lv / rv),
+                            // 溢出时和 RV32 一样回绕
+                            "+" => Some(lv.wrapping_add(rv)),
+                            "-" => Some(lv.wrapping_sub(rv)),
+                            "*" => Some(lv.wrapping_mul(rv)),
+                            // 除数为 0 时不折叠，留到运行时
+                            "/" => lv.checked_div(rv),
+                            "%" => lv.checked_rem(rv),
+                            "&" => Some(lv & rv),
+                            "|" => Some(lv | rv),
+                            "^" => Some(lv ^ rv),
+                            // 和 RV32 一样只取移位量的低 5 位
+                            "<<" => Some(lv.wrapping_shl(rv as u32)),
+                            ">>" => Some(lv.wrapping_shr(rv as u32)),
+                            ">>>" => Some((lv as u32).wrapping_shr(rv as u32) as i32),
                             ">" => Some((lv > rv) as i32),
                             "<" => Some((lv < rv) as i32),
+                            ">=" => Some((lv >= rv) as i32),
+                            "<=" => Some((lv <= rv) as i32),
                             "==" => Some((lv == rv) as i32),
                             "!=" => Some((lv != rv) as i32),
                             _ => None,
                         };
                         if let Some(val) = result {
                             let token = Token {
-                                types: l.types.clone(),
-                                pos: l.pos,
-                                value: val.to_string(),
+                                kind: TokenKind::IntLit(val as i64 as u64, NumType::Int),
+                                span,
                             };
                             return ASTNode::Literal(token);
                         }
@@ -116,21 +300,95 @@ impl ASTNode {
                     op: Some(op.clone()),
                     lhs: Box::new(lhs),
                     rhs: Some(Box::new(rhs)),
+                    span,
                 }
             }
             // 其它 BinaryOp 递归处理
-            ASTNode::BinaryOp { op, lhs, rhs } => ASTNode::BinaryOp {
+            ASTNode::BinaryOp { op, lhs, rhs, span } => ASTNode::BinaryOp {
                 op,
                 lhs: Box::new(lhs.optimal()),
                 rhs: rhs.map(|n| Box::new(n.optimal())),
+                span,
             },
-            // 一元操作递归
-            ASTNode::UnaryOp { op, expr } => ASTNode::UnaryOp {
+            // 一元操作递归，操作数是常量时折叠
+            ASTNode::UnaryOp { op, expr, span } => {
+                let expr = expr.optimal();
+                // &*p 就是 p
+                if op == "&"
+                    && let ASTNode::Deref { expr: inner, .. } = expr
+                {
+                    return *inner;
+                }
+                if let ASTNode::Literal(tok) = &expr
+                    && let Some(v) = int_value(tok)
+                {
+                    let result = match op.as_str() {
+                        "-" => Some(v.wrapping_neg()),
+                        "!" => Some((v == 0) as i32),
+                        "~" => Some(!v),
+                        _ => None,
+                    };
+                    if let Some(val) = result {
+                        let token = Token {
+                            kind: TokenKind::IntLit(val as i64 as u64, NumType::Int),
+                            span,
+                        };
+                        return ASTNode::Literal(token);
+                    }
+                }
+                ASTNode::UnaryOp {
+                    op,
+                    expr: Box::new(expr),
+                    span,
+                }
+            }
+            // 条件是常量时直接换成选中的分支
+            ASTNode::Ternary {
+                cond,
+                then_expr,
+                else_expr,
+                span,
+            } => {
+                let cond = cond.optimal();
+                let then_expr = then_expr.optimal();
+                let else_expr = else_expr.optimal();
+                if let ASTNode::Literal(tok) = &cond
+                    && let Some(v) = int_value(tok)
+                {
+                    return if v != 0 { then_expr } else { else_expr };
+                }
+                ASTNode::Ternary {
+                    cond: Box::new(cond),
+                    then_expr: Box::new(then_expr),
+                    else_expr: Box::new(else_expr),
+                    span,
+                }
+            }
+            ASTNode::Deref { expr, ty, span } => ASTNode::Deref {
+                expr: Box::new(expr.optimal()),
+                ty,
+                span,
+            },
+            ASTNode::Member {
+                expr,
+                field,
+                offset,
+                ty,
+                span,
+            } => ASTNode::Member {
+                expr: Box::new(expr.optimal()),
+                field,
+                offset,
+                ty,
+                span,
+            },
+            ASTNode::PostfixOp { op, expr, span } => ASTNode::PostfixOp {
                 op,
                 expr: Box::new(expr.optimal()),
+                span,
             },
             // 变量声明，递归初始值
-            ASTNode::VarDecl(var_decls) => ASTNode::VarDecl(
+            ASTNode::VarDecl(var_decls, span) => ASTNode::VarDecl(
                 var_decls
                     .into_iter()
                     .map(|mut v| {
@@ -138,34 +396,80 @@ impl ASTNode {
                         v
                     })
                     .collect(),
+                span,
             ),
             // 块递归
-            ASTNode::Block { stmts } => ASTNode::Block {
+            ASTNode::Block { stmts, span } => ASTNode::Block {
                 stmts: stmts.into_iter().map(|n| n.optimal()).collect(),
+                span,
             },
             // If 分支递归
             ASTNode::If {
                 cond,
                 then_br,
                 else_br,
+                span,
             } => ASTNode::If {
                 cond: Box::new(cond.optimal()),
                 then_br: Box::new(then_br.optimal()),
                 else_br: else_br.map(|b| Box::new(b.optimal())),
+                span,
             },
             // While 递归
-            ASTNode::While { cond, body } => ASTNode::While {
+            ASTNode::While { cond, body, span } => ASTNode::While {
+                cond: Box::new(cond.optimal()),
+                body: Box::new(body.optimal()),
+                span,
+            },
+            ASTNode::DoWhile { body, cond, span } => ASTNode::DoWhile {
+                body: Box::new(body.optimal()),
+                cond: Box::new(cond.optimal()),
+                span,
+            },
+            // For 递归
+            ASTNode::For {
+                init,
+                cond,
+                step,
+                body,
+                span,
+            } => ASTNode::For {
+                init: init.map(|n| Box::new(n.optimal())),
+                cond: cond.map(|n| Box::new(n.optimal())),
+                step: step.map(|n| Box::new(n.optimal())),
+                body: Box::new(body.optimal()),
+                span,
+            },
+            // Switch 和 case 递归
+            ASTNode::Switch { cond, body, span } => ASTNode::Switch {
                 cond: Box::new(cond.optimal()),
                 body: Box::new(body.optimal()),
+                span,
+            },
+            ASTNode::Case { value, body, span } => ASTNode::Case {
+                value,
+                body: Box::new(body.optimal()),
+                span,
+            },
+            ASTNode::Label { name, body, span } => ASTNode::Label {
+                name,
+                body: Box::new(body.optimal()),
+                span,
             },
             // Return 递归
-            ASTNode::Return { expr } => ASTNode::Return {
+            ASTNode::Return { expr, span } => ASTNode::Return {
                 expr: expr.map(|e| Box::new(e.optimal())),
+                span,
             },
             // Call 递归
-            ASTNode::Call { func, args } => ASTNode::Call {
+            ASTNode::Call { func, args, span } => ASTNode::Call {
                 func,
                 args: args.into_iter().map(|a| a.optimal()).collect(),
+                span,
+            },
+            ASTNode::InitList { items, span } => ASTNode::InitList {
+                items: items.into_iter().map(|n| n.optimal()).collect(),
+                span,
             },
             // FuncDef 递归
             ASTNode::FuncDef {
@@ -173,6 +477,7 @@ impl ASTNode {
                 params,
                 ret_type,
                 body,
+                span,
             } => ASTNode::FuncDef {
                 name,
                 params: params
@@ -184,10 +489,12 @@ impl ASTNode {
                     .collect(),
                 ret_type,
                 body: Box::new(body.optimal()),
+                span,
             },
             // Program 递归
-            ASTNode::Program { items } => ASTNode::Program {
+            ASTNode::Program { items, span } => ASTNode::Program {
                 items: items.into_iter().map(|i| i.optimal()).collect(),
+                span,
             },
             // 其它节点直接返回
             node => node,
@@ -196,43 +503,192 @@ impl ASTNode {
     pub fn get_ast_type(node: &ASTNode) -> Option<Type> {
         match node {
             ASTNode::Ident(id) => {
-                let symbol_info =
-                    travel_symbol_table(&id.value, get_current_scope_num(), get_current_func());
+                let symbol_info = travel_symbol_table(
+                    &id.to_string(),
+                    get_current_scope_num(),
+                    get_current_func(),
+                );
                 if let Some(value) = symbol_info {
                     Some(value.0.var_type)
                 } else {
                     println!("{:?}", get_current_func());
-                    println!("{:?} is undefined", id);
+                    println!("{}: {:?} is undefined", id.span, id.to_string());
                     None
                 }
             }
-            ASTNode::Literal(literal) => match literal.types {
-                PhraseType::Bool => Some(Type::BaseType(String::from("bool"))),
-                PhraseType::String => Some(Type::BaseType(String::from("string"))),
-                PhraseType::Oct | PhraseType::Dec | PhraseType::Hex => {
-                    Some(Type::BaseType(String::from("int")))
+            ASTNode::Literal(literal) => match literal.kind {
+                TokenKind::BoolLit(_) => Some(Type::BaseType(String::from("bool"))),
+                // 字符串常量是 char 数组，算上末尾的 0
+                TokenKind::StrLit(ref bytes) => Some(Type::Array(
+                    Box::new(Type::BaseType(String::from("char"))),
+                    bytes.len() + 1,
+                )),
+                // C 里字符常量的类型是 int
+                TokenKind::CharLit(_) => Some(Type::BaseType(String::from("int"))),
+                TokenKind::IntLit(_, ty) if ty.is_unsigned() => {
+                    Some(Type::BaseType(String::from("uint")))
                 }
-                PhraseType::Float => Some(Type::BaseType(String::from("float"))),
-                PhraseType::Char => Some(Type::BaseType(String::from("char"))),
+                TokenKind::IntLit(..) => Some(Type::BaseType(String::from("int"))),
+                TokenKind::FloatLit(_, NumType::Float) => {
+                    Some(Type::BaseType(String::from("float")))
+                }
+                TokenKind::FloatLit(..) => Some(Type::BaseType(String::from("double"))),
                 _ => unreachable!(),
             },
+            // 逗号表达式的类型是右边操作数的类型
+            ASTNode::BinaryOp {
+                op: Some(op),
+                rhs: Some(rhs),
+                ..
+            } if op == "," => ASTNode::get_ast_type(rhs),
+            ASTNode::Ternary {
+                then_expr,
+                else_expr,
+                ..
+            } => conditional_type(then_expr, else_expr),
+            // 两个指针相减得到的是相差的元素个数
+            ASTNode::BinaryOp {
+                op: Some(op),
+                lhs,
+                rhs: Some(rhs),
+                ..
+            } if op == "-"
+                && matches!(
+                    ASTNode::get_ast_type(lhs).map(decay),
+                    Some(Type::Pointer(_))
+                )
+                && matches!(
+                    ASTNode::get_ast_type(rhs).map(decay),
+                    Some(Type::Pointer(_))
+                ) =>
+            {
+                Some(Type::BaseType(String::from("int")))
+            }
+            // 指针比较和取反的结果是 int
+            ASTNode::BinaryOp {
+                op: Some(op), lhs, ..
+            } if matches!(
+                op.as_str(),
+                "==" | "!=" | "<" | "<=" | ">" | ">=" | "&&" | "||"
+            ) && matches!(
+                ASTNode::get_ast_type(lhs).map(decay),
+                Some(Type::Pointer(_))
+            ) =>
+            {
+                Some(Type::BaseType(String::from("int")))
+            }
+            ASTNode::UnaryOp { op, expr, .. }
+                if op == "!"
+                    && matches!(
+                        ASTNode::get_ast_type(expr).map(decay),
+                        Some(Type::Pointer(_))
+                    ) =>
+            {
+                Some(Type::BaseType(String::from("int")))
+            }
+            ASTNode::Deref { ty, .. } | ASTNode::Member { ty, .. } => Some(ty.clone()),
+            ASTNode::UnaryOp { op, expr, .. } if op == "&" => {
+                ASTNode::get_ast_type(expr).map(|ty| Type::Pointer(Box::new(ty)))
+            }
+            // 比较和逻辑运算的结果是 int
+            ASTNode::BinaryOp { op: Some(op), .. }
+                if matches!(
+                    op.as_str(),
+                    "==" | "!=" | "<" | ">" | "<=" | ">=" | "&&" | "||"
+                ) =>
+            {
+                Some(Type::BaseType(String::from("int")))
+            }
+            ASTNode::UnaryOp { op, .. } if op == "!" => Some(Type::BaseType(String::from("int"))),
+            // 整数的算术和位运算按一般算术转换得到结果类型，移位只看左边提升之后的类型
+            ASTNode::BinaryOp {
+                op: Some(op),
+                lhs,
+                rhs: Some(rhs),
+                ..
+            } if matches!(
+                op.as_str(),
+                "+" | "-" | "*" | "/" | "%" | "&" | "|" | "^" | "<<" | ">>" | ">>>"
+            ) =>
+            {
+                let lhs_type = ASTNode::get_ast_type(lhs);
+                match (lhs_type, ASTNode::get_ast_type(rhs)) {
+                    (Some(l), Some(r)) if is_integer(&l) && is_integer(&r) => {
+                        if matches!(op.as_str(), "<<" | ">>" | ">>>") {
+                            Some(promote(l))
+                        } else {
+                            Some(arith_type(l, r))
+                        }
+                    }
+                    // 指针加减整数还是指针，其他的操作数不对时已经报过错
+                    (Some(l), _)
+                        if (op == "+" || op == "-")
+                            && matches!(decay(l.clone()), Type::Pointer(_)) =>
+                    {
+                        Some(l)
+                    }
+                    _ => None,
+                }
+            }
+            ASTNode::UnaryOp { op, expr, .. } if matches!(op.as_str(), "-" | "~") => {
+                ASTNode::get_ast_type(expr).map(promote)
+            }
             ASTNode::BinaryOp { lhs, .. } => {
                 let lhs_ = lhs.as_ref();
                 ASTNode::get_ast_type(lhs_)
             }
-            ASTNode::UnaryOp { expr, .. } => {
+            ASTNode::UnaryOp { expr, .. } | ASTNode::PostfixOp { expr, .. } => {
                 let expr_ = expr.as_ref();
                 ASTNode::get_ast_type(expr_)
             }
+            // 没有声明的函数已经报过错，和 C 的老规矩一样当作返回 int
             ASTNode::Call { func, .. } => {
                 let symbol = look_up_symbol_table(func.clone(), 0);
                 if let Some(fun) = symbol {
                     Some(fun.0.var_type)
                 } else {
-                    None
+                    Some(Type::BaseType(String::from("int")))
                 }
             }
             _ => None,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn int_lit(v: i32) -> Box<ASTNode> {
+        Box::new(ASTNode::Literal(Token {
+            kind: TokenKind::IntLit(v as i64 as u64, NumType::Int),
+            span: Span::default(),
+        }))
+    }
+
+    fn fold(op: &str, lhs: i32, rhs: i32) -> Option<i32> {
+        let node = ASTNode::BinaryOp {
+            op: Some(op.to_string()),
+            lhs: int_lit(lhs),
+            rhs: Some(int_lit(rhs)),
+            span: Span::default(),
+        };
+        match node.optimal() {
+            ASTNode::Literal(tok) => int_value(&tok),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn fold_wraps_on_overflow() {
+        assert_eq!(fold("+", i32::MAX, 1), Some(i32::MIN));
+        assert_eq!(fold("-", i32::MIN, 1), Some(i32::MAX));
+        assert_eq!(fold("*", 0x10000, 0x10000), Some(0));
+    }
+
+    #[test]
+    fn fold_leaves_traps_to_runtime() {
+        assert_eq!(fold("/", 1, 0), None);
+        assert_eq!(fold("%", i32::MIN, -1), None);
+    }
+}
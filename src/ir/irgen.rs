@@ -1,4 +1,11 @@
-use crate::ast::types::ASTNode;
+use crate::ast::helper::{
+    element_type, global_decl, string_label, take_strings, type_align, type_size,
+};
+use crate::ast::types::{ASTNode, Type};
+use crate::utils::types::{Span, TokenKind};
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::sync::Mutex;
 
 use super::optimal::optimal;
 
@@ -8,14 +15,79 @@ pub struct IrType {
     pub src1: String,
     pub src2: String,
     pub rd: String,
+    // 生成这条四元式的源码位置
+    pub span: Span,
+}
+
+// 局部变量和全局变量的类型，数组名要换成地址，取地址时要告诉后端变量有多大
+// 局部变量按作用域一层层放，最内层在最后，每个变量记下 (IR 里的名字, 类型)
+type Scope = HashMap<String, (String, Type)>;
+static LOCAL_SCOPES: Lazy<Mutex<Vec<Scope>>> = Lazy::new(|| Mutex::new(Vec::new()));
+// 当前函数里每个名字已经声明过几次
+static LOCAL_COUNTS: Lazy<Mutex<HashMap<String, usize>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+static GLOBAL_TYPES: Lazy<Mutex<HashMap<String, Type>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+fn local_var(name: &str) -> Option<(String, Type)> {
+    let scopes = LOCAL_SCOPES.lock().unwrap();
+    scopes
+        .iter()
+        .rev()
+        .find_map(|scope| scope.get(name).cloned())
+}
+
+fn var_type(name: &str) -> Option<Type> {
+    local_var(name)
+        .map(|(_, ty)| ty)
+        .or_else(|| GLOBAL_TYPES.lock().unwrap().get(name).cloned())
+}
+
+// 后端按名字区分变量在哪，局部变量用声明时起的名字，全局变量就用原来的名字
+fn ir_name(name: &str) -> String {
+    local_var(name).map_or_else(|| name.to_string(), |(ir_name, _)| ir_name)
+}
+
+fn enter_scope() {
+    LOCAL_SCOPES.lock().unwrap().push(HashMap::new());
+}
+
+fn leave_scope() {
+    LOCAL_SCOPES.lock().unwrap().pop();
+}
+
+// 在最内层作用域声明局部变量，和全局变量或者同一个函数里前面的变量重名时加上编号
+fn declare_local(name: &str, ty: &Type) -> String {
+    let mut counts = LOCAL_COUNTS.lock().unwrap();
+    let count = counts.entry(name.to_string()).or_insert(0);
+    let ir_name = if *count == 0 && !GLOBAL_TYPES.lock().unwrap().contains_key(name) {
+        name.to_string()
+    } else {
+        format!("{}.{}", name, count)
+    };
+    *count += 1;
+    LOCAL_SCOPES
+        .lock()
+        .unwrap()
+        .last_mut()
+        .unwrap()
+        .insert(name.to_string(), (ir_name.clone(), ty.clone()));
+    ir_name
 }
 
 pub fn ir_gen(ast: &Vec<ASTNode>) -> Vec<IrType> {
     let mut code = Vec::new();
     let mut temp_id = 0;
     let mut label_id = 0;
+    let mut loops = Vec::new();
+    let mut switches = Vec::new();
     for ast_node in ast {
-        ir_gen_recursive(ast_node, &mut code, &mut temp_id, &mut label_id);
+        ir_gen_recursive(
+            ast_node,
+            &mut code,
+            &mut temp_id,
+            &mut label_id,
+            &mut loops,
+            &mut switches,
+        );
     }
     // code
     optimal(&code)
@@ -26,6 +98,10 @@ pub fn ir_gen_recursive(
     code: &mut Vec<IrType>,
     temp_id: &mut usize,
     label_id: &mut usize,
+    // 外层循环的 (break 跳到的标签, continue 跳到的标签)，最内层在最后
+    loops: &mut Vec<(String, String)>,
+    // 外层每个 switch 里的 (case 值, 标签)，default 的值为 None
+    switches: &mut Vec<Vec<(Option<i32>, String)>>,
 ) -> Option<String> {
     // 临时变量生成器
     fn new_temp(temp_id: &mut usize) -> String {
@@ -39,55 +115,295 @@ pub fn ir_gen_recursive(
         *label_id += 1;
         l
     }
+    // 变量的地址，src2 是变量占的字节数
+    fn addr_of(var: String, code: &mut Vec<IrType>, temp_id: &mut usize, span: Span) -> String {
+        let size = var_type(&var).map_or(4, |ty| type_size(&ty));
+        let temp = new_temp(temp_id);
+        code.push(IrType {
+            op: "ADDR".to_string(),
+            src1: ir_name(&var),
+            src2: size.to_string(),
+            rd: temp.clone(),
+            span,
+        });
+        temp
+    }
+    // 基地址加上字节偏移，偏移为 0 时就是基地址
+    fn offset_addr(
+        base: String,
+        offset: usize,
+        code: &mut Vec<IrType>,
+        temp_id: &mut usize,
+        span: Span,
+    ) -> String {
+        if offset == 0 {
+            return base;
+        }
+        let temp = new_temp(temp_id);
+        code.push(IrType {
+            op: "+".to_string(),
+            src1: base,
+            src2: offset.to_string(),
+            rd: temp.clone(),
+            span,
+        });
+        temp
+    }
+    // 寄存器里的 char 变量不会被 sb 截断，写进去的值先截成 8 位再做符号扩展
+    fn narrow(
+        value: String,
+        ty: Option<&Type>,
+        code: &mut Vec<IrType>,
+        temp_id: &mut usize,
+        span: Span,
+    ) -> String {
+        if ty != Some(&Type::BaseType(String::from("char"))) {
+            return value;
+        }
+        if let Ok(v) = value.parse::<i32>() {
+            return (v as i8).to_string();
+        }
+        let shifted = new_temp(temp_id);
+        code.push(IrType {
+            op: "<<".to_string(),
+            src1: value,
+            src2: "24".to_string(),
+            rd: shifted.clone(),
+            span,
+        });
+        let temp = new_temp(temp_id);
+        code.push(IrType {
+            op: ">>".to_string(),
+            src1: shifted,
+            src2: "24".to_string(),
+            rd: temp.clone(),
+            span,
+        });
+        temp
+    }
+    // 解引用和取成员表达式所在的地址和类型
+    fn place<'a>(
+        node: &'a ASTNode,
+        code: &mut Vec<IrType>,
+        temp_id: &mut usize,
+        label_id: &mut usize,
+        loops: &mut Vec<(String, String)>,
+        switches: &mut Vec<Vec<(Option<i32>, String)>>,
+    ) -> (String, &'a Type) {
+        match node {
+            ASTNode::Deref { expr, ty, .. } => {
+                let addr = ir_gen_recursive(expr, code, temp_id, label_id, loops, switches)
+                    .unwrap_or_default();
+                (addr, ty)
+            }
+            // 结构体表达式的值就是它的地址
+            ASTNode::Member {
+                expr, offset, ty, ..
+            } => {
+                let base = ir_gen_recursive(expr, code, temp_id, label_id, loops, switches)
+                    .unwrap_or_default();
+                (offset_addr(base, *offset, code, temp_id, node.span()), ty)
+            }
+            _ => unreachable!(),
+        }
+    }
+    // 结构体赋值按字复制，对齐不到 4 字节的按字节复制
+    fn copy_object(
+        dst: &str,
+        src: &str,
+        ty: &Type,
+        code: &mut Vec<IrType>,
+        temp_id: &mut usize,
+        span: Span,
+    ) {
+        let (step, load, store) = if type_align(ty) >= 4 {
+            (4, "LOAD", "STORE")
+        } else {
+            (1, "LOADB", "STOREB")
+        };
+        for offset in (0..type_size(ty)).step_by(step) {
+            let from = offset_addr(src.to_string(), offset, code, temp_id, span);
+            let to = offset_addr(dst.to_string(), offset, code, temp_id, span);
+            let temp = new_temp(temp_id);
+            code.push(IrType {
+                op: load.to_string(),
+                src1: from,
+                src2: "".to_string(),
+                rd: temp.clone(),
+                span,
+            });
+            code.push(IrType {
+                op: store.to_string(),
+                src1: temp,
+                src2: to,
+                rd: "".to_string(),
+                span,
+            });
+        }
+    }
 
     match node {
-        ASTNode::Program { items } => {
+        ASTNode::Program { items, .. } => {
+            // 全局变量只记下名字和大小，由后端分配在数据段
+            // 同一个变量可以声明好几次，只输出一次；只有 extern 声明的在别的文件里分配
+            // GLOBAL 的 rd 为 static 时不导出，EXTERN 只是告诉后端这个名字是全局变量
+            // 有初始值的再跟一条 INIT，src2 是初始值
+            // 全部放在函数前面，这时常量传播还没有记下任何变量的值，不会改掉名字和初始值
+            for item in items {
+                let ASTNode::VarDecl(decls, _) = item else {
+                    continue;
+                };
+                for decl in decls {
+                    if GLOBAL_TYPES
+                        .lock()
+                        .unwrap()
+                        .insert(decl.var_name.clone(), decl.var_type.clone())
+                        .is_some()
+                    {
+                        continue;
+                    }
+                    let linkage = global_decl(&decl.var_name).unwrap();
+                    code.push(IrType {
+                        op: if linkage.defined { "GLOBAL" } else { "EXTERN" }.to_string(),
+                        src1: decl.var_name.clone(),
+                        src2: type_size(&decl.var_type).to_string(),
+                        rd: if linkage.is_static { "static" } else { "" }.to_string(),
+                        span: decl.span,
+                    });
+                    // 每个元素一条 INIT，rd 是元素的字节数
+                    let width = type_size(element_type(&decl.var_type));
+                    for value in linkage.init.unwrap_or_default() {
+                        code.push(IrType {
+                            op: "INIT".to_string(),
+                            src1: decl.var_name.clone(),
+                            src2: value,
+                            rd: width.to_string(),
+                            span: decl.span,
+                        });
+                    }
+                }
+            }
             for item in items {
-                ir_gen_recursive(item, code, temp_id, label_id);
+                if !matches!(item, ASTNode::VarDecl(..)) {
+                    ir_gen_recursive(item, code, temp_id, label_id, loops, switches);
+                }
+            }
+            // 用到的字符串常量是不导出的 char 数组，末尾的 0 由 .zero 补上
+            for (i, bytes) in take_strings().into_iter().enumerate() {
+                let label = format!(".LC{}", i);
+                code.push(IrType {
+                    op: "GLOBAL".to_string(),
+                    src1: label.clone(),
+                    src2: (bytes.len() + 1).to_string(),
+                    rd: "static".to_string(),
+                    span: node.span(),
+                });
+                for byte in bytes {
+                    code.push(IrType {
+                        op: "INIT".to_string(),
+                        src1: label.clone(),
+                        src2: byte.to_string(),
+                        rd: "1".to_string(),
+                        span: node.span(),
+                    });
+                }
             }
             None
         }
         ASTNode::FuncDef {
             name, params, body, ..
         } => {
+            // static 函数的 src2 为 static，不导出
+            let is_static = global_decl(name).is_some_and(|decl| decl.is_static);
             code.push(IrType {
                 op: "FUNC".to_string(),
                 src1: name.clone(),
-                src2: "".to_string(),
+                src2: if is_static { "static" } else { "" }.to_string(),
                 rd: "".to_string(),
+                span: node.span(),
             });
+            LOCAL_COUNTS.lock().unwrap().clear();
+            enter_scope();
             for param in params {
                 code.push(IrType {
                     op: "PARAM".to_string(),
-                    src1: param.var_name.clone(),
+                    src1: declare_local(&param.var_name, &param.var_type),
                     src2: "".to_string(),
                     rd: "".to_string(),
+                    span: param.span,
                 });
             }
-            ir_gen_recursive(body, code, temp_id, label_id);
+            let start = code.len();
+            ir_gen_recursive(body, code, temp_id, label_id, loops, switches);
+            leave_scope();
+            // goto 的标号只在函数里有效，加上函数名免得和别的函数里的同名标号冲突
+            for ir in &mut code[start..] {
+                if ir.op == "LABEL" && ir.src1.starts_with('.') {
+                    ir.src1 = format!(".L{}{}", name, ir.src1);
+                } else if ir.op == "JMP" && ir.rd.starts_with('.') {
+                    ir.rd = format!(".L{}{}", name, ir.rd);
+                }
+            }
             code.push(IrType {
                 op: "ENDFUNC".to_string(),
                 src1: name.clone(),
                 src2: "".to_string(),
                 rd: "".to_string(),
+                span: node.span(),
             });
             None
         }
-        ASTNode::Block { stmts } => {
+        ASTNode::Block { stmts, .. } => {
+            enter_scope();
             for stmt in stmts {
-                ir_gen_recursive(stmt, code, temp_id, label_id);
+                ir_gen_recursive(stmt, code, temp_id, label_id, loops, switches);
             }
+            leave_scope();
             None
         }
-        ASTNode::VarDecl(decls) => {
+        ASTNode::VarDecl(decls, _) => {
             for decl in decls {
+                declare_local(&decl.var_name, &decl.var_type);
+                // 数组按顺序把每个元素写进去
+                if let Some(ASTNode::InitList { items, .. }) = &decl.init {
+                    let base = addr_of(decl.var_name.clone(), code, temp_id, decl.span);
+                    let elem = element_type(&decl.var_type);
+                    for (i, item) in items.iter().enumerate() {
+                        let value =
+                            ir_gen_recursive(item, code, temp_id, label_id, loops, switches)
+                                .unwrap_or_default();
+                        let addr = offset_addr(
+                            base.clone(),
+                            i * type_size(elem),
+                            code,
+                            temp_id,
+                            item.span(),
+                        );
+                        code.push(IrType {
+                            op: store_op(elem).to_string(),
+                            src1: value,
+                            src2: addr,
+                            rd: "".to_string(),
+                            span: item.span(),
+                        });
+                    }
+                    continue;
+                }
                 if let Some(init) = &decl.init {
-                    let src1 = ir_gen_recursive(init, code, temp_id, label_id).unwrap_or_default();
+                    let src1 = ir_gen_recursive(init, code, temp_id, label_id, loops, switches)
+                        .unwrap_or_default();
+                    if let Type::CustomizedType(_) = decl.var_type {
+                        let dst = addr_of(decl.var_name.clone(), code, temp_id, decl.span);
+                        copy_object(&dst, &src1, &decl.var_type, code, temp_id, decl.span);
+                        continue;
+                    }
+                    let src1 = narrow(src1, Some(&decl.var_type), code, temp_id, decl.span);
                     code.push(IrType {
                         op: "MOV".to_string(),
                         src1,
                         src2: "".to_string(),
-                        rd: decl.var_name.clone(),
+                        rd: ir_name(&decl.var_name),
+                        span: decl.span,
                     });
                 }
             }
@@ -97,8 +413,10 @@ pub fn ir_gen_recursive(
             cond,
             then_br,
             else_br,
+            ..
         } => {
-            let cond_temp = ir_gen_recursive(cond, code, temp_id, label_id).unwrap_or_default();
+            let cond_temp = ir_gen_recursive(cond, code, temp_id, label_id, loops, switches)
+                .unwrap_or_default();
             let else_label = new_label(label_id);
             let end_label = new_label(label_id);
             code.push(IrType {
@@ -106,32 +424,36 @@ pub fn ir_gen_recursive(
                 src1: cond_temp,
                 src2: "".to_string(),
                 rd: else_label.clone(),
+                span: node.span(),
             });
-            ir_gen_recursive(then_br, code, temp_id, label_id);
+            ir_gen_recursive(then_br, code, temp_id, label_id, loops, switches);
             code.push(IrType {
                 op: "JMP".to_string(),
                 src1: "".to_string(),
                 src2: "".to_string(),
                 rd: end_label.clone(),
+                span: node.span(),
             });
             code.push(IrType {
                 op: "LABEL".to_string(),
                 src1: else_label.clone(),
                 src2: "".to_string(),
                 rd: "".to_string(),
+                span: node.span(),
             });
             if let Some(else_br) = else_br {
-                ir_gen_recursive(else_br, code, temp_id, label_id);
+                ir_gen_recursive(else_br, code, temp_id, label_id, loops, switches);
             }
             code.push(IrType {
                 op: "LABEL".to_string(),
                 src1: end_label.clone(),
                 src2: "".to_string(),
                 rd: "".to_string(),
+                span: node.span(),
             });
             None
         }
-        ASTNode::While { cond, body } => {
+        ASTNode::While { cond, body, .. } => {
             let start_label = new_label(label_id);
             let cond_label = new_label(label_id);
             let end_label = new_label(label_id);
@@ -140,43 +462,269 @@ pub fn ir_gen_recursive(
                 src1: "".to_string(),
                 src2: "".to_string(),
                 rd: cond_label.clone(),
+                span: node.span(),
             });
             code.push(IrType {
                 op: "LABEL".to_string(),
                 src1: start_label.clone(),
                 src2: "".to_string(),
                 rd: "".to_string(),
+                span: node.span(),
             });
-            ir_gen_recursive(body, code, temp_id, label_id);
+            loops.push((end_label.clone(), cond_label.clone()));
+            ir_gen_recursive(body, code, temp_id, label_id, loops, switches);
+            loops.pop();
             code.push(IrType {
                 op: "LABEL".to_string(),
                 src1: cond_label.clone(),
                 src2: "".to_string(),
                 rd: "".to_string(),
+                span: node.span(),
             });
-            let cond_temp = ir_gen_recursive(cond, code, temp_id, label_id).unwrap_or_default();
+            let cond_temp = ir_gen_recursive(cond, code, temp_id, label_id, loops, switches)
+                .unwrap_or_default();
             code.push(IrType {
                 op: "JNZ".to_string(),
                 src1: cond_temp,
                 src2: "".to_string(),
                 rd: start_label.clone(),
+                span: node.span(),
             });
             code.push(IrType {
                 op: "LABEL".to_string(),
                 src1: end_label.clone(),
                 src2: "".to_string(),
                 rd: "".to_string(),
+                span: node.span(),
             });
             None
         }
-        ASTNode::Return { expr } => {
+        // continue 跳到条件判断
+        ASTNode::DoWhile { body, cond, .. } => {
+            let start_label = new_label(label_id);
+            let cond_label = new_label(label_id);
+            let end_label = new_label(label_id);
+            code.push(IrType {
+                op: "LABEL".to_string(),
+                src1: start_label.clone(),
+                src2: "".to_string(),
+                rd: "".to_string(),
+                span: node.span(),
+            });
+            loops.push((end_label.clone(), cond_label.clone()));
+            ir_gen_recursive(body, code, temp_id, label_id, loops, switches);
+            loops.pop();
+            code.push(IrType {
+                op: "LABEL".to_string(),
+                src1: cond_label.clone(),
+                src2: "".to_string(),
+                rd: "".to_string(),
+                span: node.span(),
+            });
+            let cond_temp = ir_gen_recursive(cond, code, temp_id, label_id, loops, switches)
+                .unwrap_or_default();
+            code.push(IrType {
+                op: "JNZ".to_string(),
+                src1: cond_temp,
+                src2: "".to_string(),
+                rd: start_label.clone(),
+                span: node.span(),
+            });
+            code.push(IrType {
+                op: "LABEL".to_string(),
+                src1: end_label.clone(),
+                src2: "".to_string(),
+                rd: "".to_string(),
+                span: node.span(),
+            });
+            None
+        }
+        // 和 While 一样先跳到条件判断，步进放在循环体后面
+        ASTNode::For {
+            init,
+            cond,
+            step,
+            body,
+            ..
+        } => {
+            // init 里声明的变量只在循环里有效
+            enter_scope();
+            if let Some(init) = init {
+                ir_gen_recursive(init, code, temp_id, label_id, loops, switches);
+            }
+            let start_label = new_label(label_id);
+            let cond_label = new_label(label_id);
+            let end_label = new_label(label_id);
+            code.push(IrType {
+                op: "JMP".to_string(),
+                src1: "".to_string(),
+                src2: "".to_string(),
+                rd: cond_label.clone(),
+                span: node.span(),
+            });
+            code.push(IrType {
+                op: "LABEL".to_string(),
+                src1: start_label.clone(),
+                src2: "".to_string(),
+                rd: "".to_string(),
+                span: node.span(),
+            });
+            // continue 跳到步进而不是条件
+            let step_label = new_label(label_id);
+            loops.push((end_label.clone(), step_label.clone()));
+            ir_gen_recursive(body, code, temp_id, label_id, loops, switches);
+            loops.pop();
+            code.push(IrType {
+                op: "LABEL".to_string(),
+                src1: step_label.clone(),
+                src2: "".to_string(),
+                rd: "".to_string(),
+                span: node.span(),
+            });
+            if let Some(step) = step {
+                ir_gen_recursive(step, code, temp_id, label_id, loops, switches);
+            }
+            code.push(IrType {
+                op: "LABEL".to_string(),
+                src1: cond_label.clone(),
+                src2: "".to_string(),
+                rd: "".to_string(),
+                span: node.span(),
+            });
+            // 没有条件就是死循环
+            if let Some(cond) = cond {
+                let cond_temp = ir_gen_recursive(cond, code, temp_id, label_id, loops, switches)
+                    .unwrap_or_default();
+                code.push(IrType {
+                    op: "JNZ".to_string(),
+                    src1: cond_temp,
+                    src2: "".to_string(),
+                    rd: start_label.clone(),
+                    span: node.span(),
+                });
+            } else {
+                code.push(IrType {
+                    op: "JMP".to_string(),
+                    src1: "".to_string(),
+                    src2: "".to_string(),
+                    rd: start_label.clone(),
+                    span: node.span(),
+                });
+            }
+            code.push(IrType {
+                op: "LABEL".to_string(),
+                src1: end_label.clone(),
+                src2: "".to_string(),
+                rd: "".to_string(),
+                span: node.span(),
+            });
+            leave_scope();
+            None
+        }
+        // 先生成 switch 体收集里面的 case 标号，再把分派代码放在前面
+        ASTNode::Switch { cond, body, .. } => {
+            let cond_temp = ir_gen_recursive(cond, code, temp_id, label_id, loops, switches)
+                .unwrap_or_default();
+            let end_label = new_label(label_id);
+            // switch 里的 continue 还是属于外层的循环
+            let continue_label = loops.last().map(|(_, c)| c.clone()).unwrap_or_default();
+            let mut body_code = Vec::new();
+            loops.push((end_label.clone(), continue_label));
+            switches.push(Vec::new());
+            ir_gen_recursive(body, &mut body_code, temp_id, label_id, loops, switches);
+            let labels = switches.pop().unwrap();
+            loops.pop();
+            // 没有 default 时匹配不到就跳出 switch
+            let default_label = labels
+                .iter()
+                .find(|(value, _)| value.is_none())
+                .map(|(_, label)| label.clone())
+                .unwrap_or(end_label.clone());
+            let mut cases: Vec<(i32, String)> = labels
+                .into_iter()
+                .filter_map(|(value, label)| value.map(|v| (v, label)))
+                .collect();
+            cases.sort();
+            switch_dispatch(
+                &cond_temp,
+                &cases,
+                &default_label,
+                node.span(),
+                code,
+                temp_id,
+                label_id,
+            );
+            code.extend(body_code);
+            code.push(IrType {
+                op: "LABEL".to_string(),
+                src1: end_label.clone(),
+                src2: "".to_string(),
+                rd: "".to_string(),
+                span: node.span(),
+            });
+            None
+        }
+        ASTNode::Case { value, body, .. } => {
+            let label = new_label(label_id);
+            switches.last_mut().unwrap().push((*value, label.clone()));
+            code.push(IrType {
+                op: "LABEL".to_string(),
+                src1: label,
+                src2: "".to_string(),
+                rd: "".to_string(),
+                span: node.span(),
+            });
+            ir_gen_recursive(body, code, temp_id, label_id, loops, switches);
+            None
+        }
+        // 循环外的 break/continue 在语义分析时已经报错
+        ASTNode::Break(_) | ASTNode::Continue(_) => {
+            let (break_label, continue_label) = loops.last().unwrap();
+            let target = match node {
+                ASTNode::Break(_) => break_label,
+                _ => continue_label,
+            };
+            code.push(IrType {
+                op: "JMP".to_string(),
+                src1: "".to_string(),
+                src2: "".to_string(),
+                rd: target.clone(),
+                span: node.span(),
+            });
+            None
+        }
+        // 用户的标号以 '.' 开头，和生成的标签区分开，函数结束时再加上函数名
+        ASTNode::Goto(label, _) => {
+            code.push(IrType {
+                op: "JMP".to_string(),
+                src1: "".to_string(),
+                src2: "".to_string(),
+                rd: format!(".{}", label),
+                span: node.span(),
+            });
+            None
+        }
+        ASTNode::Label { name, body, .. } => {
+            code.push(IrType {
+                op: "LABEL".to_string(),
+                src1: format!(".{}", name),
+                src2: "".to_string(),
+                rd: "".to_string(),
+                span: node.span(),
+            });
+            ir_gen_recursive(body, code, temp_id, label_id, loops, switches);
+            None
+        }
+        ASTNode::Return { expr, .. } => {
             if let Some(e) = expr {
-                let val = ir_gen_recursive(e, code, temp_id, label_id).unwrap_or_default();
+                let val = ir_gen_recursive(e, code, temp_id, label_id, loops, switches)
+                    .unwrap_or_default();
                 code.push(IrType {
                     op: "RET".to_string(),
                     src1: val,
                     src2: "".to_string(),
                     rd: "".to_string(),
+                    span: node.span(),
                 });
             } else {
                 code.push(IrType {
@@ -184,20 +732,112 @@ pub fn ir_gen_recursive(
                     src1: "".to_string(),
                     src2: "".to_string(),
                     rd: "".to_string(),
+                    span: node.span(),
                 });
             }
             None
         }
-        ASTNode::BinaryOp { op, lhs, rhs } => {
-            let left = ir_gen_recursive(lhs, code, temp_id, label_id).unwrap_or_default();
-            let right = ir_gen_recursive(rhs.as_ref().unwrap(), code, temp_id, label_id)
-                .unwrap_or_default();
-            if op.clone().unwrap_or_default() == "=" {
+        // 结构体赋值：两边的值都是地址，把右边的内容复制过去
+        ASTNode::BinaryOp {
+            op: Some(op),
+            lhs,
+            rhs: Some(rhs),
+            ..
+        } if op == "=" && matches!(lvalue_type(lhs), Some(Type::CustomizedType(_))) => {
+            let ty = lvalue_type(lhs).unwrap();
+            let dst =
+                ir_gen_recursive(lhs, code, temp_id, label_id, loops, switches).unwrap_or_default();
+            let src =
+                ir_gen_recursive(rhs, code, temp_id, label_id, loops, switches).unwrap_or_default();
+            copy_object(&dst, &src, &ty, code, temp_id, node.span());
+            Some(dst)
+        }
+        // 通过指针或者给成员赋值：先算出地址，复合赋值还要先读出原来的值
+        ASTNode::BinaryOp {
+            op: Some(op),
+            lhs,
+            rhs: Some(rhs),
+            ..
+        } if (op == "=" || compound_op(op, lhs).is_some())
+            && matches!(lhs.as_ref(), ASTNode::Deref { .. } | ASTNode::Member { .. }) =>
+        {
+            let (addr, ty) = place(lhs, code, temp_id, label_id, loops, switches);
+            let right =
+                ir_gen_recursive(rhs, code, temp_id, label_id, loops, switches).unwrap_or_default();
+            let value = match compound_op(op, lhs) {
+                Some(bin) => {
+                    let old = new_temp(temp_id);
+                    code.push(IrType {
+                        op: load_op(ty).to_string(),
+                        src1: addr.clone(),
+                        src2: "".to_string(),
+                        rd: old.clone(),
+                        span: node.span(),
+                    });
+                    let temp = new_temp(temp_id);
+                    code.push(IrType {
+                        op: bin.to_string(),
+                        src1: old,
+                        src2: right,
+                        rd: temp.clone(),
+                        span: node.span(),
+                    });
+                    temp
+                }
+                None => right,
+            };
+            code.push(IrType {
+                op: store_op(ty).to_string(),
+                src1: value.clone(),
+                src2: addr,
+                rd: "".to_string(),
+                span: node.span(),
+            });
+            Some(value)
+        }
+        ASTNode::BinaryOp { op, lhs, rhs, .. } => {
+            let left =
+                ir_gen_recursive(lhs, code, temp_id, label_id, loops, switches).unwrap_or_default();
+            let right = ir_gen_recursive(
+                rhs.as_ref().unwrap(),
+                code,
+                temp_id,
+                label_id,
+                loops,
+                switches,
+            )
+            .unwrap_or_default();
+            // 逗号表达式两边都求值，结果是右边的值
+            if op.as_deref() == Some(",") {
+                return Some(right);
+            }
+            // 复合赋值 a op= b 是 a = a op b，但 a 只求一次值
+            if let Some(bin) = compound_op(op.as_deref().unwrap_or_default(), lhs) {
+                let temp = new_temp(temp_id);
+                code.push(IrType {
+                    op: bin.to_string(),
+                    src1: left.clone(),
+                    src2: right,
+                    rd: temp.clone(),
+                    span: node.span(),
+                });
+                let temp = narrow(temp, lvalue_type(lhs).as_ref(), code, temp_id, node.span());
+                code.push(IrType {
+                    op: "=".to_string(),
+                    src1: temp,
+                    src2: "".to_string(),
+                    rd: left.clone(),
+                    span: node.span(),
+                });
+                Some(left)
+            } else if op.clone().unwrap_or_default() == "=" {
+                let right = narrow(right, lvalue_type(lhs).as_ref(), code, temp_id, node.span());
                 let ir_node = IrType {
                     op: op.clone().unwrap_or_default(),
                     src1: right,
                     src2: "".to_string(),
                     rd: left.clone(),
+                    span: node.span(),
                 };
                 code.push(ir_node);
                 Some(left)
@@ -208,27 +848,208 @@ pub fn ir_gen_recursive(
                     src1: left,
                     src2: right,
                     rd: temp.clone(),
+                    span: node.span(),
                 };
                 code.push(ir_node);
                 Some(temp)
             }
         }
-        ASTNode::UnaryOp { op, expr } => {
-            let val = ir_gen_recursive(expr, code, temp_id, label_id).unwrap_or_default();
+        // 两个分支都把值写到同一个临时变量里，没选中的分支不求值
+        ASTNode::Ternary {
+            cond,
+            then_expr,
+            else_expr,
+            ..
+        } => {
+            let cond_temp = ir_gen_recursive(cond, code, temp_id, label_id, loops, switches)
+                .unwrap_or_default();
+            let result = new_temp(temp_id);
+            let else_label = new_label(label_id);
+            let end_label = new_label(label_id);
+            code.push(IrType {
+                op: "JZ".to_string(),
+                src1: cond_temp,
+                src2: "".to_string(),
+                rd: else_label.clone(),
+                span: node.span(),
+            });
+            let then_val = ir_gen_recursive(then_expr, code, temp_id, label_id, loops, switches)
+                .unwrap_or_default();
+            code.push(IrType {
+                op: "MOV".to_string(),
+                src1: then_val,
+                src2: "".to_string(),
+                rd: result.clone(),
+                span: node.span(),
+            });
+            code.push(IrType {
+                op: "JMP".to_string(),
+                src1: "".to_string(),
+                src2: "".to_string(),
+                rd: end_label.clone(),
+                span: node.span(),
+            });
+            code.push(IrType {
+                op: "LABEL".to_string(),
+                src1: else_label,
+                src2: "".to_string(),
+                rd: "".to_string(),
+                span: node.span(),
+            });
+            let else_val = ir_gen_recursive(else_expr, code, temp_id, label_id, loops, switches)
+                .unwrap_or_default();
+            code.push(IrType {
+                op: "MOV".to_string(),
+                src1: else_val,
+                src2: "".to_string(),
+                rd: result.clone(),
+                span: node.span(),
+            });
+            code.push(IrType {
+                op: "LABEL".to_string(),
+                src1: end_label,
+                src2: "".to_string(),
+                rd: "".to_string(),
+                span: node.span(),
+            });
+            Some(result)
+        }
+        // 通过指针和成员的 ++ 和 --：读出来加减 1 再写回去
+        ASTNode::UnaryOp { op, expr, .. } | ASTNode::PostfixOp { op, expr, .. }
+            if (op == "++" || op == "--")
+                && matches!(
+                    expr.as_ref(),
+                    ASTNode::Deref { .. } | ASTNode::Member { .. }
+                ) =>
+        {
+            let (addr, ty) = place(expr, code, temp_id, label_id, loops, switches);
+            let old = new_temp(temp_id);
+            code.push(IrType {
+                op: load_op(ty).to_string(),
+                src1: addr.clone(),
+                src2: "".to_string(),
+                rd: old.clone(),
+                span: node.span(),
+            });
+            let temp = new_temp(temp_id);
+            code.push(IrType {
+                op: if op == "++" { "+" } else { "-" }.to_string(),
+                src1: old.clone(),
+                src2: "1".to_string(),
+                rd: temp.clone(),
+                span: node.span(),
+            });
+            code.push(IrType {
+                op: store_op(ty).to_string(),
+                src1: temp.clone(),
+                src2: addr,
+                rd: "".to_string(),
+                span: node.span(),
+            });
+            if matches!(node, ASTNode::PostfixOp { .. }) {
+                Some(old)
+            } else {
+                Some(temp)
+            }
+        }
+        // &*p 就是 p，&s.x 是结构体的地址加上成员的偏移
+        ASTNode::UnaryOp { op, expr, .. }
+            if op == "&"
+                && matches!(
+                    expr.as_ref(),
+                    ASTNode::Deref { .. } | ASTNode::Member { .. }
+                ) =>
+        {
+            Some(place(expr, code, temp_id, label_id, loops, switches).0)
+        }
+        // 取地址的变量在汇编里放在栈上
+        ASTNode::UnaryOp { op, expr, .. } if op == "&" => {
+            let var = match expr.as_ref() {
+                ASTNode::Ident(tok) => tok.to_string(),
+                _ => ir_gen_recursive(expr, code, temp_id, label_id, loops, switches)
+                    .unwrap_or_default(),
+            };
+            Some(addr_of(var, code, temp_id, node.span()))
+        }
+        ASTNode::Deref { .. } | ASTNode::Member { .. } => {
+            let (addr, ty) = place(node, code, temp_id, label_id, loops, switches);
+            // 多维数组取一维得到的还是数组，结构体也一样，值就是它的地址
+            if matches!(ty, Type::Array(..) | Type::CustomizedType(_)) {
+                return Some(addr);
+            }
+            let temp = new_temp(temp_id);
+            code.push(IrType {
+                op: load_op(ty).to_string(),
+                src1: addr,
+                src2: "".to_string(),
+                rd: temp.clone(),
+                span: node.span(),
+            });
+            Some(temp)
+        }
+        // 前缀的 ++ 和 -- 的值是修改之后的变量
+        ASTNode::UnaryOp { op, expr, .. } if op == "++" || op == "--" => {
+            let target = ir_gen_recursive(expr, code, temp_id, label_id, loops, switches)
+                .unwrap_or_default();
+            let temp = new_temp(temp_id);
+            increment(&target, op, &temp, node.span(), code);
+            let temp = narrow(temp, lvalue_type(expr).as_ref(), code, temp_id, node.span());
+            code.push(IrType {
+                op: "=".to_string(),
+                src1: temp,
+                src2: "".to_string(),
+                rd: target.clone(),
+                span: node.span(),
+            });
+            Some(target)
+        }
+        // 后缀的先把原来的值存到临时变量里
+        ASTNode::PostfixOp { op, expr, .. } => {
+            let target = ir_gen_recursive(expr, code, temp_id, label_id, loops, switches)
+                .unwrap_or_default();
+            let old = new_temp(temp_id);
+            code.push(IrType {
+                op: "MOV".to_string(),
+                src1: target.clone(),
+                src2: "".to_string(),
+                rd: old.clone(),
+                span: node.span(),
+            });
+            let temp = new_temp(temp_id);
+            increment(&target, op, &temp, node.span(), code);
+            let temp = narrow(temp, lvalue_type(expr).as_ref(), code, temp_id, node.span());
+            code.push(IrType {
+                op: "=".to_string(),
+                src1: temp,
+                src2: "".to_string(),
+                rd: target.clone(),
+                span: node.span(),
+            });
+            Some(old)
+        }
+        ASTNode::UnaryOp { op, expr, .. } => {
+            let val = ir_gen_recursive(expr, code, temp_id, label_id, loops, switches)
+                .unwrap_or_default();
             let temp = new_temp(temp_id);
             code.push(IrType {
                 op: op.clone(),
                 src1: val,
                 src2: "".to_string(),
                 rd: temp.clone(),
+                span: node.span(),
             });
             Some(temp)
         }
-        ASTNode::Call { func, args } => {
+        ASTNode::Call { func, args, .. } => {
             let mut arg_vars = Vec::new();
-            for arg in args {
-                let v = ir_gen_recursive(arg, code, temp_id, label_id).unwrap_or_default();
-                arg_vars.push(v);
+            // 形参是 char 时实参也要先截断
+            let params = global_decl(func)
+                .and_then(|decl| decl.params)
+                .unwrap_or_default();
+            for (i, arg) in args.iter().enumerate() {
+                let v = ir_gen_recursive(arg, code, temp_id, label_id, loops, switches)
+                    .unwrap_or_default();
+                arg_vars.push(narrow(v, params.get(i), code, temp_id, arg.span()));
             }
             for v in &arg_vars {
                 code.push(IrType {
@@ -236,6 +1057,7 @@ pub fn ir_gen_recursive(
                     src1: v.clone(),
                     src2: "".to_string(),
                     rd: "".to_string(),
+                    span: node.span(),
                 });
             }
             let temp = new_temp(temp_id);
@@ -244,11 +1066,263 @@ pub fn ir_gen_recursive(
                 src1: func.clone(),
                 src2: arg_vars.len().to_string(),
                 rd: temp.clone(),
+                span: node.span(),
             });
             Some(temp)
         }
-        ASTNode::Literal(tok) => Some(tok.value.clone()),
-        ASTNode::Ident(tok) => Some(tok.value.clone()),
+        // 整数和字符常量用解析后的数值
+        ASTNode::Literal(tok) => match &tok.kind {
+            // 字符串常量的值是它在数据段里的地址
+            TokenKind::StrLit(bytes) => {
+                let temp = new_temp(temp_id);
+                code.push(IrType {
+                    op: "ADDR".to_string(),
+                    src1: string_label(bytes),
+                    src2: (bytes.len() + 1).to_string(),
+                    rd: temp.clone(),
+                    span: node.span(),
+                });
+                Some(temp)
+            }
+            TokenKind::CharLit(v) => Some(v.to_string()),
+            TokenKind::IntLit(v, _) => Some((*v as i32).to_string()),
+            _ => Some(tok.to_string()),
+        },
+        // 数组名的值是第一个元素的地址，结构体变量的值是它的地址
+        ASTNode::Ident(tok) => match var_type(&tok.to_string()) {
+            Some(Type::Array(..) | Type::CustomizedType(_)) => {
+                Some(addr_of(tok.to_string(), code, temp_id, node.span()))
+            }
+            _ => Some(ir_name(&tok.to_string())),
+        },
         _ => None,
     }
 }
+
+// 赋值左边的类型，后端只关心变量、解引用和成员
+fn lvalue_type(node: &ASTNode) -> Option<Type> {
+    match node {
+        ASTNode::Ident(tok) => var_type(&tok.to_string()),
+        ASTNode::Deref { ty, .. } | ASTNode::Member { ty, .. } => Some(ty.clone()),
+        _ => None,
+    }
+}
+
+// 按类型的大小选择读写内存的宽度，char 只读写一个字节
+fn load_op(ty: &Type) -> &'static str {
+    if type_size(ty) == 1 { "LOADB" } else { "LOAD" }
+}
+fn store_op(ty: &Type) -> &'static str {
+    if type_size(ty) == 1 {
+        "STOREB"
+    } else {
+        "STORE"
+    }
+}
+
+// 复合赋值对应的二元运算
+fn compound_op(op: &str, lhs: &ASTNode) -> Option<&'static str> {
+    match op {
+        "+=" => Some("+"),
+        "-=" => Some("-"),
+        "*=" => Some("*"),
+        "/=" => Some("/"),
+        "%=" => Some("%"),
+        "<<=" => Some("<<"),
+        // uint 的右移是逻辑右移，>>> 只在 IR 里出现
+        ">>=" if lvalue_type(lhs) == Some(Type::BaseType(String::from("uint"))) => Some(">>>"),
+        ">>=" => Some(">>"),
+        "&=" => Some("&"),
+        "^=" => Some("^"),
+        "|=" => Some("|"),
+        _ => None,
+    }
+}
+
+// temp = target ± 1，写回 target 之前可能还要截断
+fn increment(target: &str, op: &str, temp: &str, span: Span, code: &mut Vec<IrType>) {
+    code.push(IrType {
+        op: if op == "++" { "+" } else { "-" }.to_string(),
+        src1: target.to_string(),
+        src2: "1".to_string(),
+        rd: temp.to_string(),
+        span,
+    });
+}
+
+// case 个数不少于这个数并且足够密集时用跳转表
+const JUMP_TABLE_MIN_CASES: usize = 4;
+// 跳转表的项数最多是 case 个数的这么多倍，再稀疏就用二分查找
+const JUMP_TABLE_MAX_RATIO: i64 = 3;
+// 二分查找剩下不超过这么多个 case 时逐个比较
+const COMPARE_CHAIN_MAX_CASES: usize = 3;
+
+// 根据 case 的个数和密度选择逐个比较、二分查找或者跳转表，cases 按值排好序
+fn switch_dispatch(
+    value: &str,
+    cases: &[(i32, String)],
+    default_label: &str,
+    span: Span,
+    code: &mut Vec<IrType>,
+    temp_id: &mut usize,
+    label_id: &mut usize,
+) {
+    if cases.is_empty() {
+        code.push(IrType {
+            op: "JMP".to_string(),
+            src1: "".to_string(),
+            src2: "".to_string(),
+            rd: default_label.to_string(),
+            span,
+        });
+        return;
+    }
+    let min = cases[0].0;
+    let max = cases[cases.len() - 1].0;
+    let range = max as i64 - min as i64 + 1;
+    if cases.len() >= JUMP_TABLE_MIN_CASES && range <= cases.len() as i64 * JUMP_TABLE_MAX_RATIO {
+        jump_table(value, cases, default_label, span, code, temp_id, label_id);
+    } else {
+        binary_search(value, cases, default_label, span, code, temp_id, label_id);
+    }
+}
+
+// 和常量比较，结果放在新的临时变量里
+fn compare(
+    op: &str,
+    value: &str,
+    constant: i32,
+    span: Span,
+    code: &mut Vec<IrType>,
+    temp_id: &mut usize,
+) -> String {
+    let temp = format!("t{}", *temp_id);
+    *temp_id += 1;
+    code.push(IrType {
+        op: op.to_string(),
+        src1: value.to_string(),
+        src2: constant.to_string(),
+        rd: temp.clone(),
+        span,
+    });
+    temp
+}
+
+fn jump(op: &str, cond: String, target: &str, span: Span, code: &mut Vec<IrType>) {
+    code.push(IrType {
+        op: op.to_string(),
+        src1: cond,
+        src2: "".to_string(),
+        rd: target.to_string(),
+        span,
+    });
+}
+
+// 每次和中间的 case 比较分成两半，剩下的 case 不多时逐个比较
+fn binary_search(
+    value: &str,
+    cases: &[(i32, String)],
+    default_label: &str,
+    span: Span,
+    code: &mut Vec<IrType>,
+    temp_id: &mut usize,
+    label_id: &mut usize,
+) {
+    if cases.len() <= COMPARE_CHAIN_MAX_CASES {
+        for (v, label) in cases {
+            let cond = compare("==", value, *v, span, code, temp_id);
+            jump("JNZ", cond, label, span, code);
+        }
+        code.push(IrType {
+            op: "JMP".to_string(),
+            src1: "".to_string(),
+            src2: "".to_string(),
+            rd: default_label.to_string(),
+            span,
+        });
+        return;
+    }
+    let mid = cases.len() / 2;
+    let upper_label = format!("L{}", *label_id);
+    *label_id += 1;
+    let cond = compare("<", value, cases[mid].0, span, code, temp_id);
+    jump("JZ", cond, &upper_label, span, code);
+    binary_search(
+        value,
+        &cases[..mid],
+        default_label,
+        span,
+        code,
+        temp_id,
+        label_id,
+    );
+    code.push(IrType {
+        op: "LABEL".to_string(),
+        src1: upper_label,
+        src2: "".to_string(),
+        rd: "".to_string(),
+        span,
+    });
+    binary_search(
+        value,
+        &cases[mid..],
+        default_label,
+        span,
+        code,
+        temp_id,
+        label_id,
+    );
+}
+
+// 检查范围后用 value - min 做下标，跳转表的每一项是一个 TABLE，放在 .rodata 里
+fn jump_table(
+    value: &str,
+    cases: &[(i32, String)],
+    default_label: &str,
+    span: Span,
+    code: &mut Vec<IrType>,
+    temp_id: &mut usize,
+    label_id: &mut usize,
+) {
+    let min = cases[0].0;
+    let max = cases[cases.len() - 1].0;
+    let below = compare("<", value, min, span, code, temp_id);
+    jump("JNZ", below, default_label, span, code);
+    let above = compare(">", value, max, span, code, temp_id);
+    jump("JNZ", above, default_label, span, code);
+    let index = format!("t{}", *temp_id);
+    *temp_id += 1;
+    code.push(IrType {
+        op: "-".to_string(),
+        src1: value.to_string(),
+        src2: min.to_string(),
+        rd: index.clone(),
+        span,
+    });
+    let table = format!("L{}", *label_id);
+    *label_id += 1;
+    code.push(IrType {
+        op: "JTABLE".to_string(),
+        src1: index,
+        src2: "".to_string(),
+        rd: table.clone(),
+        span,
+    });
+    let mut next = cases.iter().peekable();
+    for v in min as i64..=max as i64 {
+        let target = match next.peek() {
+            Some((case, label)) if *case as i64 == v => {
+                next.next();
+                label.as_str()
+            }
+            _ => default_label,
+        };
+        code.push(IrType {
+            op: "TABLE".to_string(),
+            src1: target.to_string(),
+            src2: "".to_string(),
+            rd: table.clone(),
+            span,
+        });
+    }
+}
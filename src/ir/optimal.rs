@@ -7,8 +7,8 @@ pub fn const_fold_and_propagate(ir_list: &[IrType]) -> Vec<IrType> {
 
     for ir in ir_list.iter() {
         let op = ir.op.as_str();
-        // 先常量传播
-        let src1 = if consts.contains_key(&ir.src1) {
+        // 先常量传播，取地址和全局变量声明的操作数是变量本身，不能换成常量
+        let src1 = if !matches!(op, "ADDR" | "GLOBAL" | "EXTERN") && consts.contains_key(&ir.src1) {
             consts[&ir.src1].clone()
         } else {
             ir.src1.clone()
@@ -19,32 +19,29 @@ pub fn const_fold_and_propagate(ir_list: &[IrType]) -> Vec<IrType> {
             ir.src2.clone()
         };
 
-        // 遇到跳转或标签清空常量表
-        if matches!(op, "JMP" | "JZ" | "JNZ" | "LABEL" | "CALL") {
+        // 遇到跳转或标签清空常量表，通过指针写内存可能改掉任何取过地址的变量，也要清空
+        // 全局变量的值不能从上一个函数带到下一个函数
+        if matches!(
+            op,
+            "JMP" | "JZ" | "JNZ" | "LABEL" | "CALL" | "STORE" | "STOREB" | "FUNC"
+        ) {
             consts.clear();
             result.push(IrType {
                 op: ir.op.clone(),
                 src1,
                 src2,
                 rd: ir.rd.clone(),
+                span: ir.span,
             });
             continue;
         }
 
         // 常量合并
-        if (op == "+" || op == "-" || op == "*" || op == "/")
-            && src1.parse::<i64>().is_ok()
-            && src2.parse::<i64>().is_ok()
-        {
-            let a = src1.parse::<i64>().unwrap();
-            let b = src2.parse::<i64>().unwrap();
-            let val = match op {
-                "+" => a + b,
-                "-" => a - b,
-                "*" => a * b,
-                "/" => a / b,
-                _ => unreachable!(),
-            };
+        let folded = match (src1.parse::<i64>(), src2.parse::<i64>()) {
+            (Ok(a), Ok(b)) => fold_binary(op, a, b),
+            _ => None,
+        };
+        if let Some(val) = folded {
             // 常量传播
             consts.insert(ir.rd.clone(), val.to_string());
             result.push(IrType {
@@ -52,6 +49,7 @@ pub fn const_fold_and_propagate(ir_list: &[IrType]) -> Vec<IrType> {
                 src1: val.to_string(),
                 src2: "".to_string(),
                 rd: ir.rd.clone(),
+                span: ir.span,
             });
         } else if op == "MOV" {
             // 只在右值为常量时才传播
@@ -62,6 +60,7 @@ pub fn const_fold_and_propagate(ir_list: &[IrType]) -> Vec<IrType> {
                     src1,
                     src2: "".to_string(),
                     rd: ir.rd.clone(),
+                    span: ir.span,
                 });
             } else {
                 // 右值不是常量，杀死常量
@@ -71,6 +70,7 @@ pub fn const_fold_and_propagate(ir_list: &[IrType]) -> Vec<IrType> {
                     src1,
                     src2: "".to_string(),
                     rd: ir.rd.clone(),
+                    span: ir.span,
                 });
             }
         } else {
@@ -80,12 +80,34 @@ pub fn const_fold_and_propagate(ir_list: &[IrType]) -> Vec<IrType> {
                 src1,
                 src2,
                 rd: ir.rd.clone(),
+                span: ir.span,
             });
         }
     }
     result
 }
 
+// 两个操作数都是常量的二元运算，不能在编译期算的返回 None
+fn fold_binary(op: &str, a: i64, b: i64) -> Option<i64> {
+    // 全部按 32 位整数算，溢出时回绕，和 RV32 的结果一致
+    let (x, y) = (a as i32, b as i32);
+    match op {
+        "+" => Some(x.wrapping_add(y) as i64),
+        "-" => Some(x.wrapping_sub(y) as i64),
+        "*" => Some(x.wrapping_mul(y) as i64),
+        // 除数为 0 的留到运行时
+        "/" => x.checked_div(y).map(|v| v as i64),
+        "%" => x.checked_rem(y).map(|v| v as i64),
+        "&" => Some((x & y) as i64),
+        "|" => Some((x | y) as i64),
+        "^" => Some((x ^ y) as i64),
+        "<<" => Some(x.wrapping_shl(y as u32) as i64),
+        ">>" => Some(x.wrapping_shr(y as u32) as i64),
+        ">>>" => Some((x as u32).wrapping_shr(y as u32) as i32 as i64),
+        _ => None,
+    }
+}
+
 fn is_hexadecimal(s: &str) -> bool {
     // 必须以0x或0X开头，并且后面是合法的十六进制字符
     if let Some(rest) = s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
@@ -98,6 +120,11 @@ fn is_decimal(s: &str) -> bool {
     s.parse::<i64>().is_ok()
 }
 
+fn is_temp(s: &str) -> bool {
+    s.strip_prefix('t')
+        .is_some_and(|n| !n.is_empty() && n.bytes().all(|b| b.is_ascii_digit()))
+}
+
 fn dead_code_elimination(ir_list: Vec<IrType>) -> Vec<IrType> {
     use std::collections::HashSet;
     // 统计所有被用到的变量
@@ -110,10 +137,13 @@ fn dead_code_elimination(ir_list: Vec<IrType>) -> Vec<IrType> {
             used.insert(ir.src2.clone());
         }
     }
-    // 仅保留rd被用到或没有rd的四元式
+    // 仅保留rd被用到或没有rd的四元式，只删 tN 形式的临时变量，全局变量可能在别的函数里用到
     ir_list
         .into_iter()
-        .filter(|ir| ir.rd.is_empty() || used.contains(&ir.rd) || !ir.rd.starts_with('t'))
+        // 函数调用可能有副作用，返回值没用到也要保留
+        .filter(|ir| {
+            ir.rd.is_empty() || ir.op == "CALL" || used.contains(&ir.rd) || !is_temp(&ir.rd)
+        })
         .collect()
 }
 
@@ -123,18 +153,33 @@ fn common_subexpression_elimination(ir_list: Vec<IrType>) -> Vec<IrType> {
     let mut result = Vec::new();
 
     for ir in ir_list {
+        // 标签处可能从别的路径跳进来，之前算过的表达式都不能再用，写内存之后也是
+        if matches!(
+            ir.op.as_str(),
+            "LABEL" | "JMP" | "JZ" | "JNZ" | "CALL" | "STORE" | "STOREB" | "FUNC"
+        ) {
+            expr_map.clear();
+        }
         let key = (ir.op.clone(), ir.src1.clone(), ir.src2.clone());
+        let hit = expr_map.get(&key).cloned();
+        // 写了某个变量之后，用到它的表达式和存在它里面的结果都失效
+        if !ir.rd.is_empty() {
+            expr_map.retain(|(_, a, b), rd| *a != ir.rd && *b != ir.rd && *rd != ir.rd);
+        }
         if ["+", "-", "*", "/"].contains(&ir.op.as_str()) {
-            if let Some(prev_rd) = expr_map.get(&key) {
+            if let Some(prev_rd) = hit {
                 // 用上次的结果
                 result.push(IrType {
                     op: "MOV".to_string(),
-                    src1: prev_rd.clone(),
+                    src1: prev_rd,
                     src2: "".to_string(),
                     rd: ir.rd,
+                    span: ir.span,
                 });
             } else {
-                expr_map.insert(key, ir.rd.clone());
+                if ir.rd != ir.src1 && ir.rd != ir.src2 {
+                    expr_map.insert(key, ir.rd.clone());
+                }
                 result.push(ir);
             }
         } else {
@@ -154,7 +199,14 @@ fn loop_unrolling(ir_list: Vec<IrType>) -> Vec<IrType> {
             // 检查是否while模式，且循环次数为常量
             let cond_ir = &ir_list[i + 2];
             let jnz_ir = &ir_list[i + 3];
-            if cond_ir.op == "<" && cond_ir.src2.parse::<usize>().is_ok() && jnz_ir.op == "JNZ" {
+            let body_ir = &ir_list[i + 1];
+            // 只有跳回本标签、循环体是单条普通语句时才是这种模式
+            if cond_ir.op == "<"
+                && cond_ir.src2.parse::<usize>().is_ok()
+                && jnz_ir.op == "JNZ"
+                && jnz_ir.rd == ir.src1
+                && !matches!(body_ir.op.as_str(), "LABEL" | "JMP" | "JZ" | "JNZ" | "RET")
+            {
                 let n = cond_ir.src2.parse::<usize>().unwrap();
                 let loop_body = ir_list[i + 1].clone();
                 // 展开n次
@@ -177,3 +229,40 @@ pub fn optimal(ir: &[IrType]) -> Vec<IrType> {
     let ir_subexpression_elimination = common_subexpression_elimination(ir_dead_elimination);
     loop_unrolling(ir_subexpression_elimination)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::types::Span;
+
+    fn ir(op: &str, src1: &str, src2: &str, rd: &str) -> IrType {
+        IrType {
+            op: op.to_string(),
+            src1: src1.to_string(),
+            src2: src2.to_string(),
+            rd: rd.to_string(),
+            span: Span::default(),
+        }
+    }
+
+    #[test]
+    fn fold_wraps_like_rv32() {
+        assert_eq!(fold_binary("+", 0x7fffffff, 1), Some(-0x80000000));
+        assert_eq!(fold_binary("-", -0x80000000, 1), Some(0x7fffffff));
+        assert_eq!(fold_binary("*", 0x10000, 0x10000), Some(0));
+        assert_eq!(fold_binary("/", -0x80000000, -1), None);
+        assert_eq!(fold_binary("%", 7, 0), None);
+    }
+
+    #[test]
+    fn propagated_constants_stay_in_i32() {
+        let irs = vec![
+            ir("MOV", "2147483647", "", "a"),
+            ir("+", "a", "1", "t0"),
+            ir("*", "t0", "2", "t1"),
+        ];
+        let folded = const_fold_and_propagate(&irs);
+        assert_eq!(folded[1], ir("MOV", "-2147483648", "", "t0"));
+        assert_eq!(folded[2], ir("MOV", "0", "", "t1"));
+    }
+}
@@ -0,0 +1,27 @@
+// 词法分析的 DFA 表由 build.rs 根据 spec.rs 生成
+use super::spec::Rule;
+
+include!(concat!(env!("OUT_DIR"), "/lexer_dfa.rs"));
+
+// 从 at 开始做最长匹配，返回匹配到的规则和结束位置
+// byte_at 越过输入末尾时返回 None
+pub fn longest_match(
+    mut byte_at: impl FnMut(usize) -> Option<u8>,
+    at: usize,
+) -> Option<(Rule, usize)> {
+    let mut state = DFA_START;
+    let mut last = None;
+    let mut pos = at;
+    while let Some(b) = byte_at(pos) {
+        let class = DFA_BYTE_CLASS[b as usize] as usize;
+        state = DFA_TRANSITIONS[state as usize * DFA_CLASSES + class];
+        if state == DFA_DEAD {
+            break;
+        }
+        pos += 1;
+        if let Some(rule) = DFA_ACCEPT[state as usize] {
+            last = Some((rule, pos));
+        }
+    }
+    last
+}
@@ -1,77 +1,151 @@
-use std::fs::File;
-use std::io::prelude::*;
+use std::io::{self, Read};
 
-#[derive(Debug)]
-pub struct Source {
+// 每次从 reader 读入的字节数
+const CHUNK_SIZE: usize = 4096;
+
+pub struct Source<'a> {
     buffer: Vec<u8>,
+    // buffer[0] 在输入里的字节偏移，前面的输入已经丢掉了
+    base: usize,
     pointer: usize,
-    len: usize,
     row: u32,
     col: u32,
+    // 还没读完的输入，读到文件结束后置为 None
+    reader: Option<Box<dyn Read + 'a>>,
+    error: Option<io::Error>,
 }
 
-impl Source {
-    pub fn new(path: &str) -> Source {
-        //加载源代码文件，打开失败则报错
-        let mut source_file = File::open(path).expect("Error: can't open this file");
-        // 将所有源代码读入到缓冲区中
-        let mut buf: Vec<u8> = Vec::new();
-        source_file
-            .read_to_end(&mut buf)
-            .expect("Error: can't read file content");
+impl<'a> Source<'a> {
+    // 源代码由预处理器读入并展开，这里只接收缓冲区
+    pub fn new(buf: Vec<u8>) -> Source<'a> {
         // 构造Source，并返回
         Source {
+            base: 0,
             pointer: 0,
-            len: buf.len(),
             row: 1,
             col: 1, // 初始位置在第1行，第0列
             buffer: buf,
+            reader: None,
+            error: None,
         }
     }
 
-    pub fn get_char(&mut self) -> Option<char> {
-        if self.pointer < self.len {
-            let c = self.buffer[self.pointer];
-            if c.is_ascii() { Some(c as char) } else { None }
-        } else {
-            None
+    // 按需从 reader 读入，分析完的部分会丢掉，不需要把整个输入留在内存里
+    pub fn from_reader(reader: impl Read + 'a) -> Source<'a> {
+        Source {
+            reader: Some(Box::new(reader)),
+            ..Source::new(Vec::new())
         }
     }
 
-    pub fn update_pointer(&mut self, n: usize) {
-        self.pointer += n;
+    // 保证 buffer 里至少读到了偏移 n 处的字节，除非输入已经读完
+    fn fill(&mut self, n: usize) {
+        while n >= self.base + self.buffer.len() {
+            let Some(reader) = self.reader.as_mut() else {
+                return;
+            };
+            // 词法分析只会往 pointer 后面看，前面的攒够一块就丢掉
+            let used = self.pointer - self.base;
+            if used >= CHUNK_SIZE {
+                self.buffer.drain(..used);
+                self.base = self.pointer;
+            }
+            let len = self.buffer.len();
+            self.buffer.resize(len + CHUNK_SIZE, 0);
+            match reader.read(&mut self.buffer[len..]) {
+                Ok(0) => {
+                    self.buffer.truncate(len);
+                    self.reader = None;
+                }
+                Ok(k) => self.buffer.truncate(len + k),
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => self.buffer.truncate(len),
+                Err(e) => {
+                    self.buffer.truncate(len);
+                    self.reader = None;
+                    self.error = Some(e);
+                }
+            }
+        }
+    }
+
+    // 读输入时遇到的 io 错误，只返回一次
+    pub fn take_error(&mut self) -> Option<io::Error> {
+        self.error.take()
+    }
+
+    pub fn byte_at(&mut self, pos: usize) -> Option<u8> {
+        self.fill(pos);
+        self.buffer.get(pos - self.base).copied()
+    }
+
+    // 解码 pos 处的一个 UTF-8 字符，返回字符和占用的字节数
+    // 不合法的字节按单个 U+FFFD 处理
+    pub fn char_at(&mut self, pos: usize) -> Option<(char, usize)> {
+        self.fill(pos + 3);
+        decode_utf8(self.buffer.get(pos - self.base..)?)
+    }
+
+    pub fn slice(&self, lo: usize, hi: usize) -> &[u8] {
+        &self.buffer[lo - self.base..hi - self.base]
+    }
+
+    // 当前处理到的字节偏移
+    pub fn offset(&self) -> usize {
+        self.pointer
     }
 
-    // 向前查看一个字符
-    pub fn look_forward(&mut self) -> Option<char> {
-        self.pointer += 1;
-        // 统一使用get_char这种方式获取字符
-        let c: Option<char> = self.get_char();
-        // 获取完后，在将指针回退一位
-        self.pointer -= 1;
-        c
+    // 向后移动 n 个字节，同时更新行列号，列号按字符计
+    pub fn advance(&mut self, n: usize) {
+        for k in self.pointer..self.pointer + n {
+            let b = self.buffer[k - self.base];
+            if b == super::NEWLINE {
+                self.row += 1;
+                self.col = 1;
+            } else if b & 0xc0 != 0x80 {
+                // UTF-8 的后续字节不占列
+                self.col += 1;
+            }
+        }
+        self.pointer += n;
     }
 
     // 返回当前指针处理到的位置
     pub fn position(&self) -> (u32, u32) {
         (self.row, self.col)
     }
+}
 
-    fn add_row(&mut self) {
-        self.row += 1;
+fn decode_utf8(bytes: &[u8]) -> Option<(char, usize)> {
+    let first = *bytes.first()?;
+    let len = match first {
+        0x00..=0x7f => return Some((first as char, 1)),
+        0xc2..=0xdf => 2,
+        0xe0..=0xef => 3,
+        0xf0..=0xf4 => 4,
+        _ => return Some((char::REPLACEMENT_CHARACTER, 1)),
+    };
+    match bytes.get(..len).map(std::str::from_utf8) {
+        Some(Ok(s)) => s.chars().next().map(|c| (c, len)),
+        _ => Some((char::REPLACEMENT_CHARACTER, 1)),
     }
-    fn add_col(&mut self) {
-        self.col += 1;
-    }
-    pub fn init_col(&mut self) {
-        self.col = 1;
-    }
-    pub fn update_position(&mut self, c: char) {
-        if c as u8 == super::NEWLINE {
-            self.add_row();
-            self.init_col();
-        } else {
-            self.add_col();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn consumed_input_is_discarded() {
+        let input = "int x;\n".repeat(10 * CHUNK_SIZE);
+        let mut src = Source::from_reader(input.as_bytes());
+        let mut pos = 0;
+        while let Some(b) = src.byte_at(pos) {
+            assert_eq!(b, input.as_bytes()[pos]);
+            src.advance(1);
+            pos += 1;
+            assert!(src.buffer.len() <= 2 * CHUNK_SIZE);
         }
+        assert_eq!(pos, input.len());
+        assert_eq!(src.position(), (10 * CHUNK_SIZE as u32 + 1, 1));
     }
 }
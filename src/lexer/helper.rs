@@ -1,311 +1,426 @@
-use super::SPACE;
+use crate::utils::helper::intern;
+use crate::utils::types::{NumType, Span, TokenKind};
 
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub enum BinaryType {
-    Hex,
-    Oct,
-    Dec,
-    Unknown,
-}
-
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub enum LiteralType {
-    Integer(BinaryType),
-    Float,
-    Char,
-    Unknown,
-}
-
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub enum WordType {
-    Identifier,
-    Operator,
-    Separator,
-    Literal(LiteralType),
-    String,
-    Comment,
-    Unknown,
-}
+use super::types::{LexError, LexErrorKind};
 
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub enum State {
-    Accepted(WordType),
-    Unaccepted,
-    Handling(WordType),
-    Init,
+// 标识符、关键字和 true/false 共用一套拼写规则，这里区分开
+pub fn word_kind(word: &str) -> TokenKind {
+    match word {
+        "true" => TokenKind::BoolLit(true),
+        "false" => TokenKind::BoolLit(false),
+        _ => TokenKind::keyword(word).unwrap_or_else(|| TokenKind::Ident(intern(word))),
+    }
 }
 
-pub fn state_init() -> State {
-    State::Init
+// 中文输入法下容易误打的全角标点和引号，报错时提示对应的 ASCII 字符
+pub fn ascii_lookalike(c: char) -> Option<char> {
+    match c {
+        '“' | '”' | '＂' => Some('"'),
+        '‘' | '’' | '＇' => Some('\''),
+        '；' => Some(';'),
+        '，' => Some(','),
+        '：' => Some(':'),
+        '（' => Some('('),
+        '）' => Some(')'),
+        '【' | '［' => Some('['),
+        '】' | '］' => Some(']'),
+        '｛' => Some('{'),
+        '｝' => Some('}'),
+        '！' => Some('!'),
+        '？' => Some('?'),
+        '。' => Some('.'),
+        '＝' => Some('='),
+        '＋' => Some('+'),
+        '－' | '—' => Some('-'),
+        '＊' => Some('*'),
+        '／' => Some('/'),
+        '＜' | '《' => Some('<'),
+        '＞' | '》' => Some('>'),
+        '\u{a0}' | '\u{3000}' => Some(' '),
+        _ => None,
+    }
 }
 
-pub fn transition(c: char, nc: Option<char>, state: State) -> Option<State> {
-    match state {
-        State::Init => {
-            if is_invisible_char(c as u8) {
-                Some(State::Init)
-            } else if is_separator(c) {
-                Some(State::Accepted(WordType::Separator))
-            } else if is_operator(c) {
-                let next_c = nc.unwrap_or(' ');
-                if !is_operator(next_c) {
-                    Some(State::Accepted(WordType::Operator))
-                } else if let Some(next_type) =
-                    WordType::cal_word_type(c, next_c, WordType::Operator)
+// 解码字符/字符串字面量引号中间的部分，返回字节序列
+// 出错时仍然尽量给出一个值，方便后面的阶段继续，错误都记在字面量的 span 上
+pub fn decode_escapes(body: &str, span: Span, errors: &mut Vec<LexError>) -> Vec<u8> {
+    let mut error = |message: String, suggestion: &str| {
+        errors.push(
+            LexError::new(LexErrorKind::InvalidEscape, span, message).with_suggestion(suggestion),
+        )
+    };
+    // 警告先单独收集，最后再放进 errors
+    let mut warnings = Vec::new();
+    let chars: Vec<char> = body.chars().collect();
+    let mut bytes = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        i += 1;
+        if c != '\\' {
+            let mut buf = [0u8; 4];
+            bytes.extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
+            continue;
+        }
+        let Some(&e) = chars.get(i) else {
+            error(
+                String::from("incomplete escape sequence"),
+                "write '\\\\' for a literal backslash",
+            );
+            break;
+        };
+        i += 1;
+        match e {
+            '\'' | '"' | '?' | '\\' => bytes.push(e as u8),
+            'a' => bytes.push(0x07),
+            'b' => bytes.push(0x08),
+            'f' => bytes.push(0x0c),
+            'n' => bytes.push(b'\n'),
+            'r' => bytes.push(b'\r'),
+            't' => bytes.push(b'\t'),
+            'v' => bytes.push(0x0b),
+            '0'..='7' => {
+                // 最多三位八进制数
+                let mut value = e.to_digit(8).unwrap();
+                let mut n = 1;
+                while n < 3
+                    && let Some(d) = chars.get(i).and_then(|c| c.to_digit(8))
                 {
-                    Some(State::Handling(next_type))
-                } else {
-                    Some(State::Unaccepted)
+                    value = value * 8 + d;
+                    i += 1;
+                    n += 1;
                 }
-            } else if is_valid_char(c) {
-                let next_c = nc.unwrap_or(' ');
-                let next_type = WordType::cal_word_type(c, next_c, WordType::Unknown);
-                if next_c as u8 == SPACE || is_separator(next_c) || is_operator(next_c) {
-                    if let Some(next) = next_type {
-                        Some(State::Accepted(next))
-                    } else {
-                        Some(State::Unaccepted)
-                    }
-                } else if let Some(next) = next_type {
-                    Some(State::Handling(next))
-                } else {
-                    Some(State::Unaccepted)
+                if value > 0xff {
+                    error(
+                        String::from("octal escape sequence out of range"),
+                        "the value of an escape sequence must fit in one byte",
+                    );
                 }
-            } else if c as u8 == 0x22 {
-                Some(State::Handling(WordType::String))
-            } else if c as u8 == 0x27 {
-                Some(State::Handling(WordType::Literal(LiteralType::Char)))
-            } else {
-                Some(State::Unaccepted)
+                bytes.push(value as u8);
             }
-        }
-        State::Handling(current_type) => {
-            let next_c = nc.unwrap_or(' '); // 如果 None，则默认 ' '
-            if is_separator(next_c) || next_c as u8 == SPACE {
-                let next_type = WordType::cal_word_type(c, next_c, current_type);
-                if let Some(next) = next_type {
-                    if next == WordType::Unknown {
-                        Some(State::Accepted(current_type))
-                    } else {
-                        Some(State::Accepted(next))
-                    }
-                } else {
-                    Some(State::Unaccepted)
+            'x' => {
+                let start = i;
+                let mut value: u32 = 0;
+                let mut overflow = false;
+                while let Some(d) = chars.get(i).and_then(|c| c.to_digit(16)) {
+                    value = value.wrapping_mul(16).wrapping_add(d);
+                    overflow |= i - start >= 8;
+                    i += 1;
                 }
-            } else {
-                let next_type = WordType::cal_word_type(c, next_c, current_type);
-                match next_type {
-                    Some(next) => match next {
-                        WordType::Unknown => Some(State::Accepted(current_type)),
-                        _ => Some(State::Handling(next)),
-                    },
-                    None => Some(State::Unaccepted),
+                if i == start {
+                    error(
+                        String::from("\\x used with no following hex digits"),
+                        "write at least one hex digit after \\x, e.g. \\x41",
+                    );
+                } else if overflow || value > 0xff {
+                    error(
+                        String::from("hex escape sequence out of range"),
+                        "the value of an escape sequence must fit in one byte",
+                    );
                 }
+                bytes.push(value as u8);
             }
-        }
-
-        State::Accepted(_) | State::Unaccepted => Some(State::Init),
-    }
-}
-
-pub fn is_invisible_char(c: u8) -> bool {
-    match c {
-        // 空格
-        32 => true,
-        // \r\n
-        10 | 13 => true,
-        // tab
-        9 | 11 => true,
-        // other
-        _ => false,
-    }
-}
-
-pub fn is_separator(c: char) -> bool {
-    matches!(c, '(' | ')' | '{' | '}' | '[' | ']' | ';' | ',' | ':')
-}
-
-pub fn is_operator(c: char) -> bool {
-    matches!(
-        c,
-        '+' | '-' | '*' | '/' | '%' | '=' | '!' | '>' | '<' | '&' | '|' | '^' | '~'
-    )
-}
-
-pub fn is_valid_char(c: char) -> bool {
-    let is_alpha = c.is_ascii_alphabetic();
-    let is_digit = c.is_ascii_digit();
-    let is_special = matches!(c, '_' | '$');
-    is_alpha || is_digit || is_special
-}
-
-impl LiteralType {
-    pub fn cal_literal_type(c: char, nc: char, state: LiteralType) -> Option<LiteralType> {
-        match state {
-            LiteralType::Integer(ref t) => {
-                if let Some(next_state) = BinaryType::cal_binary_type(c, nc, *t) {
-                    if next_state == BinaryType::Unknown {
-                        Some(LiteralType::Unknown)
-                    } else {
-                        Some(LiteralType::Integer(next_state))
+            'u' | 'U' => {
+                // 通用字符名，按 UTF-8 编码
+                let len = if e == 'u' { 4 } else { 8 };
+                let digits: String = chars[i..].iter().take(len).collect();
+                match u32::from_str_radix(&digits, 16)
+                    .ok()
+                    .filter(|_| digits.len() == len)
+                    .and_then(char::from_u32)
+                {
+                    Some(u) => {
+                        let mut buf = [0u8; 4];
+                        bytes.extend_from_slice(u.encode_utf8(&mut buf).as_bytes());
                     }
-                } else if c == '.' {
-                    Some(LiteralType::Float)
-                } else {
-                    None
+                    None => error(
+                        format!("incomplete universal character name \\{}{}", e, digits),
+                        "\\u takes exactly 4 hex digits and \\U takes exactly 8",
+                    ),
                 }
+                i += digits.chars().count();
             }
-            LiteralType::Float => match c {
-                '0'..='9' => Some(LiteralType::Float),
-                _ => None,
-            },
-            LiteralType::Char => {
-                if c.is_ascii() && c != '\'' {
-                    Some(LiteralType::Char)
-                } else if c == '\'' {
-                    Some(LiteralType::Unknown)
-                } else {
-                    None
-                }
+            _ => {
+                // 和 gcc 一样只给警告，取字符本身
+                warnings.push(LexError::new(
+                    LexErrorKind::UnknownEscape,
+                    span,
+                    format!("unknown escape sequence '\\{}'", e),
+                ));
+                let mut buf = [0u8; 4];
+                bytes.extend_from_slice(e.encode_utf8(&mut buf).as_bytes());
             }
-            LiteralType::Unknown => match c {
-                '0'..='9' => BinaryType::cal_binary_type(c, nc, BinaryType::Unknown)
-                    .map(LiteralType::Integer),
-                '\'' => Some(LiteralType::Char),
-                _ => None,
-            },
         }
     }
+    errors.extend(warnings);
+    bytes
 }
 
-impl WordType {
-    pub fn cal_word_type(c: char, nc: char, state: WordType) -> Option<WordType> {
-        match state {
-            WordType::Unknown => match c {
-                'a'..='z' | 'A'..='Z' | '_' => Some(WordType::Identifier),
-                '0'..='9' => LiteralType::cal_literal_type(c, nc, LiteralType::Unknown)
-                    .map(WordType::Literal),
-                _ => None,
-            },
-            WordType::Identifier => match c {
-                'a'..='z' | 'A'..='Z' | '_' | '0'..='9' => Some(WordType::Identifier),
-                _ => None,
-            },
-            WordType::Literal(ref t) => {
-                if let Some(next_type) = LiteralType::cal_literal_type(c, nc, *t) {
-                    if next_type == LiteralType::Unknown {
-                        Some(WordType::Unknown)
-                    } else {
-                        Some(WordType::Literal(next_type))
-                    }
-                } else {
-                    None
-                }
-            }
-            WordType::String => {
-                if is_separator(nc) {
-                    match c {
-                        '"' => Some(WordType::String),
-                        _ => None,
-                    }
-                } else {
-                    match c {
-                        '"' => Some(WordType::Unknown),
-                        c if is_valid_char(c) => Some(WordType::String),
-                        _ => None,
-                    }
-                }
-            }
-            WordType::Comment => Some(WordType::Comment),
-            WordType::Operator => {
-                if is_comment(c, nc) {
-                    Some(WordType::Comment)
-                } else if is_operator(c) {
-                    if !is_operator(nc) {
-                        Some(WordType::Unknown)
-                    } else {
-                        Some(WordType::Operator)
-                    }
-                } else {
-                    None
-                }
-            }
-            WordType::Separator => Some(WordType::Separator),
-        }
+// 字符常量的类型是 int：单字节是这个 char 转成 int 的值，char 按有符号处理，和 lb 读出来的一致，
+// 多字节按 gcc 的做法依次左移拼起来，截断成 int
+pub fn char_constant_value(bytes: &[u8], span: Span, errors: &mut Vec<LexError>) -> i64 {
+    if let [byte] = bytes {
+        return *byte as i8 as i64;
+    }
+    if bytes.len() > 1 {
+        errors.push(LexError::new(
+            LexErrorKind::MultiCharConstant,
+            span,
+            String::from("multi-character character constant"),
+        ));
     }
+    let value = bytes
+        .iter()
+        .fold(0u32, |acc, b| acc.wrapping_shl(8) | *b as u32);
+    value as i32 as i64
 }
 
-impl BinaryType {
-    pub fn cal_binary_type(c: char, nc: char, state: BinaryType) -> Option<BinaryType> {
-        match state {
-            BinaryType::Hex => match c {
-                '0'..='9' | 'a'..='f' | 'A'..='F' => Some(BinaryType::Hex),
-                _ => None,
-            },
-            BinaryType::Oct => match c {
-                '0'..='7' => Some(BinaryType::Oct),
-                _ => None,
-            },
-            BinaryType::Dec => {
-                if c.is_numeric() {
-                    Some(BinaryType::Dec)
-                } else if is_invisible_char(c as u8) {
-                    Some(BinaryType::Unknown)
-                } else {
-                    None
-                }
-            }
-            BinaryType::Unknown => match c {
-                '1'..='9' => {
-                    if nc.is_numeric() || is_separator(nc) || is_invisible_char(nc as u8) {
-                        Some(BinaryType::Dec)
-                    } else if nc == '.' {
-                        Some(BinaryType::Unknown)
-                    } else {
-                        None
-                    }
-                }
-                '0' => match nc {
-                    'X' | 'x' => Some(BinaryType::Hex),
-                    '0'..'8' => Some(BinaryType::Oct),
-                    _ => {
-                        if is_separator(nc) {
-                            Some(BinaryType::Dec)
-                        } else {
-                            None
-                        }
-                    }
-                },
-                _ => None,
-            },
-        }
+// 解析一个完整的数值字面量（预处理数的写法），返回分类和值
+// 超出范围、非法后缀、非法数字都返回具体的错误和修改建议，警告放进 warnings
+pub fn parse_number(
+    raw: &str,
+    span: Span,
+    warnings: &mut Vec<LexError>,
+) -> Result<TokenKind, LexError> {
+    let lower = raw.to_ascii_lowercase();
+    let is_hex = lower.starts_with("0x");
+    let is_float = if is_hex {
+        lower.contains('.') || lower.contains('p')
+    } else {
+        !lower.starts_with("0b") && (lower.contains('.') || lower.contains('e'))
+    };
+    if is_float {
+        parse_float(raw, is_hex, span)
+    } else {
+        parse_integer(raw, span, warnings)
     }
 }
 
-pub fn is_reserved_word(word: String) -> bool {
-    matches!(
-        word.as_str(),
-        "if" | "else"
-            | "while"
-            | "for"
-            | "return"
-            | "break"
-            | "continue"
-            | "int"
-            | "float"
-            | "double"
-            | "char"
-            | "String"
-            | "uint"
-    )
+fn parse_integer(
+    raw: &str,
+    span: Span,
+    warnings: &mut Vec<LexError>,
+) -> Result<TokenKind, LexError> {
+    let error = |kind, message| LexError::new(kind, span, message);
+    let bad_suffix = |suffix: &str| {
+        error(
+            LexErrorKind::InvalidSuffix,
+            format!("invalid suffix \"{}\" on integer constant", suffix),
+        )
+        .with_suggestion("valid integer suffixes are u, l, ll and their combinations ul, ull")
+    };
+    let lower = raw.to_ascii_lowercase();
+    let (radix, body) = if let Some(rest) = lower.strip_prefix("0x") {
+        (16, rest)
+    } else if let Some(rest) = lower.strip_prefix("0b") {
+        (2, rest)
+    } else if lower.starts_with('0') {
+        (8, &lower[..])
+    } else {
+        (10, &lower[..])
+    };
+    // 数字部分到第一个不是十六进制/十进制数字的字符为止，剩下的是后缀
+    let digits_end = body
+        .find(|c: char| {
+            if radix == 16 {
+                !c.is_ascii_hexdigit()
+            } else {
+                !c.is_ascii_digit()
+            }
+        })
+        .unwrap_or(body.len());
+    let (digits, suffix) = body.split_at(digits_end);
+    let suffix_raw = &raw[raw.len() - suffix.len()..];
+    if digits.is_empty() {
+        return Err(bad_suffix(&raw[1..])
+            .with_suggestion(format!("write at least one digit after \"{}\"", &raw[..2])));
+    }
+    if let Some(bad) = digits.chars().find(|c| c.to_digit(radix).is_none()) {
+        let (name, suggestion) = if radix == 8 {
+            let decimal = digits.trim_start_matches('0');
+            (
+                "octal",
+                format!(
+                    "a leading 0 makes the constant octal; write {}{} for a decimal value",
+                    decimal, suffix_raw
+                ),
+            )
+        } else {
+            (
+                "binary",
+                String::from("binary constants may only use the digits 0 and 1"),
+            )
+        };
+        return Err(error(
+            LexErrorKind::BadDigit,
+            format!("invalid digit \"{}\" in {} constant", bad, name),
+        )
+        .with_suggestion(suggestion));
+    }
+    let (unsigned, longs) = match suffix {
+        "" => (false, 0),
+        "u" => (true, 0),
+        "l" => (false, 1),
+        "ul" | "lu" => (true, 1),
+        "ll" => (false, 2),
+        "ull" | "llu" => (true, 2),
+        _ => return Err(bad_suffix(suffix_raw)),
+    };
+    // 大小写混用的 lL/Ll 不是合法的 long long 后缀
+    if longs == 2 && !(suffix_raw.contains("ll") || suffix_raw.contains("LL")) {
+        return Err(bad_suffix(suffix_raw).with_suggestion("write long long as ll or LL, not lL"));
+    }
+    let value = u64::from_str_radix(digits, radix).map_err(|_| {
+        error(
+            LexErrorKind::OutOfRange,
+            String::from("integer constant is too large for its type"),
+        )
+    })?;
+    // C11 6.4.4.1：按顺序取第一个能表示这个值的类型
+    let candidates: &[NumType] = match (radix == 10, unsigned, longs) {
+        (_, true, 0) => &[NumType::UInt, NumType::ULong, NumType::ULongLong],
+        (_, true, 1) => &[NumType::ULong, NumType::ULongLong],
+        (_, true, _) => &[NumType::ULongLong],
+        (true, false, 0) => &[NumType::Int, NumType::Long, NumType::LongLong],
+        (true, false, 1) => &[NumType::Long, NumType::LongLong],
+        (true, false, _) => &[NumType::LongLong],
+        (false, false, 0) => &[
+            NumType::Int,
+            NumType::UInt,
+            NumType::Long,
+            NumType::ULong,
+            NumType::LongLong,
+            NumType::ULongLong,
+        ],
+        (false, false, 1) => &[
+            NumType::Long,
+            NumType::ULong,
+            NumType::LongLong,
+            NumType::ULongLong,
+        ],
+        (false, false, _) => &[NumType::LongLong, NumType::ULongLong],
+    };
+    let ty = match candidates.iter().find(|t| value <= t.max_value()) {
+        Some(ty) => *ty,
+        None => {
+            // 和 gcc 一样，十进制常量放不下 long long 时当作无符号数
+            warnings.push(error(
+                LexErrorKind::LargeUnsigned,
+                String::from("integer constant is so large that it is unsigned"),
+            ));
+            NumType::ULongLong
+        }
+    };
+    Ok(TokenKind::IntLit(value, ty))
 }
 
-pub fn is_bool_identifier(word: String) -> bool {
-    matches!(word.as_str(), "true" | "false")
+fn parse_float(raw: &str, is_hex: bool, span: Span) -> Result<TokenKind, LexError> {
+    let error = |kind, message| LexError::new(kind, span, message);
+    let lower = raw.to_ascii_lowercase();
+    let (body, ty) = if let Some(b) = lower.strip_suffix('f') {
+        (b, NumType::Float)
+    } else if let Some(b) = lower.strip_suffix('l') {
+        (b, NumType::LongDouble)
+    } else {
+        (&lower[..], NumType::Double)
+    };
+    let value = if is_hex {
+        parse_hex_float(&body[2..]).map_err(|(kind, message, suggestion)| {
+            error(kind, message).with_suggestion(suggestion)
+        })?
+    } else {
+        let exp_digits = body
+            .split_once('e')
+            .map(|(_, e)| e.trim_start_matches(['+', '-']));
+        if exp_digits.is_some_and(|e| e.is_empty()) {
+            return Err(error(
+                LexErrorKind::MalformedNumber,
+                String::from("exponent has no digits"),
+            )
+            .with_suggestion("write digits after the exponent, e.g. 1e10"));
+        }
+        body.parse::<f64>().map_err(|_| {
+            let suffix_start = body
+                .find(|c: char| {
+                    !(c.is_ascii_digit() || c == '.' || c == 'e' || c == '+' || c == '-')
+                })
+                .unwrap_or(0);
+            error(
+                LexErrorKind::InvalidSuffix,
+                format!(
+                    "invalid suffix \"{}\" on floating constant",
+                    &raw[suffix_start..]
+                ),
+            )
+            .with_suggestion("valid floating suffixes are f and l")
+        })?
+    };
+    if value.is_infinite() {
+        return Err(error(
+            LexErrorKind::OutOfRange,
+            String::from("floating constant exceeds range of 'double'"),
+        ));
+    }
+    if ty == NumType::Float && value > f32::MAX as f64 {
+        return Err(error(
+            LexErrorKind::OutOfRange,
+            format!("floating constant exceeds range of '{}'", ty.name()),
+        )
+        .with_suggestion("drop the f suffix to make it a double"));
+    }
+    Ok(TokenKind::FloatLit(value, ty))
 }
 
-pub fn is_comment(c: char, nc: char) -> bool {
-    let is_single_line_comment: bool = c == '/' && nc == '/';
-    let is_multi_line_comment: bool = c == '/' && nc == '*';
-    is_multi_line_comment | is_single_line_comment
+// 十六进制浮点数 h.hhhp±d，去掉了 0x 前缀
+// 出错时返回 (错误类别, 信息, 建议)，span 由调用者补上
+fn parse_hex_float(body: &str) -> Result<f64, (LexErrorKind, String, &'static str)> {
+    let Some((mantissa, exp)) = body.split_once('p') else {
+        return Err((
+            LexErrorKind::MalformedNumber,
+            String::from("hexadecimal floating constants require an exponent"),
+            "add a binary exponent, e.g. 0x1.8p0",
+        ));
+    };
+    let exp_digits = exp.trim_start_matches(['+', '-']);
+    if exp_digits.is_empty() || !exp_digits.chars().all(|c| c.is_ascii_digit()) {
+        return Err((
+            LexErrorKind::MalformedNumber,
+            String::from("exponent has no digits"),
+            "write decimal digits after p, e.g. 0x1p4",
+        ));
+    }
+    let (int_part, frac_part) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    if int_part.is_empty() && frac_part.is_empty() {
+        return Err((
+            LexErrorKind::MalformedNumber,
+            String::from("no digits in hexadecimal floating constant"),
+            "write at least one hex digit before p",
+        ));
+    }
+    let bad_digit = |c: char| {
+        (
+            LexErrorKind::BadDigit,
+            format!("invalid digit \"{}\" in hexadecimal floating constant", c),
+            "hexadecimal constants may only use 0-9 and a-f",
+        )
+    };
+    let mut value = 0f64;
+    for c in int_part.chars() {
+        let d = c.to_digit(16).ok_or_else(|| bad_digit(c))?;
+        value = value * 16.0 + d as f64;
+    }
+    let mut scale = 1.0 / 16.0;
+    for c in frac_part.chars() {
+        let d = c.to_digit(16).ok_or_else(|| bad_digit(c))?;
+        value += d as f64 * scale;
+        scale /= 16.0;
+    }
+    let exp: i32 = exp.parse().map_err(|_| {
+        (
+            LexErrorKind::OutOfRange,
+            String::from("floating constant exceeds range of 'double'"),
+            "use a smaller exponent",
+        )
+    })?;
+    Ok(value * 2f64.powi(exp))
 }
@@ -1,241 +1,589 @@
-use crate::lexer::NEWLINE;
-use crate::utils::helper::print_err_info;
-use crate::utils::types::PhraseType;
+use crate::preprocessor::helper::span_of;
+use crate::utils::session::register_file;
+use crate::utils::types::{NumType, Span, TokenKind};
 
+use super::dfa;
 use super::file::Source;
 use super::helper::*;
-use std::panic;
+use super::spec::Rule;
+use super::types::{LexError, LexErrorKind};
+use std::collections::VecDeque;
+use std::fmt;
+use std::io::Read;
+use std::sync::Arc;
 use std::vec::Vec;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Token {
-    pub pos: usize,
-    pub types: PhraseType,
-    pub value: String,
+    pub span: Span,
+    pub kind: TokenKind,
 }
 
-fn push(pos_cow: usize, types: PhraseType, token_value: String, array: &mut Vec<Token>) {
-    array.push(Token {
-        pos: pos_cow,
-        types,
-        value: token_value,
-    });
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.kind)
+    }
 }
-pub fn run(filepath: &str) -> Vec<Token> {
-    let mut f = Source::new(filepath);
-    let mut tokens = Vec::new();
-    let mut state = State::Init;
-    let mut token: Vec<char> = Vec::new();
-    let mut err_info: Vec<String> = Vec::new();
-
-    while let Some(c) = f.get_char() {
-        let nc = f.look_forward();
-        if let Some(next) = transition(c, nc, state) {
-            state = next;
-        } else {
-            panic!("Error: unexpected state!");
-        }
-        f.update_pointer(1);
-        // println!("{:?} {:?}", state, c);
-        match state {
-            State::Accepted(ref t) => {
-                let need_push = c as u8 != super::NEWLINE && c as u8 != super::SPACE;
-                if need_push {
-                    token.push(c);
-                }
-                f.update_position(c);
-                let pos_cow = f.position().0 as usize;
-                match *t {
-                    WordType::Identifier => {
-                        if is_reserved_word(token.iter().collect()) {
-                            push(
-                                pos_cow,
-                                PhraseType::Keyword,
-                                token.iter().collect(),
-                                &mut tokens,
-                            );
-                        } else if is_bool_identifier(token.iter().collect()) {
-                            push(
-                                pos_cow,
-                                PhraseType::Bool,
-                                token.iter().collect(),
-                                &mut tokens,
-                            );
-                        } else {
-                            push(
-                                pos_cow,
-                                PhraseType::Identifier,
-                                token.iter().collect(),
-                                &mut tokens,
-                            );
-                        }
-                    }
-                    WordType::Operator => {
-                        push(
-                            pos_cow,
-                            PhraseType::Operator,
-                            token.iter().collect(),
-                            &mut tokens,
-                        );
-                    }
-                    WordType::Separator => {
-                        push(
-                            pos_cow,
-                            PhraseType::Separator,
-                            token.iter().collect(),
-                            &mut tokens,
-                        );
-                    }
-                    WordType::Literal(ref t) => match *t {
-                        LiteralType::Integer(ref t) => match *t {
-                            BinaryType::Hex => {
-                                push(
-                                    pos_cow,
-                                    PhraseType::Hex,
-                                    token.iter().collect(),
-                                    &mut tokens,
-                                );
-                            }
-                            BinaryType::Oct => {
-                                push(
-                                    pos_cow,
-                                    PhraseType::Oct,
-                                    token.iter().collect(),
-                                    &mut tokens,
-                                );
-                            }
-                            BinaryType::Dec => {
-                                push(
-                                    pos_cow,
-                                    PhraseType::Dec,
-                                    token.iter().collect(),
-                                    &mut tokens,
-                                );
-                            }
-                            BinaryType::Unknown => {
-                                push(
-                                    pos_cow,
-                                    PhraseType::Unknown,
-                                    token.iter().collect(),
-                                    &mut tokens,
-                                );
-                            }
-                        },
-                        LiteralType::Float => {
-                            push(
-                                pos_cow,
-                                PhraseType::Float,
-                                token.iter().collect(),
-                                &mut tokens,
-                            );
-                        }
-                        LiteralType::Char => {
-                            push(
-                                pos_cow,
-                                PhraseType::Char,
-                                token.iter().collect(),
-                                &mut tokens,
-                            );
-                        }
-                        LiteralType::Unknown => {
-                            push(
-                                pos_cow,
-                                PhraseType::Unknown,
-                                token.iter().collect(),
-                                &mut tokens,
-                            );
-                        }
-                    },
-                    WordType::String => {
-                        push(
-                            pos_cow,
-                            PhraseType::String,
-                            token.iter().collect(),
-                            &mut tokens,
-                        );
-                    }
-                    WordType::Comment => {
-                        let comment_type = token[1] == '/';
-                        if comment_type {
-                            while let Some(c) = f.get_char() {
-                                f.update_pointer(1);
-                                if c as u8 == NEWLINE {
-                                    f.update_position(c);
-                                    break;
-                                }
-                            }
-                        } else {
-                            let mut comment_end = false;
-                            while let Some(c) = f.get_char() {
-                                f.update_pointer(1);
-                                if c == '*' {
-                                    comment_end = true;
-                                } else if comment_end && c == '/' {
-                                    break;
-                                } else {
-                                    f.update_position(c);
-                                    comment_end = false;
-                                }
-                            }
-                        }
-                    }
-                    WordType::Unknown => {
-                        push(
-                            pos_cow,
-                            PhraseType::Unknown,
-                            token.iter().collect(),
-                            &mut tokens,
-                        );
-                    }
-                }
-                token.clear();
-                state = state_init();
+
+// 词法分析器，按需产生 token，出错时产生 LexError 并继续往下分析
+pub struct Lexer<'a> {
+    src: Source<'a>,
+    // 已经分析出来但还没有交出去的结果
+    pending: VecDeque<Result<Token, LexError>>,
+    // None 表示输入是预处理之后的文本，位置通过行号映射换算回源文件
+    file: Option<u32>,
+}
+
+impl Lexer<'static> {
+    pub fn new(source: &str) -> Lexer<'static> {
+        Lexer::from_bytes(source.as_bytes())
+    }
+
+    // token 的位置里已经有行列号和字节范围，源码原文不用再登记一份
+    pub fn from_bytes(source: &[u8]) -> Lexer<'static> {
+        let file = register_file("<input>", Arc::from(""));
+        Lexer::with_source(Source::new(source.to_vec()), Some(file))
+    }
+
+    // 预处理器的输出，token 的位置记在原来的源文件上
+    pub fn from_preprocessed(source: &str) -> Lexer<'static> {
+        Lexer::with_source(Source::new(source.as_bytes().to_vec()), None)
+    }
+}
+
+impl<'a> Lexer<'a> {
+    // 边读边分析，分析完的输入随时丢掉，源码原文也就无从登记
+    pub fn from_reader(reader: impl Read + 'a) -> Lexer<'a> {
+        let file = register_file("<input>", Arc::from(""));
+        Lexer::with_source(Source::from_reader(reader), Some(file))
+    }
+
+    fn with_source(src: Source<'a>, file: Option<u32>) -> Lexer<'a> {
+        Lexer {
+            src,
+            pending: VecDeque::new(),
+            file,
+        }
+    }
+
+    // pos 是起点的行列号，[lo, hi) 是输入中的字节范围
+    fn span(&self, pos: (u32, u32), lo: usize, hi: usize) -> Span {
+        let Some(file) = self.file else {
+            let len = String::from_utf8_lossy(self.src.slice(lo, hi))
+                .chars()
+                .count();
+            return span_of(pos.0 as usize, pos.1 as usize, len);
+        };
+        Span {
+            file,
+            line: pos.0,
+            col: pos.1,
+            lo,
+            hi,
+        }
+    }
+
+    fn push(&mut self, span: Span, kind: TokenKind) {
+        self.pending.push_back(Ok(Token { span, kind }));
+    }
+
+    fn error(&mut self, error: LexError) {
+        self.pending.push_back(Err(error));
+    }
+
+    // 取下一个结果，必要时继续读输入
+    fn fetch(&mut self) -> Option<Result<Token, LexError>> {
+        loop {
+            if let Some(item) = self.pending.pop_front() {
+                return Some(item);
             }
-            State::Unaccepted => {
-                let pos = f.position();
-                token.push(c);
-                while let Some(c) = f.get_char() {
-                    f.update_pointer(1);
-                    f.update_position(c);
-                    if is_separator(c) || is_invisible_char(c as u8) {
-                        break;
-                    }
-                    token.push(c);
-                }
-                let info = format!(
-                    "Error->position ({}, {})! {:?}",
-                    pos.0,
-                    pos.1,
-                    token.iter().collect::<String>()
+            if !self.step() {
+                return None;
+            }
+        }
+    }
+
+    fn peek(&mut self) -> Option<&Result<Token, LexError>> {
+        while self.pending.is_empty() {
+            if !self.step() {
+                return None;
+            }
+        }
+        self.pending.front()
+    }
+
+    // 用 DFA 读一个词法单元，输入结束时返回 false
+    fn step(&mut self) -> bool {
+        let (pos, lo) = (self.src.position(), self.src.offset());
+        let first = self.src.byte_at(lo);
+        // 读入出错后输入就到此为止，先把错误交出去，下一次再结束
+        if let Some(e) = self.src.take_error() {
+            let span = self.span(pos, lo, lo);
+            self.error(LexError::new(
+                LexErrorKind::Io,
+                span,
+                format!("failed to read input: {}", e),
+            ));
+            return true;
+        }
+        let Some(first) = first else {
+            return false;
+        };
+        let src = &mut self.src;
+        let Some((rule, hi)) = dfa::longest_match(|k| src.byte_at(k), lo) else {
+            // 没有规则能匹配：跳过一个字符并报错，后面的 token 不受影响
+            let (c, len) = self.src.char_at(lo).unwrap();
+            self.src.advance(len);
+            let span = self.span(pos, lo, lo + len);
+            let error = if c.is_ascii() {
+                LexError::new(
+                    LexErrorKind::StrayChar,
+                    span,
+                    format!("stray '{}' in program", c.escape_default()),
+                )
+            } else if c == char::REPLACEMENT_CHARACTER && len == 1 {
+                LexError::new(
+                    LexErrorKind::StrayChar,
+                    span,
+                    format!("invalid UTF-8 byte 0x{:02x} in program", first),
+                )
+                .with_suggestion("save the source file as UTF-8")
+            } else {
+                let error = LexError::new(
+                    LexErrorKind::StrayChar,
+                    span,
+                    format!(
+                        "non-ASCII character '{}' (U+{:04X}) is only allowed in comments and literals",
+                        c, c as u32
+                    ),
                 );
-                err_info.push(info);
-                token.clear();
-                state = state_init();
+                match ascii_lookalike(c) {
+                    Some(a) => error.with_suggestion(format!("did you mean '{}'?", a)),
+                    None => error,
+                }
+            };
+            self.error(error);
+            return true;
+        };
+        self.src.advance(hi - lo);
+        if matches!(
+            rule,
+            Rule::Whitespace | Rule::LineComment | Rule::BlockComment
+        ) {
+            return true;
+        }
+        let span = self.span(pos, lo, hi);
+        let raw = String::from_utf8_lossy(self.src.slice(lo, hi));
+        let (kind, errors) = token_kind(rule, &raw, span);
+        for e in errors {
+            self.error(e);
+        }
+        if let Some(kind) = kind {
+            self.push(span, kind);
+        }
+        true
+    }
+}
+
+// 按匹配到的规则得到 token 和错误，出错时尽量仍然给出一个 token，
+// 这样后面的语法分析看到的 token 流和用户的本意差不多
+fn token_kind(rule: Rule, raw: &str, span: Span) -> (Option<TokenKind>, Vec<LexError>) {
+    let mut errors = Vec::new();
+    let kind = match rule {
+        Rule::Whitespace | Rule::LineComment | Rule::BlockComment => None,
+        // 注释一直延伸到文件结束，整个跳过
+        Rule::UnterminatedComment => {
+            errors.push(
+                LexError::new(
+                    LexErrorKind::UnterminatedComment,
+                    span,
+                    String::from("unterminated comment"),
+                )
+                .with_suggestion("close the comment with */"),
+            );
+            None
+        }
+        Rule::Ident => Some(word_kind(raw)),
+        // 规则里的每个标点都在 PUNCTUATORS 表中
+        Rule::Punct => Some(TokenKind::punctuator(raw).expect("punctuator missing from table")),
+        // 数值字面量整体读出来再按 C11 的文法解析
+        Rule::Number => match parse_number(raw, span, &mut errors) {
+            Ok(kind) => Some(kind),
+            Err(e) => {
+                errors.push(e);
+                // 出错的常量当作 0，后面的阶段还能继续
+                Some(TokenKind::IntLit(0, NumType::Int))
+            }
+        },
+        // 没有闭合的字面量在行尾结束，已经读到的内容照常给出
+        Rule::UnterminatedChar | Rule::UnterminatedStr => {
+            let (kind, quote) = if rule == Rule::UnterminatedChar {
+                (LexErrorKind::UnterminatedChar, '\'')
+            } else {
+                (LexErrorKind::UnterminatedString, '"')
+            };
+            errors.push(
+                LexError::new(
+                    kind,
+                    span,
+                    format!("missing terminating {} character", quote),
+                )
+                .with_suggestion(format!(
+                    "add a closing {} before the end of the line",
+                    quote
+                )),
+            );
+            let body = raw[1..].strip_suffix('\\').unwrap_or(&raw[1..]);
+            let bytes = decode_escapes(body, span, &mut errors);
+            if rule == Rule::UnterminatedStr {
+                Some(TokenKind::StrLit(bytes))
+            } else {
+                Some(TokenKind::CharLit(char_constant_value(
+                    &bytes,
+                    span,
+                    &mut errors,
+                )))
             }
-            State::Handling(_) => {
-                f.update_position(c);
-                token.push(c);
-                if is_hex_format(c, nc, state) {
-                    let next_c = nc.unwrap_or(' ');
-                    token.push(next_c);
-                    f.update_pointer(1);
+        }
+        // 字符常量和字符串字面量单独处理转义
+        Rule::Char | Rule::Str => {
+            let bytes = decode_escapes(&raw[1..raw.len() - 1], span, &mut errors);
+            if rule == Rule::Str {
+                Some(TokenKind::StrLit(bytes))
+            } else {
+                if bytes.is_empty() {
+                    errors.push(
+                        LexError::new(
+                            LexErrorKind::EmptyChar,
+                            span,
+                            String::from("empty character constant"),
+                        )
+                        .with_suggestion("write '\\0' for a null character"),
+                    );
                 }
+                Some(TokenKind::CharLit(char_constant_value(
+                    &bytes,
+                    span,
+                    &mut errors,
+                )))
             }
-            State::Init => {
-                f.update_position(c);
+        }
+    };
+    (kind, errors)
+}
+
+impl Iterator for Lexer<'_> {
+    type Item = Result<Token, LexError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut item = self.fetch()?;
+        // 相邻的字符串字面量拼接成一个
+        if let Ok(prev) = &mut item
+            && let TokenKind::StrLit(bytes) = &mut prev.kind
+        {
+            while self.peek().is_some_and(|r| {
+                matches!(
+                    r,
+                    Ok(Token {
+                        kind: TokenKind::StrLit(_),
+                        ..
+                    })
+                )
+            }) {
+                let Some(Ok(tok)) = self.pending.pop_front() else {
+                    unreachable!()
+                };
+                if let TokenKind::StrLit(b) = tok.kind {
+                    bytes.extend_from_slice(&b);
+                }
+                prev.span = prev.span.to(tok.span);
             }
         }
+        Some(item)
+    }
+}
+
+// 输入是预处理之后的文本，返回所有 token 和所有词法错误
+pub fn run(source: &str) -> (Vec<Token>, Vec<LexError>) {
+    let mut tokens = Vec::new();
+    let mut errors = Vec::new();
+    for item in Lexer::from_preprocessed(source) {
+        match item {
+            Ok(token) => tokens.push(token),
+            Err(e) => errors.push(e),
+        }
     }
-    print_err_info(err_info);
-    tokens
+    (tokens, errors)
 }
 
-pub fn is_hex_format(c: char, nc: Option<char>, state: State) -> bool {
-    match state {
-        State::Handling(WordType::Literal(LiteralType::Integer(BinaryType::Hex))) => {
-            let next_c = nc.unwrap_or(' ');
-            c == '0' && next_c == 'x'
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::helper::intern;
+
+    // 把结果分成 token 和诊断
+    fn lex(source: &str) -> (Vec<TokenKind>, Vec<LexError>) {
+        let mut tokens = Vec::new();
+        let mut errors = Vec::new();
+        for item in Lexer::new(source) {
+            match item {
+                Ok(tok) => tokens.push(tok.kind),
+                Err(e) => errors.push(e),
+            }
+        }
+        (tokens, errors)
+    }
+
+    // 一次只交出一个字节的输入，token 会跨过每一次读取的边界
+    struct Trickle<'a>(&'a [u8]);
+
+    impl Read for Trickle<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            let Some((first, rest)) = self.0.split_first() else {
+                return Ok(0);
+            };
+            buf[0] = *first;
+            self.0 = rest;
+            Ok(1)
+        }
+    }
+
+    // 读到一半出错
+    struct Broken;
+
+    impl Read for Broken {
+        fn read(&mut self, _: &mut [u8]) -> std::io::Result<usize> {
+            Err(std::io::Error::other("disk on fire"))
         }
-        _ => false,
+    }
+
+    fn kinds(lexer: Lexer) -> Vec<TokenKind> {
+        lexer.map(|item| item.unwrap().kind).collect()
+    }
+
+    #[test]
+    fn constructors_agree() {
+        let source = "int x = 0x1f; // comment\nx >>= 2;";
+        let expected = kinds(Lexer::new(source));
+        assert_eq!(expected.len(), 9);
+        assert_eq!(expected[1], TokenKind::Ident(intern("x")));
+        assert_eq!(expected[3], TokenKind::IntLit(31, NumType::Int));
+        assert_eq!(kinds(Lexer::from_bytes(source.as_bytes())), expected);
+        assert_eq!(kinds(Lexer::from_reader(source.as_bytes())), expected);
+        assert_eq!(
+            kinds(Lexer::from_reader(Trickle(source.as_bytes()))),
+            expected
+        );
+    }
+
+    #[test]
+    fn spans_count_lines_and_bytes() {
+        let tokens: Vec<Token> = Lexer::new("a\n  bc").map(Result::unwrap).collect();
+        assert_eq!((tokens[1].span.line, tokens[1].span.col), (2, 3));
+        assert_eq!((tokens[1].span.lo, tokens[1].span.hi), (4, 6));
+    }
+
+    #[test]
+    fn long_streams_keep_their_spans() {
+        // 长度超过一次读入的字符串，前后都是要丢掉的输入
+        let long = "x".repeat(10000);
+        let source = format!("{}\"{}\"\n{}", "a ".repeat(5000), long, "b ".repeat(5000));
+        let tokens: Vec<Token> = Lexer::from_reader(Trickle(source.as_bytes()))
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(tokens.len(), 10001);
+        assert_eq!(tokens[5000].kind, TokenKind::StrLit(long.into_bytes()));
+        assert_eq!((tokens[5000].span.lo, tokens[5000].span.hi), (10000, 20002));
+        let last = &tokens[10000].span;
+        assert_eq!((last.line, last.col, last.lo), (2, 9999, 30001));
+    }
+
+    #[test]
+    fn read_errors_are_reported() {
+        let items: Vec<_> = Lexer::from_reader(Broken).collect();
+        assert_eq!(items.len(), 1);
+        let error = items[0].as_ref().unwrap_err();
+        assert_eq!(error.kind, LexErrorKind::Io);
+        assert!(error.message.contains("disk on fire"));
+    }
+
+    #[test]
+    fn adjacent_strings_are_concatenated() {
+        let tokens: Vec<Token> = Lexer::from_reader(Trickle(b"\"ab\" /* x */ \"c\\n\"\n\"d\";"))
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(tokens.len(), 2);
+        assert_eq!(tokens[0].kind, TokenKind::StrLit(b"abc\nd".to_vec()));
+        // 拼接后的 span 覆盖所有片段
+        assert_eq!((tokens[0].span.lo, tokens[0].span.hi), (0, 22));
+        assert_eq!(tokens[1].kind, TokenKind::Semi);
+    }
+
+    #[test]
+    fn recovers_after_errors() {
+        let (tokens, errors) = lex("a @ b \"open\nc 'x");
+        let kinds: Vec<LexErrorKind> = errors.iter().map(|e| e.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                LexErrorKind::StrayChar,
+                LexErrorKind::UnterminatedString,
+                LexErrorKind::UnterminatedChar,
+            ]
+        );
+        assert_eq!(errors[0].span.col, 3);
+        // 出错的地方跳过或者给出恢复后的 token，后面照常分析
+        assert_eq!(
+            tokens,
+            vec![
+                TokenKind::Ident(intern("a")),
+                TokenKind::Ident(intern("b")),
+                TokenKind::StrLit(b"open".to_vec()),
+                TokenKind::Ident(intern("c")),
+                TokenKind::CharLit('x' as i64),
+            ]
+        );
+    }
+
+    #[test]
+    fn punctuators_take_the_longest_match() {
+        use TokenKind::*;
+        let (tokens, errors) = lex("a+++b ->... <<= >>= %= <: :> <% %> %: %:%: ## .");
+        assert!(errors.is_empty(), "{:?}", errors);
+        let a = Ident(intern("a"));
+        let b = Ident(intern("b"));
+        // 双字符替代写法和原来的写法是同一个 token
+        assert_eq!(
+            tokens,
+            vec![
+                a,
+                PlusPlus,
+                Plus,
+                b,
+                Arrow,
+                Ellipsis,
+                ShlAssign,
+                ShrAssign,
+                PercentAssign,
+                LBracket,
+                RBracket,
+                LBrace,
+                RBrace,
+                Hash,
+                HashHash,
+                HashHash,
+                Dot,
+            ]
+        );
+    }
+
+    #[test]
+    fn char_constants_take_char_values() {
+        let (tokens, errors) = lex(r"'\n' '\x41' '\377' '\0'");
+        assert!(errors.is_empty(), "{:?}", errors);
+        // char 是有符号的，'\377' 和读出来的 char 一样是 -1
+        assert_eq!(
+            tokens,
+            vec![
+                TokenKind::CharLit(10),
+                TokenKind::CharLit(65),
+                TokenKind::CharLit(-1),
+                TokenKind::CharLit(0),
+            ]
+        );
+    }
+
+    #[test]
+    fn literal_warnings_are_diagnostics() {
+        let (tokens, errors) = lex("\"a\\qb\" 'ab'");
+        assert_eq!(
+            tokens,
+            vec![
+                TokenKind::StrLit(b"aqb".to_vec()),
+                TokenKind::CharLit(0x6162)
+            ]
+        );
+        let kinds: Vec<LexErrorKind> = errors.iter().map(|e| e.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![LexErrorKind::UnknownEscape, LexErrorKind::MultiCharConstant]
+        );
+        assert!(errors.iter().all(LexError::is_warning));
+        assert_eq!(errors[1].span.col, 8);
+        assert!(
+            errors[0]
+                .to_string()
+                .contains("warning: unknown escape sequence '\\q'")
+        );
+    }
+
+    #[test]
+    fn integer_types_follow_c11() {
+        let (tokens, errors) =
+            lex("2147483647 2147483648 0x80000000 0b101 017 10u 5ll 0xffffffffffULL");
+        assert!(errors.is_empty());
+        assert_eq!(
+            tokens,
+            vec![
+                TokenKind::IntLit(2147483647, NumType::Int),
+                TokenKind::IntLit(2147483648, NumType::LongLong),
+                TokenKind::IntLit(0x80000000, NumType::UInt),
+                TokenKind::IntLit(5, NumType::Int),
+                TokenKind::IntLit(15, NumType::Int),
+                TokenKind::IntLit(10, NumType::UInt),
+                TokenKind::IntLit(5, NumType::LongLong),
+                TokenKind::IntLit(0xffffffffff, NumType::ULongLong),
+            ]
+        );
+    }
+
+    #[test]
+    fn floats_take_exponents_and_suffixes() {
+        let (tokens, errors) = lex("1.5 2e3f 0x1p-2 .5L");
+        assert!(errors.is_empty());
+        assert_eq!(
+            tokens,
+            vec![
+                TokenKind::FloatLit(1.5, NumType::Double),
+                TokenKind::FloatLit(2000.0, NumType::Float),
+                TokenKind::FloatLit(0.25, NumType::Double),
+                TokenKind::FloatLit(0.5, NumType::LongDouble),
+            ]
+        );
+    }
+
+    #[test]
+    fn bad_numbers_are_diagnosed() {
+        let (tokens, errors) = lex("089 0b102 1lL 0x 99999999999999999999 1e");
+        // 出错的常量当作 0
+        assert_eq!(tokens.len(), 6);
+        let kinds: Vec<LexErrorKind> = errors.iter().map(|e| e.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                LexErrorKind::BadDigit,
+                LexErrorKind::BadDigit,
+                LexErrorKind::InvalidSuffix,
+                LexErrorKind::InvalidSuffix,
+                LexErrorKind::OutOfRange,
+                LexErrorKind::MalformedNumber,
+            ]
+        );
+        assert_eq!(
+            errors[0].suggestion.as_deref(),
+            Some("a leading 0 makes the constant octal; write 89 for a decimal value")
+        );
+    }
+
+    #[test]
+    fn huge_decimal_is_unsigned_with_warning() {
+        let (tokens, errors) = lex("18446744073709551615");
+        assert_eq!(
+            tokens,
+            vec![TokenKind::IntLit(u64::MAX, NumType::ULongLong)]
+        );
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].kind, LexErrorKind::LargeUnsigned);
+        assert!(errors[0].is_warning());
     }
 }
@@ -1,9 +1,15 @@
+// 构建时生成的 DFA 表
+pub mod dfa;
 // 文件处理模块
 pub mod file;
 // helper functions
 pub mod helper;
 // 模块入口
+#[allow(clippy::module_inception)]
 pub mod lexer;
+// 词法规则
+pub mod spec;
+// 词法错误
+pub mod types;
 
 pub const NEWLINE: u8 = 0xa;
-pub const SPACE: u8 = 0x20;
@@ -0,0 +1,40 @@
+// 词法规则的声明，build.rs 据此生成最小化的 DFA 表（见 build/dfa.rs）
+// 匹配时取最长的一个，长度相同时取排在前面的规则
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rule {
+    Whitespace,
+    LineComment,
+    BlockComment,
+    // 没有闭合的 /* ，一直延伸到文件结束
+    UnterminatedComment,
+    // 关键字也按标识符匹配，之后再查关键字表
+    Ident,
+    // 预处理数，具体的数值文法由 parse_number 检查
+    Number,
+    Char,
+    UnterminatedChar,
+    Str,
+    UnterminatedStr,
+    Punct,
+}
+
+// 支持的写法：字面字节、\ 转义、[...] 和 [^...] 字符类、.（除换行外任意字节）、
+// 分组 ()、选择 |、重复 * + ?
+// 字符类取补集时包括 0x80 以上的字节，所以注释和字符串里可以出现 UTF-8
+pub const RULES: &[(Rule, &str)] = &[
+    (Rule::Whitespace, r"[ \t\r\n\v\f]+"),
+    (Rule::LineComment, r"//[^\n]*"),
+    (Rule::BlockComment, r"/\*([^*]|\*+[^*/])*\*+/"),
+    (Rule::UnterminatedComment, r"/\*([^*]|\*+[^*/])*\**"),
+    (Rule::Ident, r"[A-Za-z_$][A-Za-z0-9_$]*"),
+    (Rule::Number, r"\.?[0-9]([0-9A-Za-z_.]|[eEpP][+\-])*"),
+    (Rule::Char, r"'([^'\\\n]|\\[^\n])*'"),
+    (Rule::UnterminatedChar, r"'([^'\\\n]|\\[^\n])*\\?"),
+    (Rule::Str, r#""([^"\\\n]|\\[^\n])*""#),
+    (Rule::UnterminatedStr, r#""([^"\\\n]|\\[^\n])*\\?"#),
+    (
+        Rule::Punct,
+        r"\[|\]|\(|\)|\{|\}|\.|->|\+\+|--|&|\*|\+|-|~|!|/|%|<<|>>|<|>|<=|>=|==|!=|\^|\||&&|\|\||\?|:|;|\.\.\.|=|\*=|/=|%=|\+=|-=|<<=|>>=|&=|\^=|\|=|,|#|##|<:|:>|<%|%>|%:|%:%:",
+    ),
+];
@@ -0,0 +1,77 @@
+use crate::utils::types::Span;
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LexErrorKind {
+    UnterminatedString,
+    UnterminatedChar,
+    UnterminatedComment,
+    EmptyChar,
+    // 转义序列不完整或超出范围
+    InvalidEscape,
+    // 八进制/二进制常量里出现了不属于该进制的数字
+    BadDigit,
+    InvalidSuffix,
+    // 指数没有数字、十六进制浮点数缺少指数等
+    MalformedNumber,
+    // 常量超出类型的表示范围
+    OutOfRange,
+    // 注释和字面量之外出现的非法字符，包括非 ASCII 字符和不合法的 UTF-8
+    StrayChar,
+    // 读输入失败
+    Io,
+    // 以下几种和 gcc 一样只是警告，不影响编译
+    UnknownEscape,
+    MultiCharConstant,
+    // 十进制常量放不下 long long，当作无符号数
+    LargeUnsigned,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LexError {
+    pub kind: LexErrorKind,
+    pub span: Span,
+    pub message: String,
+    // 给用户的修改建议，打印成 note
+    pub suggestion: Option<String>,
+}
+
+impl LexError {
+    pub fn new(kind: LexErrorKind, span: Span, message: String) -> LexError {
+        LexError {
+            kind,
+            span,
+            message,
+            suggestion: None,
+        }
+    }
+
+    pub fn with_suggestion(mut self, suggestion: impl Into<String>) -> LexError {
+        self.suggestion = Some(suggestion.into());
+        self
+    }
+
+    pub fn is_warning(&self) -> bool {
+        matches!(
+            self.kind,
+            LexErrorKind::UnknownEscape
+                | LexErrorKind::MultiCharConstant
+                | LexErrorKind::LargeUnsigned
+        )
+    }
+}
+
+impl fmt::Display for LexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let level = if self.is_warning() {
+            "warning"
+        } else {
+            "error"
+        };
+        write!(f, "{}: {}: {}", self.span, level, self.message)?;
+        if let Some(suggestion) = &self.suggestion {
+            write!(f, "\n{}: note: {}", self.span, suggestion)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,9 @@
+// 编译器的各个阶段，main.rs 是命令行驱动
+pub mod asm;
+pub mod ast;
+pub mod ir;
+// 词法分析器模块
+pub mod lexer;
+pub mod parser;
+pub mod preprocessor;
+pub mod utils;
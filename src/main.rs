@@ -1,38 +1,91 @@
 use std::env;
+use std::process::exit;
 
-// 词法分析器模块
-mod asm;
-mod ast;
-mod ir;
-mod lexer;
-mod parser;
-mod utils;
-use asm::write_asm::write_asm;
-use ast::astgen::ast_gen;
-use ir::irgen::ir_gen;
-use parser::parse::parse;
-use utils::helper::print_ast;
-use utils::helper::print_cst;
-use utils::helper::print_ir;
-use utils::helper::print_tokens;
-
-// mod utils;
+use quickcc::asm::write_asm::write_asm;
+use quickcc::ast::astgen::ast_gen;
+use quickcc::ast::helper::{dump_record_layouts, take_semantic_errors};
+use quickcc::ir::irgen::ir_gen;
+use quickcc::lexer;
+use quickcc::parser::parse::parse;
+use quickcc::preprocessor::preprocess::preprocess;
+use quickcc::utils::helper::print_ast;
+use quickcc::utils::helper::print_ir;
+use quickcc::utils::session::Session;
 
 fn main() {
     let args: Vec<String> = env::args().collect();
-    if args.len() < 2 {
-        panic!("Please input the filename!");
+    let mut filename: Option<String> = None;
+    let mut include_dirs: Vec<String> = Vec::new();
+    let mut defines: Vec<String> = Vec::new();
+    let mut only_preprocess = false;
+    let mut dump_layouts = false;
+    let mut iter = args.iter().skip(1);
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "-E" => only_preprocess = true,
+            "-fdump-record-layouts" => dump_layouts = true,
+            "-I" => include_dirs.push(iter.next().expect("-I expects a directory").clone()),
+            "-D" => defines.push(iter.next().expect("-D expects a macro name").clone()),
+            _ if arg.starts_with("-I") => include_dirs.push(arg[2..].to_string()),
+            _ if arg.starts_with("-D") => defines.push(arg[2..].to_string()),
+            _ => filename = Some(arg.clone()),
+        }
     }
-    let filename = match args.get(1) {
+    let filename = match filename {
         Some(name) => name,
-        None => panic!("Get filename error!"),
+        None => panic!("Please input the filename!"),
     };
 
-    let lexer_tokens = lexer::lexer::run(&filename[..]);
+    // 读入的源文件登记在会话里，编译结束时一起释放
+    let _session = Session::new();
+    let source = match preprocess(&filename, &include_dirs, &defines) {
+        Ok(source) => source,
+        Err(errors) => {
+            for err in errors {
+                eprintln!("{}", err);
+            }
+            exit(1);
+        }
+    };
+    if only_preprocess {
+        print!("{}", source.text);
+        return;
+    }
+    let (lexer_tokens, lex_errors) = lexer::lexer::run(&source.text);
     // print_tokens(&lexer_tokens);
-    let cst = parse(&lexer_tokens);
+    // 词法错误的 token 已经做过恢复，继续做语法分析，一次报告尽量多的错误
+    let (cst, syntax_errors) = parse(&lexer_tokens, source.eof);
+    for err in &lex_errors {
+        eprintln!("{}", err);
+    }
+    for err in &syntax_errors {
+        eprintln!("{}", err);
+    }
+    // 警告照常打印，但不算错误
+    let lex_error_count = lex_errors.iter().filter(|e| !e.is_warning()).count();
+    if lex_error_count > 0 || !syntax_errors.is_empty() {
+        eprintln!(
+            "{} lexical error(s), {} syntax error(s)",
+            lex_error_count,
+            syntax_errors.len()
+        );
+        exit(1);
+    }
     // print_cst(&cst);
     let ast = ast_gen(&cst);
+    let semantic_errors = take_semantic_errors();
+    for err in &semantic_errors {
+        eprintln!("{}", err);
+    }
+    let semantic_error_count = semantic_errors.iter().filter(|e| !e.warning).count();
+    if semantic_error_count > 0 {
+        eprintln!("{} semantic error(s)", semantic_error_count);
+        exit(1);
+    }
+    // 和 clang 一样打印每个结构体的布局，用来和寄存器手册对照
+    if dump_layouts {
+        print!("{}", dump_record_layouts());
+    }
     print_ast(&ast);
     let ir = ir_gen(&ast);
     print_ir(&ir);
@@ -1,323 +1,5 @@
+// LALR(1) 分析表由 build.rs 根据 grammar.rs 生成
+use super::grammar::Prod;
 use super::types::{Action, State};
 
-const NTOK: usize = 33;
-const NNONTERM: usize = 41;
-const NSTATE: usize = 156;
-
-pub const ACTION: [[Action; NTOK]; NSTATE] = [
-    /* State 0 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S6), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S7), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 1 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S6), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S7), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(19) ],
-    /* State 2 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(31) ],
-    /* State 3 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Accept ],
-    /* State 4 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S12), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S13), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 5 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(33), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(33), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 6 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(32), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(32), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 7 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S14), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S15), Action::Error, Action::Error, Action::Error ],
-    /* State 8 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(18) ],
-    /* State 9 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S16), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 10 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S18), Action::Error, Action::Error, Action::Error ],
-    /* State 11 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S19), Action::Error, Action::Error, Action::Reduce(13), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S20), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 12 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(16), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(16), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(16) ],
-    /* State 13 */ [ Action::Error, Action::Error, Action::Error, Action::Shift(State::S21), Action::Error, Action::Error, Action::Error, Action::Reduce(44), Action::Error, Action::Error, Action::Reduce(44), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(44), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 14 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(40), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(40), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S22), Action::Error, Action::Error, Action::Error ],
-    /* State 15 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S6), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S7), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 16 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(15), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(15), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(15) ],
-    /* State 17 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(17), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(17), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(17) ],
-    /* State 18 */ [ Action::Shift(State::S44), Action::Error, Action::Error, Action::Shift(State::S45), Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S46), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S6), Action::Error, Action::Error, Action::Error, Action::Shift(State::S47), Action::Shift(State::S48), Action::Shift(State::S49), Action::Shift(State::S50), Action::Shift(State::S51), Action::Shift(State::S7), Action::Shift(State::S52), Action::Shift(State::S53), Action::Shift(State::S18), Action::Error, Action::Error, Action::Error ],
-    /* State 19 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S55), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 20 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S56), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 21 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S60), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S6), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S7), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 22 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S6), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S7), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 23 */ [ Action::Reduce(12), Action::Error, Action::Error, Action::Reduce(12), Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(12), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S6), Action::Error, Action::Error, Action::Error, Action::Reduce(12), Action::Reduce(12), Action::Reduce(12), Action::Reduce(12), Action::Reduce(12), Action::Shift(State::S7), Action::Reduce(12), Action::Reduce(12), Action::Reduce(12), Action::Error, Action::Reduce(12), Action::Error ],
-    /* State 24 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S63), Action::Error ],
-    /* State 25 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S55), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 26 */ [ Action::Reduce(28), Action::Error, Action::Error, Action::Reduce(28), Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(28), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(28), Action::Reduce(28), Action::Reduce(28), Action::Reduce(28), Action::Reduce(28), Action::Reduce(28), Action::Error, Action::Reduce(28), Action::Reduce(28), Action::Reduce(28), Action::Error, Action::Reduce(28), Action::Error ],
-    /* State 27 */ [ Action::Shift(State::S44), Action::Error, Action::Error, Action::Shift(State::S45), Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S46), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S47), Action::Shift(State::S48), Action::Shift(State::S49), Action::Shift(State::S50), Action::Shift(State::S51), Action::Error, Action::Shift(State::S52), Action::Shift(State::S53), Action::Shift(State::S18), Action::Error, Action::Error, Action::Error ],
-    /* State 28 */ [ Action::Error, Action::Reduce(88), Action::Reduce(88), Action::Error, Action::Reduce(88), Action::Reduce(88), Action::Reduce(88), Action::Reduce(88), Action::Reduce(88), Action::Reduce(88), Action::Reduce(88), Action::Reduce(88), Action::Reduce(88), Action::Reduce(88), Action::Reduce(88), Action::Reduce(88), Action::Reduce(88), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(88), Action::Error, Action::Error ],
-    /* State 29 */ [ Action::Reduce(34), Action::Error, Action::Error, Action::Reduce(34), Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(34), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(34), Action::Reduce(34), Action::Reduce(34), Action::Reduce(34), Action::Reduce(34), Action::Error, Action::Reduce(34), Action::Reduce(34), Action::Reduce(34), Action::Error, Action::Reduce(34), Action::Error ],
-    /* State 30 */ [ Action::Reduce(24), Action::Error, Action::Error, Action::Reduce(24), Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(24), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(24), Action::Reduce(24), Action::Reduce(24), Action::Reduce(24), Action::Reduce(24), Action::Reduce(24), Action::Error, Action::Reduce(24), Action::Reduce(24), Action::Reduce(24), Action::Error, Action::Reduce(24), Action::Error ],
-    /* State 31 */ [ Action::Shift(State::S44), Action::Error, Action::Error, Action::Shift(State::S45), Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S46), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S47), Action::Shift(State::S48), Action::Shift(State::S49), Action::Shift(State::S50), Action::Shift(State::S51), Action::Error, Action::Shift(State::S52), Action::Shift(State::S53), Action::Shift(State::S18), Action::Error, Action::Reduce(37), Action::Error ],
-    /* State 32 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S69), Action::Error ],
-    /* State 33 */ [ Action::Reduce(35), Action::Error, Action::Error, Action::Reduce(35), Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(35), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(35), Action::Reduce(35), Action::Reduce(35), Action::Reduce(35), Action::Reduce(35), Action::Error, Action::Reduce(35), Action::Reduce(35), Action::Reduce(35), Action::Error, Action::Reduce(35), Action::Error ],
-    /* State 34 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(68), Action::Error, Action::Error, Action::Reduce(68), Action::Error, Action::Error, Action::Reduce(68), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 35 */ [ Action::Error, Action::Shift(State::S71), Action::Reduce(63), Action::Error, Action::Reduce(63), Action::Error, Action::Error, Action::Reduce(63), Action::Error, Action::Error, Action::Reduce(63), Action::Error, Action::Error, Action::Reduce(63), Action::Shift(State::S72), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(63), Action::Error, Action::Error ],
-    /* State 36 */ [ Action::Error, Action::Error, Action::Shift(State::S74), Action::Error, Action::Reduce(76), Action::Error, Action::Error, Action::Reduce(76), Action::Error, Action::Error, Action::Reduce(76), Action::Error, Action::Error, Action::Reduce(76), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(76), Action::Error, Action::Error ],
-    /* State 37 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S75), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 38 */ [ Action::Error, Action::Reduce(90), Action::Reduce(90), Action::Error, Action::Reduce(90), Action::Error, Action::Shift(State::S77), Action::Reduce(90), Action::Shift(State::S78), Action::Error, Action::Reduce(90), Action::Reduce(90), Action::Reduce(90), Action::Reduce(90), Action::Reduce(90), Action::Reduce(90), Action::Reduce(90), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(90), Action::Error, Action::Error ],
-    /* State 39 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(80), Action::Error, Action::Error, Action::Reduce(80), Action::Error, Action::Error, Action::Reduce(80), Action::Error, Action::Error, Action::Reduce(80), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S80), Action::Error, Action::Error ],
-    /* State 40 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(48), Action::Error, Action::Error, Action::Reduce(48), Action::Error, Action::Error, Action::Reduce(48), Action::Error, Action::Error, Action::Shift(State::S82), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 41 */ [ Action::Error, Action::Reduce(96), Action::Reduce(96), Action::Error, Action::Reduce(96), Action::Reduce(96), Action::Reduce(96), Action::Reduce(96), Action::Reduce(96), Action::Reduce(96), Action::Reduce(96), Action::Reduce(96), Action::Reduce(96), Action::Reduce(96), Action::Reduce(96), Action::Reduce(96), Action::Reduce(96), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(96), Action::Error, Action::Error ],
-    /* State 42 */ [ Action::Error, Action::Reduce(53), Action::Reduce(53), Action::Error, Action::Reduce(53), Action::Error, Action::Error, Action::Reduce(53), Action::Error, Action::Error, Action::Reduce(53), Action::Shift(State::S84), Action::Shift(State::S85), Action::Reduce(53), Action::Reduce(53), Action::Shift(State::S86), Action::Shift(State::S87), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(53), Action::Error, Action::Error ],
-    /* State 43 */ [ Action::Error, Action::Reduce(70), Action::Reduce(70), Action::Error, Action::Reduce(70), Action::Shift(State::S89), Action::Reduce(70), Action::Reduce(70), Action::Reduce(70), Action::Shift(State::S90), Action::Reduce(70), Action::Reduce(70), Action::Reduce(70), Action::Reduce(70), Action::Reduce(70), Action::Reduce(70), Action::Reduce(70), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(70), Action::Error, Action::Error ],
-    /* State 44 */ [ Action::Shift(State::S44), Action::Error, Action::Error, Action::Shift(State::S45), Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S46), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S47), Action::Shift(State::S48), Action::Error, Action::Shift(State::S50), Action::Error, Action::Error, Action::Shift(State::S52), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 45 */ [ Action::Shift(State::S44), Action::Error, Action::Error, Action::Shift(State::S45), Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S46), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S47), Action::Shift(State::S48), Action::Error, Action::Shift(State::S50), Action::Error, Action::Error, Action::Shift(State::S52), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 46 */ [ Action::Shift(State::S44), Action::Error, Action::Error, Action::Shift(State::S45), Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S46), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S47), Action::Shift(State::S48), Action::Error, Action::Shift(State::S50), Action::Error, Action::Error, Action::Shift(State::S52), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 47 */ [ Action::Error, Action::Reduce(86), Action::Reduce(86), Action::Error, Action::Reduce(86), Action::Reduce(86), Action::Reduce(86), Action::Reduce(86), Action::Reduce(86), Action::Reduce(86), Action::Reduce(86), Action::Reduce(86), Action::Reduce(86), Action::Reduce(86), Action::Reduce(86), Action::Reduce(86), Action::Reduce(86), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(86), Action::Error, Action::Error ],
-    /* State 48 */ [ Action::Error, Action::Reduce(84), Action::Reduce(84), Action::Shift(State::S94), Action::Reduce(84), Action::Reduce(84), Action::Reduce(84), Action::Reduce(84), Action::Reduce(84), Action::Reduce(84), Action::Reduce(84), Action::Reduce(84), Action::Reduce(84), Action::Reduce(84), Action::Reduce(84), Action::Reduce(84), Action::Reduce(84), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(84), Action::Error, Action::Error ],
-    /* State 49 */ [ Action::Error, Action::Error, Action::Error, Action::Shift(State::S95), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 50 */ [ Action::Error, Action::Reduce(83), Action::Reduce(83), Action::Error, Action::Reduce(83), Action::Reduce(83), Action::Reduce(83), Action::Reduce(83), Action::Reduce(83), Action::Reduce(83), Action::Reduce(83), Action::Reduce(83), Action::Reduce(83), Action::Reduce(83), Action::Reduce(83), Action::Reduce(83), Action::Reduce(83), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(83), Action::Error, Action::Error ],
-    /* State 51 */ [ Action::Shift(State::S44), Action::Error, Action::Error, Action::Shift(State::S45), Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S46), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S47), Action::Shift(State::S48), Action::Error, Action::Shift(State::S50), Action::Error, Action::Error, Action::Shift(State::S52), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 52 */ [ Action::Error, Action::Reduce(85), Action::Reduce(85), Action::Error, Action::Reduce(85), Action::Reduce(85), Action::Reduce(85), Action::Reduce(85), Action::Reduce(85), Action::Reduce(85), Action::Reduce(85), Action::Reduce(85), Action::Reduce(85), Action::Reduce(85), Action::Reduce(85), Action::Reduce(85), Action::Reduce(85), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(85), Action::Error, Action::Error ],
-    /* State 53 */ [ Action::Error, Action::Error, Action::Error, Action::Shift(State::S97), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 54 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(14), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 55 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(44), Action::Error, Action::Error, Action::Reduce(44), Action::Error, Action::Error, Action::Reduce(44), Action::Error, Action::Error, Action::Reduce(44), Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(44), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 56 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S98), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 57 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(47), Action::Error, Action::Error, Action::Shift(State::S99), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 58 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S55), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 59 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S101), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 60 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(23), Action::Error, Action::Error, Action::Error ],
-    /* State 61 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S102), Action::Error ],
-    /* State 62 */ [ Action::Reduce(11), Action::Error, Action::Error, Action::Reduce(11), Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(11), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(11), Action::Reduce(11), Action::Reduce(11), Action::Reduce(11), Action::Reduce(11), Action::Error, Action::Reduce(11), Action::Reduce(11), Action::Reduce(11), Action::Error, Action::Reduce(11), Action::Error ],
-    /* State 63 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(39), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(39), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 64 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S103), Action::Error, Action::Error, Action::Reduce(8), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 65 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S104), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 66 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(6), Action::Error, Action::Error, Action::Reduce(6), Action::Error, Action::Error, Action::Shift(State::S105), Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S20), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 67 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S106), Action::Error ],
-    /* State 68 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(36), Action::Error ],
-    /* State 69 */ [ Action::Reduce(5), Action::Error, Action::Error, Action::Reduce(5), Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(5), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(5), Action::Error, Action::Error, Action::Reduce(5), Action::Reduce(5), Action::Reduce(5), Action::Reduce(5), Action::Reduce(5), Action::Reduce(5), Action::Reduce(5), Action::Reduce(5), Action::Reduce(5), Action::Reduce(5), Action::Error, Action::Reduce(5), Action::Reduce(5) ],
-    /* State 70 */ [ Action::Error, Action::Error, Action::Reduce(62), Action::Error, Action::Reduce(62), Action::Error, Action::Error, Action::Reduce(62), Action::Error, Action::Error, Action::Reduce(62), Action::Error, Action::Error, Action::Reduce(62), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(62), Action::Error, Action::Error ],
-    /* State 71 */ [ Action::Shift(State::S44), Action::Error, Action::Error, Action::Shift(State::S45), Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S46), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S47), Action::Shift(State::S48), Action::Error, Action::Shift(State::S50), Action::Error, Action::Error, Action::Shift(State::S52), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 72 */ [ Action::Shift(State::S44), Action::Error, Action::Error, Action::Shift(State::S45), Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S46), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S47), Action::Shift(State::S48), Action::Error, Action::Shift(State::S50), Action::Error, Action::Error, Action::Shift(State::S52), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 73 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(75), Action::Error, Action::Error, Action::Reduce(75), Action::Error, Action::Error, Action::Reduce(75), Action::Error, Action::Error, Action::Reduce(75), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(75), Action::Error, Action::Error ],
-    /* State 74 */ [ Action::Shift(State::S44), Action::Error, Action::Error, Action::Shift(State::S45), Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S46), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S47), Action::Shift(State::S48), Action::Error, Action::Shift(State::S50), Action::Error, Action::Error, Action::Shift(State::S52), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 75 */ [ Action::Reduce(27), Action::Error, Action::Error, Action::Reduce(27), Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(27), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(27), Action::Reduce(27), Action::Reduce(27), Action::Reduce(27), Action::Reduce(27), Action::Reduce(27), Action::Error, Action::Reduce(27), Action::Reduce(27), Action::Reduce(27), Action::Error, Action::Reduce(27), Action::Error ],
-    /* State 76 */ [ Action::Error, Action::Reduce(89), Action::Reduce(89), Action::Error, Action::Reduce(89), Action::Error, Action::Error, Action::Reduce(89), Action::Error, Action::Error, Action::Reduce(89), Action::Reduce(89), Action::Reduce(89), Action::Reduce(89), Action::Reduce(89), Action::Reduce(89), Action::Reduce(89), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(89), Action::Error, Action::Error ],
-    /* State 77 */ [ Action::Shift(State::S44), Action::Error, Action::Error, Action::Shift(State::S45), Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S46), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S47), Action::Shift(State::S48), Action::Error, Action::Shift(State::S50), Action::Error, Action::Error, Action::Shift(State::S52), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 78 */ [ Action::Shift(State::S44), Action::Error, Action::Error, Action::Shift(State::S45), Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S46), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S47), Action::Shift(State::S48), Action::Error, Action::Shift(State::S50), Action::Error, Action::Error, Action::Shift(State::S52), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 79 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(79), Action::Error, Action::Error, Action::Reduce(79), Action::Error, Action::Error, Action::Reduce(79), Action::Error, Action::Error, Action::Reduce(79), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 80 */ [ Action::Shift(State::S44), Action::Error, Action::Error, Action::Shift(State::S45), Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S46), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S47), Action::Shift(State::S48), Action::Error, Action::Shift(State::S50), Action::Error, Action::Error, Action::Shift(State::S52), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 81 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(49), Action::Error, Action::Error, Action::Reduce(49), Action::Error, Action::Error, Action::Reduce(49), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 82 */ [ Action::Shift(State::S44), Action::Error, Action::Error, Action::Shift(State::S45), Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S46), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S47), Action::Shift(State::S48), Action::Error, Action::Shift(State::S50), Action::Error, Action::Error, Action::Shift(State::S52), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 83 */ [ Action::Error, Action::Reduce(52), Action::Reduce(52), Action::Error, Action::Reduce(52), Action::Error, Action::Error, Action::Reduce(52), Action::Error, Action::Error, Action::Reduce(52), Action::Error, Action::Error, Action::Reduce(52), Action::Reduce(52), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(52), Action::Error, Action::Error ],
-    /* State 84 */ [ Action::Shift(State::S44), Action::Error, Action::Error, Action::Shift(State::S45), Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S46), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S47), Action::Shift(State::S48), Action::Error, Action::Shift(State::S50), Action::Error, Action::Error, Action::Shift(State::S52), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 85 */ [ Action::Shift(State::S44), Action::Error, Action::Error, Action::Shift(State::S45), Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S46), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S47), Action::Shift(State::S48), Action::Error, Action::Shift(State::S50), Action::Error, Action::Error, Action::Shift(State::S52), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 86 */ [ Action::Shift(State::S44), Action::Error, Action::Error, Action::Shift(State::S45), Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S46), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S47), Action::Shift(State::S48), Action::Error, Action::Shift(State::S50), Action::Error, Action::Error, Action::Shift(State::S52), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 87 */ [ Action::Shift(State::S44), Action::Error, Action::Error, Action::Shift(State::S45), Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S46), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S47), Action::Shift(State::S48), Action::Error, Action::Shift(State::S50), Action::Error, Action::Error, Action::Shift(State::S52), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 88 */ [ Action::Error, Action::Reduce(69), Action::Reduce(69), Action::Error, Action::Reduce(69), Action::Error, Action::Reduce(69), Action::Reduce(69), Action::Reduce(69), Action::Error, Action::Reduce(69), Action::Reduce(69), Action::Reduce(69), Action::Reduce(69), Action::Reduce(69), Action::Reduce(69), Action::Reduce(69), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(69), Action::Error, Action::Error ],
-    /* State 89 */ [ Action::Shift(State::S44), Action::Error, Action::Error, Action::Shift(State::S45), Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S46), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S47), Action::Shift(State::S48), Action::Error, Action::Shift(State::S50), Action::Error, Action::Error, Action::Shift(State::S52), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 90 */ [ Action::Shift(State::S44), Action::Error, Action::Error, Action::Shift(State::S45), Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S46), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S47), Action::Shift(State::S48), Action::Error, Action::Shift(State::S50), Action::Error, Action::Error, Action::Shift(State::S52), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 91 */ [ Action::Error, Action::Reduce(97), Action::Reduce(97), Action::Error, Action::Reduce(97), Action::Reduce(97), Action::Reduce(97), Action::Reduce(97), Action::Reduce(97), Action::Reduce(97), Action::Reduce(97), Action::Reduce(97), Action::Reduce(97), Action::Reduce(97), Action::Reduce(97), Action::Reduce(97), Action::Reduce(97), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(97), Action::Error, Action::Error ],
-    /* State 92 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S120), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 93 */ [ Action::Error, Action::Reduce(95), Action::Reduce(95), Action::Error, Action::Reduce(95), Action::Reduce(95), Action::Reduce(95), Action::Reduce(95), Action::Reduce(95), Action::Reduce(95), Action::Reduce(95), Action::Reduce(95), Action::Reduce(95), Action::Reduce(95), Action::Reduce(95), Action::Reduce(95), Action::Reduce(95), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(95), Action::Error, Action::Error ],
-    /* State 94 */ [ Action::Shift(State::S44), Action::Error, Action::Error, Action::Shift(State::S45), Action::Shift(State::S123), Action::Error, Action::Error, Action::Error, Action::Shift(State::S46), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S47), Action::Shift(State::S48), Action::Error, Action::Shift(State::S50), Action::Error, Action::Error, Action::Shift(State::S52), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 95 */ [ Action::Shift(State::S44), Action::Error, Action::Error, Action::Shift(State::S45), Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S46), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S47), Action::Shift(State::S48), Action::Error, Action::Shift(State::S50), Action::Error, Action::Error, Action::Shift(State::S52), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 96 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S125), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 97 */ [ Action::Shift(State::S44), Action::Error, Action::Error, Action::Shift(State::S45), Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S46), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S47), Action::Shift(State::S48), Action::Error, Action::Shift(State::S50), Action::Error, Action::Error, Action::Shift(State::S52), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 98 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(45), Action::Error, Action::Error, Action::Reduce(45), Action::Error, Action::Error, Action::Reduce(45), Action::Error, Action::Error, Action::Reduce(45), Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(45), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 99 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S6), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S7), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 100 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(30), Action::Error, Action::Error, Action::Reduce(30), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S20), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 101 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(22), Action::Error, Action::Error, Action::Error ],
-    /* State 102 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(38), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(38), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 103 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S55), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 104 */ [ Action::Reduce(10), Action::Error, Action::Error, Action::Reduce(10), Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(10), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(10), Action::Error, Action::Error, Action::Error, Action::Reduce(10), Action::Reduce(10), Action::Reduce(10), Action::Reduce(10), Action::Reduce(10), Action::Reduce(10), Action::Reduce(10), Action::Reduce(10), Action::Reduce(10), Action::Error, Action::Reduce(10), Action::Error ],
-    /* State 105 */ [ Action::Shift(State::S44), Action::Error, Action::Error, Action::Shift(State::S45), Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S46), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S47), Action::Shift(State::S48), Action::Error, Action::Shift(State::S50), Action::Error, Action::Error, Action::Shift(State::S52), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 106 */ [ Action::Reduce(4), Action::Error, Action::Error, Action::Reduce(4), Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(4), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(4), Action::Error, Action::Error, Action::Reduce(4), Action::Reduce(4), Action::Reduce(4), Action::Reduce(4), Action::Reduce(4), Action::Reduce(4), Action::Reduce(4), Action::Reduce(4), Action::Reduce(4), Action::Reduce(4), Action::Error, Action::Reduce(4), Action::Reduce(4) ],
-    /* State 107 */ [ Action::Error, Action::Shift(State::S71), Action::Reduce(66), Action::Error, Action::Reduce(66), Action::Error, Action::Error, Action::Reduce(66), Action::Error, Action::Error, Action::Reduce(66), Action::Error, Action::Error, Action::Reduce(66), Action::Shift(State::S72), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(66), Action::Error, Action::Error ],
-    /* State 108 */ [ Action::Error, Action::Shift(State::S71), Action::Reduce(67), Action::Error, Action::Reduce(67), Action::Error, Action::Error, Action::Reduce(67), Action::Error, Action::Error, Action::Reduce(67), Action::Error, Action::Error, Action::Reduce(67), Action::Shift(State::S72), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(67), Action::Error, Action::Error ],
-    /* State 109 */ [ Action::Error, Action::Error, Action::Shift(State::S74), Action::Error, Action::Reduce(78), Action::Error, Action::Error, Action::Reduce(78), Action::Error, Action::Error, Action::Reduce(78), Action::Error, Action::Error, Action::Reduce(78), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(78), Action::Error, Action::Error ],
-    /* State 110 */ [ Action::Error, Action::Reduce(94), Action::Reduce(94), Action::Error, Action::Reduce(94), Action::Error, Action::Shift(State::S77), Action::Reduce(94), Action::Shift(State::S78), Action::Error, Action::Reduce(94), Action::Reduce(94), Action::Reduce(94), Action::Reduce(94), Action::Reduce(94), Action::Reduce(94), Action::Reduce(94), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(94), Action::Error, Action::Error ],
-    /* State 111 */ [ Action::Error, Action::Reduce(93), Action::Reduce(93), Action::Error, Action::Reduce(93), Action::Error, Action::Shift(State::S77), Action::Reduce(93), Action::Shift(State::S78), Action::Error, Action::Reduce(93), Action::Reduce(93), Action::Reduce(93), Action::Reduce(93), Action::Reduce(93), Action::Reduce(93), Action::Reduce(93), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(93), Action::Error, Action::Error ],
-    /* State 112 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(82), Action::Error, Action::Error, Action::Reduce(82), Action::Error, Action::Error, Action::Reduce(82), Action::Error, Action::Error, Action::Reduce(82), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S80), Action::Error, Action::Error ],
-    /* State 113 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(51), Action::Error, Action::Error, Action::Reduce(51), Action::Error, Action::Error, Action::Reduce(51), Action::Error, Action::Error, Action::Shift(State::S82), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 114 */ [ Action::Error, Action::Reduce(61), Action::Reduce(61), Action::Error, Action::Reduce(61), Action::Error, Action::Error, Action::Reduce(61), Action::Error, Action::Error, Action::Reduce(61), Action::Shift(State::S84), Action::Shift(State::S85), Action::Reduce(61), Action::Reduce(61), Action::Shift(State::S86), Action::Shift(State::S87), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(61), Action::Error, Action::Error ],
-    /* State 115 */ [ Action::Error, Action::Reduce(59), Action::Reduce(59), Action::Error, Action::Reduce(59), Action::Error, Action::Error, Action::Reduce(59), Action::Error, Action::Error, Action::Reduce(59), Action::Shift(State::S84), Action::Shift(State::S85), Action::Reduce(59), Action::Reduce(59), Action::Shift(State::S86), Action::Shift(State::S87), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(59), Action::Error, Action::Error ],
-    /* State 116 */ [ Action::Error, Action::Reduce(58), Action::Reduce(58), Action::Error, Action::Reduce(58), Action::Error, Action::Error, Action::Reduce(58), Action::Error, Action::Error, Action::Reduce(58), Action::Shift(State::S84), Action::Shift(State::S85), Action::Reduce(58), Action::Reduce(58), Action::Shift(State::S86), Action::Shift(State::S87), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(58), Action::Error, Action::Error ],
-    /* State 117 */ [ Action::Error, Action::Reduce(60), Action::Reduce(60), Action::Error, Action::Reduce(60), Action::Error, Action::Error, Action::Reduce(60), Action::Error, Action::Error, Action::Reduce(60), Action::Shift(State::S84), Action::Shift(State::S85), Action::Reduce(60), Action::Reduce(60), Action::Shift(State::S86), Action::Shift(State::S87), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(60), Action::Error, Action::Error ],
-    /* State 118 */ [ Action::Error, Action::Reduce(73), Action::Reduce(73), Action::Error, Action::Reduce(73), Action::Shift(State::S89), Action::Reduce(73), Action::Reduce(73), Action::Reduce(73), Action::Shift(State::S90), Action::Reduce(73), Action::Reduce(73), Action::Reduce(73), Action::Reduce(73), Action::Reduce(73), Action::Reduce(73), Action::Reduce(73), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(73), Action::Error, Action::Error ],
-    /* State 119 */ [ Action::Error, Action::Reduce(74), Action::Reduce(74), Action::Error, Action::Reduce(74), Action::Shift(State::S89), Action::Reduce(74), Action::Reduce(74), Action::Reduce(74), Action::Shift(State::S90), Action::Reduce(74), Action::Reduce(74), Action::Reduce(74), Action::Reduce(74), Action::Reduce(74), Action::Reduce(74), Action::Reduce(74), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(74), Action::Error, Action::Error ],
-    /* State 120 */ [ Action::Error, Action::Reduce(87), Action::Reduce(87), Action::Error, Action::Reduce(87), Action::Reduce(87), Action::Reduce(87), Action::Reduce(87), Action::Reduce(87), Action::Reduce(87), Action::Reduce(87), Action::Reduce(87), Action::Reduce(87), Action::Reduce(87), Action::Reduce(87), Action::Reduce(87), Action::Reduce(87), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(87), Action::Error, Action::Error ],
-    /* State 121 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S143), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 122 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(2), Action::Error, Action::Error, Action::Shift(State::S145), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 123 */ [ Action::Error, Action::Reduce(21), Action::Reduce(21), Action::Error, Action::Reduce(21), Action::Reduce(21), Action::Reduce(21), Action::Reduce(21), Action::Reduce(21), Action::Reduce(21), Action::Reduce(21), Action::Reduce(21), Action::Reduce(21), Action::Reduce(21), Action::Reduce(21), Action::Reduce(21), Action::Reduce(21), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(21), Action::Error, Action::Error ],
-    /* State 124 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S146), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 125 */ [ Action::Reduce(29), Action::Error, Action::Error, Action::Reduce(29), Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(29), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(29), Action::Reduce(29), Action::Reduce(29), Action::Reduce(29), Action::Reduce(29), Action::Reduce(29), Action::Error, Action::Reduce(29), Action::Reduce(29), Action::Reduce(29), Action::Error, Action::Reduce(29), Action::Error ],
-    /* State 126 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S147), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 127 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(46), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 128 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(9), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 129 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(7), Action::Error, Action::Error, Action::Reduce(7), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 130 */ [ Action::Error, Action::Error, Action::Reduce(64), Action::Error, Action::Reduce(64), Action::Error, Action::Error, Action::Reduce(64), Action::Error, Action::Error, Action::Reduce(64), Action::Error, Action::Error, Action::Reduce(64), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(64), Action::Error, Action::Error ],
-    /* State 131 */ [ Action::Error, Action::Error, Action::Reduce(65), Action::Error, Action::Reduce(65), Action::Error, Action::Error, Action::Reduce(65), Action::Error, Action::Error, Action::Reduce(65), Action::Error, Action::Error, Action::Reduce(65), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(65), Action::Error, Action::Error ],
-    /* State 132 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(77), Action::Error, Action::Error, Action::Reduce(77), Action::Error, Action::Error, Action::Reduce(77), Action::Error, Action::Error, Action::Reduce(77), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(77), Action::Error, Action::Error ],
-    /* State 133 */ [ Action::Error, Action::Reduce(92), Action::Reduce(92), Action::Error, Action::Reduce(92), Action::Error, Action::Error, Action::Reduce(92), Action::Error, Action::Error, Action::Reduce(92), Action::Reduce(92), Action::Reduce(92), Action::Reduce(92), Action::Reduce(92), Action::Reduce(92), Action::Reduce(92), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(92), Action::Error, Action::Error ],
-    /* State 134 */ [ Action::Error, Action::Reduce(91), Action::Reduce(91), Action::Error, Action::Reduce(91), Action::Error, Action::Error, Action::Reduce(91), Action::Error, Action::Error, Action::Reduce(91), Action::Reduce(91), Action::Reduce(91), Action::Reduce(91), Action::Reduce(91), Action::Reduce(91), Action::Reduce(91), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(91), Action::Error, Action::Error ],
-    /* State 135 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(81), Action::Error, Action::Error, Action::Reduce(81), Action::Error, Action::Error, Action::Reduce(81), Action::Error, Action::Error, Action::Reduce(81), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 136 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(50), Action::Error, Action::Error, Action::Reduce(50), Action::Error, Action::Error, Action::Reduce(50), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 137 */ [ Action::Error, Action::Reduce(57), Action::Reduce(57), Action::Error, Action::Reduce(57), Action::Error, Action::Error, Action::Reduce(57), Action::Error, Action::Error, Action::Reduce(57), Action::Error, Action::Error, Action::Reduce(57), Action::Reduce(57), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(57), Action::Error, Action::Error ],
-    /* State 138 */ [ Action::Error, Action::Reduce(55), Action::Reduce(55), Action::Error, Action::Reduce(55), Action::Error, Action::Error, Action::Reduce(55), Action::Error, Action::Error, Action::Reduce(55), Action::Error, Action::Error, Action::Reduce(55), Action::Reduce(55), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(55), Action::Error, Action::Error ],
-    /* State 139 */ [ Action::Error, Action::Reduce(54), Action::Reduce(54), Action::Error, Action::Reduce(54), Action::Error, Action::Error, Action::Reduce(54), Action::Error, Action::Error, Action::Reduce(54), Action::Error, Action::Error, Action::Reduce(54), Action::Reduce(54), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(54), Action::Error, Action::Error ],
-    /* State 140 */ [ Action::Error, Action::Reduce(56), Action::Reduce(56), Action::Error, Action::Reduce(56), Action::Error, Action::Error, Action::Reduce(56), Action::Error, Action::Error, Action::Reduce(56), Action::Error, Action::Error, Action::Reduce(56), Action::Reduce(56), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(56), Action::Error, Action::Error ],
-    /* State 141 */ [ Action::Error, Action::Reduce(72), Action::Reduce(72), Action::Error, Action::Reduce(72), Action::Error, Action::Reduce(72), Action::Reduce(72), Action::Reduce(72), Action::Error, Action::Reduce(72), Action::Reduce(72), Action::Reduce(72), Action::Reduce(72), Action::Reduce(72), Action::Reduce(72), Action::Reduce(72), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(72), Action::Error, Action::Error ],
-    /* State 142 */ [ Action::Error, Action::Reduce(71), Action::Reduce(71), Action::Error, Action::Reduce(71), Action::Error, Action::Reduce(71), Action::Reduce(71), Action::Reduce(71), Action::Error, Action::Reduce(71), Action::Reduce(71), Action::Reduce(71), Action::Reduce(71), Action::Reduce(71), Action::Reduce(71), Action::Reduce(71), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(71), Action::Error, Action::Error ],
-    /* State 143 */ [ Action::Error, Action::Reduce(20), Action::Reduce(20), Action::Error, Action::Reduce(20), Action::Reduce(20), Action::Reduce(20), Action::Reduce(20), Action::Reduce(20), Action::Reduce(20), Action::Reduce(20), Action::Reduce(20), Action::Reduce(20), Action::Reduce(20), Action::Reduce(20), Action::Reduce(20), Action::Reduce(20), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(20), Action::Error, Action::Error ],
-    /* State 144 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(1), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 145 */ [ Action::Shift(State::S44), Action::Error, Action::Error, Action::Shift(State::S45), Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S46), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S47), Action::Shift(State::S48), Action::Error, Action::Shift(State::S50), Action::Error, Action::Error, Action::Shift(State::S52), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 146 */ [ Action::Shift(State::S44), Action::Error, Action::Error, Action::Shift(State::S45), Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S46), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S47), Action::Shift(State::S48), Action::Shift(State::S49), Action::Shift(State::S50), Action::Shift(State::S51), Action::Error, Action::Shift(State::S52), Action::Shift(State::S53), Action::Shift(State::S18), Action::Error, Action::Error, Action::Error ],
-    /* State 147 */ [ Action::Shift(State::S44), Action::Error, Action::Error, Action::Shift(State::S45), Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S46), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S47), Action::Shift(State::S48), Action::Shift(State::S49), Action::Shift(State::S50), Action::Shift(State::S51), Action::Error, Action::Shift(State::S52), Action::Shift(State::S53), Action::Shift(State::S18), Action::Error, Action::Error, Action::Error ],
-    /* State 148 */ [ Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(3), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error ],
-    /* State 149 */ [ Action::Reduce(34), Action::Error, Action::Error, Action::Reduce(34), Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(34), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S153), Action::Reduce(34), Action::Reduce(34), Action::Reduce(34), Action::Reduce(34), Action::Reduce(34), Action::Error, Action::Reduce(34), Action::Reduce(34), Action::Reduce(34), Action::Error, Action::Reduce(34), Action::Error ],
-    /* State 150 */ [ Action::Reduce(41), Action::Error, Action::Error, Action::Reduce(41), Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(41), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(41), Action::Reduce(41), Action::Reduce(41), Action::Reduce(41), Action::Reduce(41), Action::Error, Action::Reduce(41), Action::Reduce(41), Action::Reduce(41), Action::Error, Action::Reduce(41), Action::Error ],
-    /* State 151 */ [ Action::Reduce(25), Action::Error, Action::Error, Action::Reduce(25), Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(25), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(25), Action::Reduce(25), Action::Reduce(25), Action::Reduce(25), Action::Reduce(25), Action::Reduce(25), Action::Error, Action::Reduce(25), Action::Reduce(25), Action::Reduce(25), Action::Error, Action::Reduce(25), Action::Error ],
-    /* State 152 */ [ Action::Reduce(43), Action::Error, Action::Error, Action::Reduce(43), Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(43), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(43), Action::Reduce(43), Action::Reduce(43), Action::Reduce(43), Action::Reduce(43), Action::Error, Action::Reduce(43), Action::Reduce(43), Action::Reduce(43), Action::Error, Action::Reduce(43), Action::Error ],
-    /* State 153 */ [ Action::Shift(State::S44), Action::Error, Action::Error, Action::Shift(State::S45), Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S46), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Shift(State::S47), Action::Shift(State::S48), Action::Shift(State::S49), Action::Shift(State::S50), Action::Shift(State::S51), Action::Error, Action::Shift(State::S52), Action::Shift(State::S53), Action::Shift(State::S18), Action::Error, Action::Error, Action::Error ],
-    /* State 154 */ [ Action::Reduce(26), Action::Error, Action::Error, Action::Reduce(26), Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(26), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(26), Action::Reduce(26), Action::Reduce(26), Action::Reduce(26), Action::Reduce(26), Action::Reduce(26), Action::Error, Action::Reduce(26), Action::Reduce(26), Action::Reduce(26), Action::Error, Action::Reduce(26), Action::Error ],
-    /* State 155 */ [ Action::Reduce(42), Action::Error, Action::Error, Action::Reduce(42), Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(42), Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Error, Action::Reduce(42), Action::Reduce(42), Action::Reduce(42), Action::Reduce(42), Action::Reduce(42), Action::Error, Action::Reduce(42), Action::Reduce(42), Action::Reduce(42), Action::Error, Action::Reduce(42), Action::Error ],
-];
-
-pub const GOTO: [[Option<State>; NNONTERM]; NSTATE] = [
-    /* State 0 */ [ None, None, None, None, None, None, None, None, None, Some(State::S1), Some(State::S2), None, None, None, None, None, Some(State::S3), Some(State::S4), None, None, Some(State::S5), None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 1 */ [ None, None, None, None, None, None, None, None, None, Some(State::S1), Some(State::S8), None, None, None, None, None, None, Some(State::S4), None, None, Some(State::S5), None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 2 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 3 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 4 */ [ None, None, None, None, None, None, None, None, Some(State::S9), None, None, None, Some(State::S10), None, None, None, None, None, None, None, None, None, Some(State::S11), None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 5 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 6 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 7 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 8 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 9 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 10 */ [ None, None, None, Some(State::S17), None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 11 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 12 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 13 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 14 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 15 */ [ None, None, None, None, None, None, Some(State::S23), Some(State::S24), None, None, None, None, None, None, None, None, None, Some(State::S25), None, None, Some(State::S5), None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 16 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 17 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 18 */ [ None, None, None, Some(State::S26), None, None, Some(State::S23), Some(State::S27), None, None, None, Some(State::S28), None, Some(State::S29), Some(State::S30), None, None, Some(State::S25), Some(State::S31), Some(State::S32), Some(State::S5), Some(State::S33), None, None, Some(State::S34), None, Some(State::S35), None, Some(State::S36), None, Some(State::S37), Some(State::S38), None, Some(State::S39), None, Some(State::S40), None, Some(State::S41), Some(State::S42), None, Some(State::S43) ],
-    /* State 19 */ [ None, None, None, None, None, None, None, None, Some(State::S54), None, None, None, None, None, None, None, None, None, None, None, None, None, Some(State::S11), None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 20 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 21 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, Some(State::S57), None, Some(State::S58), None, None, Some(State::S5), None, None, Some(State::S59), None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 22 */ [ None, None, None, None, None, None, Some(State::S23), Some(State::S61), None, None, None, None, None, None, None, None, None, Some(State::S25), None, None, Some(State::S5), None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 23 */ [ None, None, None, None, None, None, Some(State::S23), Some(State::S62), None, None, None, None, None, None, None, None, None, Some(State::S25), None, None, Some(State::S5), None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 24 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 25 */ [ None, None, None, None, Some(State::S64), Some(State::S65), None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, Some(State::S66), None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 26 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 27 */ [ None, None, None, Some(State::S26), None, None, None, None, None, None, None, Some(State::S28), None, Some(State::S29), Some(State::S30), None, None, None, Some(State::S31), Some(State::S67), None, Some(State::S33), None, None, Some(State::S34), None, Some(State::S35), None, Some(State::S36), None, Some(State::S37), Some(State::S38), None, Some(State::S39), None, Some(State::S40), None, Some(State::S41), Some(State::S42), None, Some(State::S43) ],
-    /* State 28 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 29 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 30 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 31 */ [ None, None, None, Some(State::S26), None, None, None, None, None, None, None, Some(State::S28), None, Some(State::S29), Some(State::S30), None, None, None, Some(State::S31), Some(State::S68), None, Some(State::S33), None, None, Some(State::S34), None, Some(State::S35), None, Some(State::S36), None, Some(State::S37), Some(State::S38), None, Some(State::S39), None, Some(State::S40), None, Some(State::S41), Some(State::S42), None, Some(State::S43) ],
-    /* State 32 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 33 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 34 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 35 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, Some(State::S70), None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 36 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, Some(State::S73), None, None, None, None, None, None ],
-    /* State 37 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 38 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, Some(State::S76), None ],
-    /* State 39 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, Some(State::S79), None, None, None, None ],
-    /* State 40 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, Some(State::S81), None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 41 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 42 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, Some(State::S83), None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 43 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, Some(State::S88), None, None, None, None, None, None, None, None ],
-    /* State 44 */ [ None, None, None, None, None, None, None, None, None, None, None, Some(State::S28), None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, Some(State::S41), None, None, Some(State::S91) ],
-    /* State 45 */ [ None, None, None, None, None, None, None, None, None, None, None, Some(State::S28), None, None, None, None, None, None, None, None, None, None, None, None, Some(State::S34), None, Some(State::S35), None, Some(State::S36), None, Some(State::S92), Some(State::S38), None, Some(State::S39), None, Some(State::S40), None, Some(State::S41), Some(State::S42), None, Some(State::S43) ],
-    /* State 46 */ [ None, None, None, None, None, None, None, None, None, None, None, Some(State::S28), None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, Some(State::S41), None, None, Some(State::S93) ],
-    /* State 47 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 48 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 49 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 50 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 51 */ [ None, None, None, None, None, None, None, None, None, None, None, Some(State::S28), None, None, None, None, None, None, None, None, None, None, None, None, Some(State::S34), None, Some(State::S35), None, Some(State::S36), None, Some(State::S96), Some(State::S38), None, Some(State::S39), None, Some(State::S40), None, Some(State::S41), Some(State::S42), None, Some(State::S43) ],
-    /* State 52 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 53 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 54 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 55 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 56 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 57 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 58 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, Some(State::S100), None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 59 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 60 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 61 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 62 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 63 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 64 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 65 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 66 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 67 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 68 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 69 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 70 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 71 */ [ None, None, None, None, None, None, None, None, None, None, None, Some(State::S28), None, None, None, None, None, None, None, None, None, None, None, None, None, None, Some(State::S107), None, None, None, None, Some(State::S38), None, None, None, None, None, Some(State::S41), Some(State::S42), None, Some(State::S43) ],
-    /* State 72 */ [ None, None, None, None, None, None, None, None, None, None, None, Some(State::S28), None, None, None, None, None, None, None, None, None, None, None, None, None, None, Some(State::S108), None, None, None, None, Some(State::S38), None, None, None, None, None, Some(State::S41), Some(State::S42), None, Some(State::S43) ],
-    /* State 73 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 74 */ [ None, None, None, None, None, None, None, None, None, None, None, Some(State::S28), None, None, None, None, None, None, None, None, None, None, None, None, None, None, Some(State::S35), None, Some(State::S109), None, None, Some(State::S38), None, None, None, None, None, Some(State::S41), Some(State::S42), None, Some(State::S43) ],
-    /* State 75 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 76 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 77 */ [ None, None, None, None, None, None, None, None, None, None, None, Some(State::S28), None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, Some(State::S110), None, None, None, None, None, Some(State::S41), None, None, Some(State::S43) ],
-    /* State 78 */ [ None, None, None, None, None, None, None, None, None, None, None, Some(State::S28), None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, Some(State::S111), None, None, None, None, None, Some(State::S41), None, None, Some(State::S43) ],
-    /* State 79 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 80 */ [ None, None, None, None, None, None, None, None, None, None, None, Some(State::S28), None, None, None, None, None, None, None, None, None, None, None, None, None, None, Some(State::S35), None, Some(State::S36), None, None, Some(State::S38), None, Some(State::S112), None, None, None, Some(State::S41), Some(State::S42), None, Some(State::S43) ],
-    /* State 81 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 82 */ [ None, None, None, None, None, None, None, None, None, None, None, Some(State::S28), None, None, None, None, None, None, None, None, None, None, None, None, None, None, Some(State::S35), None, Some(State::S36), None, None, Some(State::S38), None, Some(State::S39), None, Some(State::S113), None, Some(State::S41), Some(State::S42), None, Some(State::S43) ],
-    /* State 83 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 84 */ [ None, None, None, None, None, None, None, None, None, None, None, Some(State::S28), None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, Some(State::S38), None, None, None, None, None, Some(State::S41), Some(State::S114), None, Some(State::S43) ],
-    /* State 85 */ [ None, None, None, None, None, None, None, None, None, None, None, Some(State::S28), None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, Some(State::S38), None, None, None, None, None, Some(State::S41), Some(State::S115), None, Some(State::S43) ],
-    /* State 86 */ [ None, None, None, None, None, None, None, None, None, None, None, Some(State::S28), None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, Some(State::S38), None, None, None, None, None, Some(State::S41), Some(State::S116), None, Some(State::S43) ],
-    /* State 87 */ [ None, None, None, None, None, None, None, None, None, None, None, Some(State::S28), None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, Some(State::S38), None, None, None, None, None, Some(State::S41), Some(State::S117), None, Some(State::S43) ],
-    /* State 88 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 89 */ [ None, None, None, None, None, None, None, None, None, None, None, Some(State::S28), None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, Some(State::S41), None, None, Some(State::S118) ],
-    /* State 90 */ [ None, None, None, None, None, None, None, None, None, None, None, Some(State::S28), None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, Some(State::S41), None, None, Some(State::S119) ],
-    /* State 91 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 92 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 93 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 94 */ [ None, Some(State::S121), None, None, None, None, None, None, None, None, None, Some(State::S28), None, None, None, None, None, None, None, None, None, None, None, None, Some(State::S34), None, Some(State::S35), None, Some(State::S36), None, Some(State::S122), Some(State::S38), None, Some(State::S39), None, Some(State::S40), None, Some(State::S41), Some(State::S42), None, Some(State::S43) ],
-    /* State 95 */ [ None, None, None, None, None, None, None, None, None, None, None, Some(State::S28), None, None, None, None, None, None, None, None, None, None, None, None, Some(State::S34), None, Some(State::S35), None, Some(State::S36), None, Some(State::S124), Some(State::S38), None, Some(State::S39), None, Some(State::S40), None, Some(State::S41), Some(State::S42), None, Some(State::S43) ],
-    /* State 96 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 97 */ [ None, None, None, None, None, None, None, None, None, None, None, Some(State::S28), None, None, None, None, None, None, None, None, None, None, None, None, Some(State::S34), None, Some(State::S35), None, Some(State::S36), None, Some(State::S126), Some(State::S38), None, Some(State::S39), None, Some(State::S40), None, Some(State::S41), Some(State::S42), None, Some(State::S43) ],
-    /* State 98 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 99 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, Some(State::S57), None, Some(State::S58), None, None, Some(State::S5), None, None, Some(State::S127), None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 100 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 101 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 102 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 103 */ [ None, None, None, None, Some(State::S64), Some(State::S128), None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, Some(State::S66), None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 104 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 105 */ [ None, None, None, None, None, None, None, None, None, None, None, Some(State::S28), None, None, None, None, None, None, None, None, None, None, None, None, Some(State::S34), None, Some(State::S35), None, Some(State::S36), None, Some(State::S129), Some(State::S38), None, Some(State::S39), None, Some(State::S40), None, Some(State::S41), Some(State::S42), None, Some(State::S43) ],
-    /* State 106 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 107 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, Some(State::S130), None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 108 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, Some(State::S131), None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 109 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, Some(State::S132), None, None, None, None, None, None ],
-    /* State 110 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, Some(State::S133), None ],
-    /* State 111 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, Some(State::S134), None ],
-    /* State 112 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, Some(State::S135), None, None, None, None ],
-    /* State 113 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, Some(State::S136), None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 114 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, Some(State::S137), None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 115 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, Some(State::S138), None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 116 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, Some(State::S139), None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 117 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, Some(State::S140), None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 118 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, Some(State::S141), None, None, None, None, None, None, None, None ],
-    /* State 119 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, Some(State::S142), None, None, None, None, None, None, None, None ],
-    /* State 120 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 121 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 122 */ [ None, None, Some(State::S144), None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 123 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 124 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 125 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 126 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 127 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 128 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 129 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 130 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 131 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 132 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 133 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 134 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 135 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 136 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 137 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 138 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 139 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 140 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 141 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 142 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 143 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 144 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 145 */ [ None, None, None, None, None, None, None, None, None, None, None, Some(State::S28), None, None, None, None, None, None, None, None, None, None, None, None, Some(State::S34), None, Some(State::S35), None, Some(State::S36), None, Some(State::S148), Some(State::S38), None, Some(State::S39), None, Some(State::S40), None, Some(State::S41), Some(State::S42), None, Some(State::S43) ],
-    /* State 146 */ [ None, None, None, Some(State::S26), None, None, None, None, None, None, None, Some(State::S28), None, Some(State::S149), Some(State::S30), None, None, None, Some(State::S150), None, None, Some(State::S33), None, None, Some(State::S34), None, Some(State::S35), None, Some(State::S36), None, Some(State::S37), Some(State::S38), None, Some(State::S39), None, Some(State::S40), None, Some(State::S41), Some(State::S42), None, Some(State::S43) ],
-    /* State 147 */ [ None, None, None, Some(State::S26), None, None, None, None, None, None, None, Some(State::S28), None, Some(State::S151), Some(State::S30), None, None, None, None, None, None, Some(State::S152), None, None, Some(State::S34), None, Some(State::S35), None, Some(State::S36), None, Some(State::S37), Some(State::S38), None, Some(State::S39), None, Some(State::S40), None, Some(State::S41), Some(State::S42), None, Some(State::S43) ],
-    /* State 148 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 149 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 150 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 151 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 152 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 153 */ [ None, None, None, Some(State::S26), None, None, None, None, None, None, None, Some(State::S28), None, Some(State::S154), Some(State::S30), None, None, None, None, None, None, Some(State::S155), None, None, Some(State::S34), None, Some(State::S35), None, Some(State::S36), None, Some(State::S37), Some(State::S38), None, Some(State::S39), None, Some(State::S40), None, Some(State::S41), Some(State::S42), None, Some(State::S43) ],
-    /* State 154 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-    /* State 155 */ [ None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None ],
-];
+include!(concat!(env!("OUT_DIR"), "/parser_tables.rs"));
@@ -0,0 +1,464 @@
+// 语法规则的声明，build.rs 据此生成 LALR(1) 分析表（见 build/lalr.rs）
+// 第一条产生式的左部是开始符号；加产生式只需要在 Prod 里加一个名字，
+// 再在 parse 里写对应的规约动作
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Prod {
+    Program,
+    ExtDefList,
+    ExtDefListLast,
+    ExtDefVars,
+    ExtDefSpec,
+    ExtDefFun,
+    ExtDefProto,
+    ExtDefStorage,
+    ExtDefError,
+    ExtDefErrorBody,
+    ExtDefErrorSync,
+    ExtDecList,
+    ExtDecListLast,
+    SpecifierType,
+    SpecifierStruct,
+    StructDef,
+    StructDefAnon,
+    StructDecl,
+    VarDecPointer,
+    VarDecDirect,
+    VarDecId,
+    VarDecArray,
+    VarDecArrayUnsized,
+    FunDec,
+    FunDecNoParams,
+    FunDecPointer,
+    VarList,
+    VarListLast,
+    ParaDec,
+    ParaDecAbstract,
+    ParaDecNoName,
+    AbstractDec,
+    AbstractDecLast,
+    CompSt,
+    CompStNoDefs,
+    StmtList,
+    StmtListLast,
+    StmtListError,
+    StmtMatched,
+    StmtUnmatched,
+    MatchedNormal,
+    MatchedWhile,
+    MatchedIfElse,
+    UnmatchedIf,
+    UnmatchedIfElse,
+    UnmatchedWhile,
+    MatchedFor,
+    UnmatchedFor,
+    ForInitDef,
+    ForInitExpr,
+    ForInitEmpty,
+    ForExpr,
+    ForExprEmpty,
+    MatchedSwitch,
+    UnmatchedSwitch,
+    MatchedCase,
+    UnmatchedCase,
+    MatchedDefault,
+    UnmatchedDefault,
+    MatchedLabel,
+    UnmatchedLabel,
+    NormalExpr,
+    NormalCompSt,
+    NormalReturn,
+    NormalBreak,
+    NormalContinue,
+    NormalGoto,
+    NormalDoWhile,
+    NormalError,
+    DefList,
+    DefListLast,
+    Def,
+    DefError,
+    DefErrorSync,
+    DecList,
+    DecListLast,
+    Dec,
+    DecInit,
+    Initializer,
+    InitializerList,
+    InitializerListComma,
+    InitList,
+    InitListFirst,
+    Expression,
+    Comma,
+    CommaChain,
+    CommaPrime,
+    CommaPrimeLast,
+    Assign,
+    AssignChain,
+    AssignPrime,
+    AssignPrimeLast,
+    Conditional,
+    ConditionalTernary,
+    LogicalOr,
+    LogicalOrChain,
+    LogicalOrPrime,
+    LogicalOrPrimeLast,
+    LogicalAnd,
+    LogicalAndChain,
+    LogicalAndPrime,
+    LogicalAndPrimeLast,
+    BitOr,
+    BitOrChain,
+    BitOrPrime,
+    BitOrPrimeLast,
+    BitXor,
+    BitXorChain,
+    BitXorPrime,
+    BitXorPrimeLast,
+    BitAnd,
+    BitAndChain,
+    BitAndPrime,
+    BitAndPrimeLast,
+    Equality,
+    EqualityChain,
+    EqualityPrime,
+    EqualityPrimeLast,
+    Comparison,
+    ComparisonChain,
+    ComparisonPrime,
+    ComparisonPrimeLast,
+    Shift,
+    ShiftChain,
+    ShiftPrime,
+    ShiftPrimeLast,
+    Term,
+    TermChain,
+    TermPrime,
+    TermPrimeLast,
+    Factor,
+    FactorChain,
+    FactorPrime,
+    FactorPrimeLast,
+    UnaryOp,
+    UnaryPostfix,
+    UnaryPrimary,
+    PrimaryToken,
+    PrimaryParen,
+    PrimaryCall,
+    PrimaryIndex,
+    PrimaryMember,
+    FunCall,
+    FunCallNoArgs,
+    Arguments,
+    ArgumentsLast,
+    ArgumentsTail,
+}
+
+// 写法：左部 -> 右部，右部的符号用空格分开，右部为空表示 ε
+// 'x' 是按拼写匹配的 token（标点和关键字），全大写的名字是一类 token：
+// ID 标识符、LITERAL 字面量、TYPE 基本类型关键字，其余是非终结符
+// error 是出错恢复用的终结符，出错时分析器退栈到能移进 error 的状态，
+// 再丢弃 token 直到下一个 token 能继续分析
+// 同一个左部的多个候选用 | 写在一行里时，它们共用一个 Prod
+pub const GRAMMAR: &[(Prod, &str)] = &[
+    (Prod::Program, "Program -> ExtDefList"),
+    (Prod::ExtDefList, "ExtDefList -> ExtDef ExtDefList"),
+    (Prod::ExtDefListLast, "ExtDefList -> ExtDef"),
+    (Prod::ExtDefVars, "ExtDef -> Specifier ExtDecList ';'"),
+    (Prod::ExtDefSpec, "ExtDef -> Specifier ';'"),
+    (Prod::ExtDefFun, "ExtDef -> Specifier FunDec CompSt"),
+    // 函数原型
+    (Prod::ExtDefProto, "ExtDef -> Specifier FunDec ';'"),
+    // 存储类只能写在顶层的声明前面，写了几个由语义分析报错
+    (
+        Prod::ExtDefStorage,
+        "ExtDef -> 'extern' ExtDef | 'static' ExtDef",
+    ),
+    // 顶层出错时同步到 ';'、函数体或者下一个声明的开头
+    (Prod::ExtDefError, "ExtDef -> error ';'"),
+    (Prod::ExtDefErrorBody, "ExtDef -> error CompSt"),
+    (Prod::ExtDefErrorSync, "ExtDef -> error"),
+    // 全局变量也可以初始化，初始值必须是常量，由语义分析检查
+    (Prod::ExtDecList, "ExtDecList -> Dec ',' ExtDecList"),
+    (Prod::ExtDecListLast, "ExtDecList -> Dec"),
+    (Prod::SpecifierType, "Specifier -> TYPE"),
+    (Prod::SpecifierStruct, "Specifier -> StructSpecifier"),
+    (
+        Prod::StructDef,
+        "StructSpecifier -> 'struct' ID '{' DefList '}'",
+    ),
+    (
+        Prod::StructDefAnon,
+        "StructSpecifier -> 'struct' '{' DefList '}'",
+    ),
+    (Prod::StructDecl, "StructSpecifier -> 'struct' ID"),
+    // '*' 放在 DirectDec 外面，[] 比 * 结合得紧，int *a[3] 是指针数组
+    (Prod::VarDecPointer, "VarDec -> '*' VarDec"),
+    (Prod::VarDecDirect, "VarDec -> DirectDec"),
+    (Prod::VarDecId, "DirectDec -> ID"),
+    (Prod::VarDecArray, "DirectDec -> DirectDec '[' LITERAL ']'"),
+    // 形参可以不写长度
+    (Prod::VarDecArrayUnsized, "DirectDec -> DirectDec '[' ']'"),
+    (Prod::FunDec, "FunDec -> ID '(' VarList ')'"),
+    (Prod::FunDecNoParams, "FunDec -> ID '(' ')'"),
+    // 返回指针的函数
+    (Prod::FunDecPointer, "FunDec -> '*' FunDec"),
+    (Prod::VarList, "VarList -> ParaDec ',' VarList"),
+    (Prod::VarListLast, "VarList -> ParaDec"),
+    (Prod::ParaDec, "ParaDec -> Specifier VarDec"),
+    // 原型里的形参可以不写名字，int f(int, char *)
+    (Prod::ParaDecAbstract, "ParaDec -> Specifier AbstractDec"),
+    (Prod::ParaDecNoName, "ParaDec -> Specifier"),
+    (Prod::AbstractDec, "AbstractDec -> '*' AbstractDec"),
+    (Prod::AbstractDecLast, "AbstractDec -> '*'"),
+    (Prod::CompSt, "CompSt -> '{' DefList StmtList '}'"),
+    (Prod::CompStNoDefs, "CompSt -> '{' StmtList '}'"),
+    (Prod::StmtList, "StmtList -> Stmt StmtList"),
+    (Prod::StmtListLast, "StmtList -> Stmt"),
+    // 语句出错时同步到 ';' 或者所在块的 '}'
+    (Prod::StmtListError, "StmtList -> error"),
+    // 把 if 分成 else 已经配对和没有配对的两类，消除悬空 else 的二义性
+    (Prod::StmtMatched, "Stmt -> MatchedStmt"),
+    (Prod::StmtUnmatched, "Stmt -> UnMatchedStmt"),
+    (Prod::MatchedNormal, "MatchedStmt -> NormalStmt"),
+    (
+        Prod::MatchedWhile,
+        "MatchedStmt -> 'while' '(' Expression ')' MatchedStmt",
+    ),
+    (
+        Prod::MatchedIfElse,
+        "MatchedStmt -> 'if' '(' Expression ')' MatchedStmt 'else' MatchedStmt",
+    ),
+    (
+        Prod::UnmatchedIf,
+        "UnMatchedStmt -> 'if' '(' Expression ')' Stmt",
+    ),
+    (
+        Prod::UnmatchedIfElse,
+        "UnMatchedStmt -> 'if' '(' Expression ')' MatchedStmt 'else' UnMatchedStmt",
+    ),
+    (
+        Prod::UnmatchedWhile,
+        "UnMatchedStmt -> 'while' '(' Expression ')' UnMatchedStmt",
+    ),
+    // for 的三个部分都可以省略，第一部分还可以是声明
+    (
+        Prod::MatchedFor,
+        "MatchedStmt -> 'for' '(' ForInit ForExpr ';' ForExpr ')' MatchedStmt",
+    ),
+    (
+        Prod::UnmatchedFor,
+        "UnMatchedStmt -> 'for' '(' ForInit ForExpr ';' ForExpr ')' UnMatchedStmt",
+    ),
+    // 不直接用 Def，避免和 Def 的出错恢复产生式冲突
+    (Prod::ForInitDef, "ForInit -> Specifier DecList ';'"),
+    (Prod::ForInitExpr, "ForInit -> Expression ';'"),
+    (Prod::ForInitEmpty, "ForInit -> ';'"),
+    (Prod::ForExpr, "ForExpr -> Expression"),
+    (Prod::ForExprEmpty, "ForExpr ->"),
+    (
+        Prod::MatchedSwitch,
+        "MatchedStmt -> 'switch' '(' Expression ')' MatchedStmt",
+    ),
+    (
+        Prod::UnmatchedSwitch,
+        "UnMatchedStmt -> 'switch' '(' Expression ')' UnMatchedStmt",
+    ),
+    // case 后面是常量表达式，不允许赋值
+    (
+        Prod::MatchedCase,
+        "MatchedStmt -> 'case' Conditional ':' MatchedStmt",
+    ),
+    (
+        Prod::UnmatchedCase,
+        "UnMatchedStmt -> 'case' Conditional ':' UnMatchedStmt",
+    ),
+    (
+        Prod::MatchedDefault,
+        "MatchedStmt -> 'default' ':' MatchedStmt",
+    ),
+    (
+        Prod::UnmatchedDefault,
+        "UnMatchedStmt -> 'default' ':' UnMatchedStmt",
+    ),
+    // goto 的标号，在整个函数里有效
+    (Prod::MatchedLabel, "MatchedStmt -> ID ':' MatchedStmt"),
+    (
+        Prod::UnmatchedLabel,
+        "UnMatchedStmt -> ID ':' UnMatchedStmt",
+    ),
+    (Prod::NormalExpr, "NormalStmt -> Expression ';'"),
+    (Prod::NormalCompSt, "NormalStmt -> CompSt"),
+    (Prod::NormalReturn, "NormalStmt -> 'return' Expression ';'"),
+    (Prod::NormalBreak, "NormalStmt -> 'break' ';'"),
+    (Prod::NormalContinue, "NormalStmt -> 'continue' ';'"),
+    (Prod::NormalGoto, "NormalStmt -> 'goto' ID ';'"),
+    // 循环体后面跟着 while，不会有悬空 else 的问题
+    (
+        Prod::NormalDoWhile,
+        "NormalStmt -> 'do' Stmt 'while' '(' Expression ')' ';'",
+    ),
+    (Prod::NormalError, "NormalStmt -> error ';'"),
+    (Prod::DefList, "DefList -> Def DefList"),
+    (Prod::DefListLast, "DefList -> Def"),
+    (Prod::Def, "Def -> Specifier DecList ';'"),
+    // 声明出错时同步到 ';' 或者下一个声明的开头
+    (Prod::DefError, "Def -> Specifier error ';'"),
+    (Prod::DefErrorSync, "Def -> Specifier error"),
+    (Prod::DecList, "DecList -> Dec ',' DecList"),
+    (Prod::DecListLast, "DecList -> Dec"),
+    (Prod::Dec, "Dec -> VarDec"),
+    (Prod::DecInit, "Dec -> VarDec '=' Initializer"),
+    // 初始值和实参里的逗号是分隔符，所以只能是赋值表达式
+    (Prod::Initializer, "Initializer -> Assign"),
+    // 花括号里的初始值列表，最后可以多一个逗号
+    (Prod::InitializerList, "Initializer -> '{' InitList '}'"),
+    (
+        Prod::InitializerListComma,
+        "Initializer -> '{' InitList ',' '}'",
+    ),
+    // 左递归，这样看到 ',' 之后再看下一个是不是 '}'
+    (Prod::InitList, "InitList -> InitList ',' Initializer"),
+    (Prod::InitListFirst, "InitList -> Initializer"),
+    // 表达式按优先级分层，每层是 X -> Y XPrime，XPrime -> op Y XPrime | op Y
+    (Prod::Expression, "Expression -> Comma"),
+    // 逗号运算符优先级最低，左结合
+    (Prod::Comma, "Comma -> Assign"),
+    (Prod::CommaChain, "Comma -> Assign CommaPrime"),
+    (Prod::CommaPrime, "CommaPrime -> ',' Assign CommaPrime"),
+    (Prod::CommaPrimeLast, "CommaPrime -> ',' Assign"),
+    (Prod::Assign, "Assign -> Conditional"),
+    (Prod::AssignChain, "Assign -> Conditional AssignPrime"),
+    // 赋值和复合赋值的左边必须是左值，在语义分析时检查
+    (
+        Prod::AssignPrime,
+        "AssignPrime -> '=' Conditional AssignPrime \
+         | '+=' Conditional AssignPrime \
+         | '-=' Conditional AssignPrime \
+         | '*=' Conditional AssignPrime \
+         | '/=' Conditional AssignPrime \
+         | '%=' Conditional AssignPrime \
+         | '<<=' Conditional AssignPrime \
+         | '>>=' Conditional AssignPrime \
+         | '&=' Conditional AssignPrime \
+         | '^=' Conditional AssignPrime \
+         | '|=' Conditional AssignPrime",
+    ),
+    (
+        Prod::AssignPrimeLast,
+        "AssignPrime -> '=' Conditional \
+         | '+=' Conditional \
+         | '-=' Conditional \
+         | '*=' Conditional \
+         | '/=' Conditional \
+         | '%=' Conditional \
+         | '<<=' Conditional \
+         | '>>=' Conditional \
+         | '&=' Conditional \
+         | '^=' Conditional \
+         | '|=' Conditional",
+    ),
+    // 条件运算符右结合，中间的操作数可以是任意表达式
+    (Prod::Conditional, "Conditional -> LogicalOr"),
+    (
+        Prod::ConditionalTernary,
+        "Conditional -> LogicalOr '?' Expression ':' Conditional",
+    ),
+    (Prod::LogicalOr, "LogicalOr -> LogicalAnd"),
+    (
+        Prod::LogicalOrChain,
+        "LogicalOr -> LogicalAnd LogicalOrPrime",
+    ),
+    (
+        Prod::LogicalOrPrime,
+        "LogicalOrPrime -> '||' LogicalAnd LogicalOrPrime",
+    ),
+    (
+        Prod::LogicalOrPrimeLast,
+        "LogicalOrPrime -> '||' LogicalAnd",
+    ),
+    (Prod::LogicalAnd, "LogicalAnd -> BitOr"),
+    (Prod::LogicalAndChain, "LogicalAnd -> BitOr LogicalAndPrime"),
+    (
+        Prod::LogicalAndPrime,
+        "LogicalAndPrime -> '&&' BitOr LogicalAndPrime",
+    ),
+    (Prod::LogicalAndPrimeLast, "LogicalAndPrime -> '&&' BitOr"),
+    (Prod::BitOr, "BitOr -> BitXor"),
+    (Prod::BitOrChain, "BitOr -> BitXor BitOrPrime"),
+    (Prod::BitOrPrime, "BitOrPrime -> '|' BitXor BitOrPrime"),
+    (Prod::BitOrPrimeLast, "BitOrPrime -> '|' BitXor"),
+    (Prod::BitXor, "BitXor -> BitAnd"),
+    (Prod::BitXorChain, "BitXor -> BitAnd BitXorPrime"),
+    (Prod::BitXorPrime, "BitXorPrime -> '^' BitAnd BitXorPrime"),
+    (Prod::BitXorPrimeLast, "BitXorPrime -> '^' BitAnd"),
+    (Prod::BitAnd, "BitAnd -> Equality"),
+    (Prod::BitAndChain, "BitAnd -> Equality BitAndPrime"),
+    (Prod::BitAndPrime, "BitAndPrime -> '&' Equality BitAndPrime"),
+    (Prod::BitAndPrimeLast, "BitAndPrime -> '&' Equality"),
+    (Prod::Equality, "Equality -> Comparison"),
+    (Prod::EqualityChain, "Equality -> Comparison EqualityPrime"),
+    (
+        Prod::EqualityPrime,
+        "EqualityPrime -> '==' Comparison EqualityPrime | '!=' Comparison EqualityPrime",
+    ),
+    (
+        Prod::EqualityPrimeLast,
+        "EqualityPrime -> '==' Comparison | '!=' Comparison",
+    ),
+    (Prod::Comparison, "Comparison -> Shift"),
+    (Prod::ComparisonChain, "Comparison -> Shift ComparisonPrime"),
+    (
+        Prod::ComparisonPrime,
+        "ComparisonPrime -> '<' Shift ComparisonPrime | '<=' Shift ComparisonPrime \
+         | '>' Shift ComparisonPrime | '>=' Shift ComparisonPrime",
+    ),
+    (
+        Prod::ComparisonPrimeLast,
+        "ComparisonPrime -> '<' Shift | '<=' Shift | '>' Shift | '>=' Shift",
+    ),
+    (Prod::Shift, "Shift -> Term"),
+    (Prod::ShiftChain, "Shift -> Term ShiftPrime"),
+    (
+        Prod::ShiftPrime,
+        "ShiftPrime -> '<<' Term ShiftPrime | '>>' Term ShiftPrime",
+    ),
+    (Prod::ShiftPrimeLast, "ShiftPrime -> '<<' Term | '>>' Term"),
+    (Prod::Term, "Term -> Factor"),
+    (Prod::TermChain, "Term -> Factor TermPrime"),
+    (
+        Prod::TermPrime,
+        "TermPrime -> '+' Factor TermPrime | '-' Factor TermPrime",
+    ),
+    (Prod::TermPrimeLast, "TermPrime -> '+' Factor | '-' Factor"),
+    (Prod::Factor, "Factor -> Unary"),
+    (Prod::FactorChain, "Factor -> Unary FactorPrime"),
+    (
+        Prod::FactorPrime,
+        "FactorPrime -> '*' Unary FactorPrime | '/' Unary FactorPrime \
+         | '%' Unary FactorPrime",
+    ),
+    (
+        Prod::FactorPrimeLast,
+        "FactorPrime -> '*' Unary | '/' Unary | '%' Unary",
+    ),
+    (
+        Prod::UnaryOp,
+        "Unary -> '!' Unary | '-' Unary | '~' Unary | '++' Unary | '--' Unary \
+         | '*' Unary | '&' Unary",
+    ),
+    (Prod::UnaryPostfix, "Unary -> Primary '++' | Primary '--'"),
+    (Prod::UnaryPrimary, "Unary -> Primary"),
+    (Prod::PrimaryToken, "Primary -> ID | LITERAL"),
+    (Prod::PrimaryParen, "Primary -> '(' Expression ')'"),
+    (Prod::PrimaryCall, "Primary -> FunCall"),
+    (Prod::PrimaryIndex, "Primary -> Primary '[' Expression ']'"),
+    (
+        Prod::PrimaryMember,
+        "Primary -> Primary '.' ID | Primary '->' ID",
+    ),
+    (Prod::FunCall, "FunCall -> ID '(' Arguments ')'"),
+    (Prod::FunCallNoArgs, "FunCall -> ID '(' ')'"),
+    (Prod::Arguments, "Arguments -> Assign ArgumentsTail"),
+    (Prod::ArgumentsLast, "Arguments -> Assign"),
+    (Prod::ArgumentsTail, "ArgumentsTail -> ',' Assign"),
+];
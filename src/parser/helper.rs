@@ -1,70 +1,249 @@
+use super::constant::{ACTION, EOF, ERROR, FIRST, GOTO, NTERM, NonTerm, TERMINALS, terminal};
+use super::grammar::Prod;
 use super::parse::CSTNode;
-use super::types::State;
+use super::types::{Action, State, SyntaxError};
 use crate::lexer::lexer::Token;
-use crate::utils::types::PhraseType;
+use crate::preprocessor::helper::expansion_notes;
+use crate::utils::types::{Span, TokenKind};
 
-pub fn error_handler(state: &Vec<State>, look: &Token) {
-    // 分析异常原因
-    println!(
-        "current state {:?} unexpected {:?}",
-        state.last().unwrap(),
-        look
-    );
+// 遇到 ACTION 表里的空格子时生成的错误
+// stack 是出错时的状态栈，sym 是栈上的 token，prev 是 look 前面的一个 token
+pub fn syntax_error(
+    stack: &[State],
+    sym: &[Token],
+    prev: Option<&Token>,
+    look: &Token,
+) -> SyntaxError {
+    let found = match look.kind {
+        TokenKind::Eof => String::from("end of input"),
+        _ => format!("'{}'", look),
+    };
+    let expected: Vec<usize> = (0..NTERM)
+        .filter(|&t| t != ERROR && simulate(stack, t).is_some())
+        .collect();
+    let semi = terminal(";").unwrap();
+    let mut notes = expansion_notes(look.span);
+
+    // 漏了分号：补上 ';' 之后 look 能接着分析下去，错误报在前一个 token 的后面
+    if let Some(prev) = prev
+        && let Some(after) = simulate(stack, semi)
+        && term_index(look).is_some_and(|t| simulate(&after, t).is_some())
+    {
+        let what = completed(&after).map_or("", describe);
+        let mut span = prev.span;
+        span.col += (span.hi - span.lo) as u32;
+        span.lo = span.hi;
+        let message = if what.is_empty() {
+            format!(
+                "expected ';', found {} at {}:{}",
+                found, look.span.line, look.span.col
+            )
+        } else {
+            format!(
+                "expected ';' after {}, found {} at {}:{}",
+                what, found, look.span.line, look.span.col
+            )
+        };
+        return SyntaxError {
+            span,
+            message,
+            notes,
+        };
+    }
+
+    let open = sym.iter().rev().find(|t| t.kind == TokenKind::LBrace);
+    let message = match look.kind {
+        // 括号没有配对
+        TokenKind::Eof if expected.contains(&terminal("}").unwrap()) => {
+            if let Some(open) = open {
+                notes.push(format!("{}: note: to match this '{{'", open.span));
+            }
+            String::from("expected '}' at end of input")
+        }
+        TokenKind::RBrace if open.is_none() => String::from("'}' without a matching '{'"),
+        TokenKind::Else => String::from("'else' without a previous 'if'"),
+        _ if expected.is_empty() => format!("unexpected {}", found),
+        _ => format!("expected {}, found {}", expected_names(&expected), found),
+    };
+    SyntaxError {
+        span: look.span,
+        message,
+        notes,
+    }
+}
+
+// 假设下一个终结符是 term，从状态栈 stack 开始一直规约到移进它为止，
+// 返回移进之后的状态栈；term 在这里不合法时返回 None
+fn simulate(stack: &[State], term: usize) -> Option<Vec<State>> {
+    let mut stack = stack.to_vec();
+    loop {
+        match ACTION[stack.last()?.to_index()][term] {
+            Action::Shift(ns) => {
+                stack.push(ns);
+                return Some(stack);
+            }
+            Action::Reduce(prod) => {
+                stack.truncate(stack.len() - prod.rhs_len());
+                let st = stack.last()?.to_index();
+                stack.push(GOTO[st][prod.lhs() as usize]?);
+            }
+            Action::Accept => return Some(stack),
+            Action::Error => return None,
+        }
+    }
 }
 
-pub fn is_type_keyword(value: &String) -> bool {
-    matches!(
-        value.as_str(),
-        "int" | "uint" | "float" | "double" | "char" | "String"
-    )
+// 移进之后接下来要规约的产生式，也就是刚才那个终结符结束的结构
+fn completed(stack: &[State]) -> Option<Prod> {
+    ACTION[stack.last()?.to_index()]
+        .iter()
+        .find_map(|act| match act {
+            Action::Reduce(prod) => Some(*prod),
+            _ => None,
+        })
 }
 
-/// 把 token_type 映射到 action_table 的列号
-pub fn term_index(tok: &Token) -> usize {
-    use PhraseType::*;
-    match tok.types {
-        Operator if tok.value == "!" => 0,
-        Operator if tok.value == "!=" => 1,
-        Operator if tok.value == "&&" => 2,
-        Separator if tok.value == "(" => 3,
-        Separator if tok.value == ")" => 4,
-        Operator if tok.value == "*" => 5,
-        Operator if tok.value == "+" => 6,
-        Separator if tok.value == "," => 7,
-        Operator if tok.value == "-" => 8,
-        Operator if tok.value == "/" => 9,
-        Separator if tok.value == ";" => 10,
-        Operator if tok.value == "<" => 11,
-        Operator if tok.value == "<=" => 12,
-        Operator if tok.value == "=" => 13,
-        Operator if tok.value == "==" => 14,
-        Operator if tok.value == ">" => 15,
-        Operator if tok.value == ">=" => 16,
-        Keyword if is_type_keyword(&tok.value) => 17,
-        Separator if tok.value == "[" => 18,
-        Separator if tok.value == "]" => 19,
-        Keyword if tok.value == "else" => 20,
-        Identifier if tok.value == "false" => 21,
-        Identifier if tok.value != "true" => 22,
-        Keyword if tok.value == "if" => 23,
-        Float | Bool | Char | Hex | Dec | Oct => 24,
-        Keyword if tok.value == "return" => 25,
-        Keyword if tok.value == "struct" => 26,
-        Identifier if tok.value == "true" => 27,
-        Keyword if tok.value == "while" => 28,
-        Separator if tok.value == "{" => 29,
-        Operator if tok.value == "||" => 30,
-        Separator if tok.value == "}" => 31,
-        _ => 32, // end of file
+fn describe(prod: Prod) -> &'static str {
+    match prod {
+        Prod::ExtDefVars | Prod::ExtDefSpec | Prod::ExtDefProto | Prod::Def => "declaration",
+        Prod::NormalExpr => "expression",
+        Prod::NormalReturn => "return statement",
+        Prod::NormalDoWhile => "do/while statement",
+        _ => "",
     }
 }
 
+// 把期望的终结符写成人能看懂的样子，能以某个非终结符概括的一组终结符合并成一个说法
+fn expected_names(expected: &[usize]) -> String {
+    let mut rest: Vec<usize> = expected.to_vec();
+    let mut names: Vec<String> = Vec::new();
+    for (nt, name) in [
+        (NonTerm::Stmt, "statement"),
+        (NonTerm::Expression, "expression"),
+        (NonTerm::Specifier, "type name"),
+    ] {
+        let first = FIRST[nt as usize];
+        if first.iter().all(|t| rest.contains(t)) {
+            rest.retain(|t| !first.contains(t));
+            names.push(String::from(name));
+        }
+    }
+    for t in rest {
+        names.push(match TERMINALS[t] {
+            "ID" => String::from("identifier"),
+            "LITERAL" => String::from("constant"),
+            "TYPE" => String::from("type name"),
+            _ if t == EOF => String::from("end of input"),
+            s => format!("'{}'", s),
+        });
+    }
+    match names.split_last() {
+        Some((last, init)) if !init.is_empty() => format!("{} or {}", init.join(", "), last),
+        _ => names.concat(),
+    }
+}
+
+// 规约时产生式右部 n 个符号合起来的位置
+pub fn reduce_span(spans: &[Span], n: usize) -> Span {
+    spans[spans.len() - n..]
+        .iter()
+        .fold(Span::default(), |acc, s| acc.to(*s))
+}
+
+/// 把 token 映射到 ACTION 表的列号，文法里没有用到的 token 返回 None
+pub fn term_index(tok: &Token) -> Option<usize> {
+    use TokenKind::*;
+    // 和 grammar.rs 里终结符的写法对应
+    let name = match &tok.kind {
+        Ident(_) => "ID",
+        IntLit(..) | FloatLit(..) | CharLit(_) | StrLit(_) | BoolLit(_) => "LITERAL",
+        k if k.is_type_keyword() => "TYPE",
+        Eof => "$",
+        k => k.as_str()?,
+    };
+    terminal(name)
+}
+
+#[allow(dead_code)]
 impl CSTNode {
+    pub fn span(&self) -> Span {
+        match self {
+            CSTNode::Program(_, span) | CSTNode::Expression(_, span) | CSTNode::Error(span) => {
+                *span
+            }
+            CSTNode::Assign { span, .. }
+            | CSTNode::AssignPrime { span, .. }
+            | CSTNode::UnMatchedStmt { span, .. }
+            | CSTNode::ForStmt { span, .. }
+            | CSTNode::ForInit { span, .. }
+            | CSTNode::ForExpr(_, span)
+            | CSTNode::SwitchStmt { span, .. }
+            | CSTNode::DoWhileStmt { span, .. }
+            | CSTNode::LabeledStmt { span, .. }
+            | CSTNode::StructSpecifier { span, .. }
+            | CSTNode::Specifier { span, .. }
+            | CSTNode::ParaDec { span, .. }
+            | CSTNode::MatchedStmt { span, .. }
+            | CSTNode::StmtList { span, .. }
+            | CSTNode::Stmt { span, .. }
+            | CSTNode::NormalStmt { span, .. }
+            | CSTNode::FunCall { span, .. }
+            | CSTNode::FunDec { span, .. }
+            | CSTNode::VarList { span, .. }
+            | CSTNode::VarDec { span, .. }
+            | CSTNode::LogicalOrPrime { span, .. }
+            | CSTNode::LogicalOr { span, .. }
+            | CSTNode::LogicalAnd { span, .. }
+            | CSTNode::LogicalAndPrime { span, .. }
+            | CSTNode::Comma { span, .. }
+            | CSTNode::CommaPrime { span, .. }
+            | CSTNode::Conditional { span, .. }
+            | CSTNode::BitOr { span, .. }
+            | CSTNode::BitOrPrime { span, .. }
+            | CSTNode::BitXor { span, .. }
+            | CSTNode::BitXorPrime { span, .. }
+            | CSTNode::BitAnd { span, .. }
+            | CSTNode::BitAndPrime { span, .. }
+            | CSTNode::Shift { span, .. }
+            | CSTNode::ShiftPrime { span, .. }
+            | CSTNode::ExtDefList { span, .. }
+            | CSTNode::ExtDef { span, .. }
+            | CSTNode::ExtDecList { span, .. }
+            | CSTNode::DefList { span, .. }
+            | CSTNode::Def { span, .. }
+            | CSTNode::DecList { span, .. }
+            | CSTNode::Dec { span, .. }
+            | CSTNode::InitList { span, .. }
+            | CSTNode::CompSt { span, .. }
+            | CSTNode::Arguments { span, .. }
+            | CSTNode::ArgumentsTail { span, .. }
+            | CSTNode::Equality { span, .. }
+            | CSTNode::EqualityPrime { span, .. }
+            | CSTNode::ComparisonPrime { span, .. }
+            | CSTNode::Comparison { span, .. }
+            | CSTNode::Term { span, .. }
+            | CSTNode::TermPrime { span, .. }
+            | CSTNode::Factor { span, .. }
+            | CSTNode::FactorPrime { span, .. }
+            | CSTNode::Unary { span, .. }
+            | CSTNode::Postfix { span, .. }
+            | CSTNode::Index { span, .. }
+            | CSTNode::Member { span, .. }
+            | CSTNode::Primary { span, .. } => *span,
+        }
+    }
+
     fn name(&self) -> &str {
         match self {
             CSTNode::Assign { .. } => "Assign",
             CSTNode::AssignPrime { .. } => "AssignPrime",
             CSTNode::UnMatchedStmt { .. } => "UnMatchedStmt",
+            CSTNode::ForStmt { .. } => "ForStmt",
+            CSTNode::ForInit { .. } => "ForInit",
+            CSTNode::ForExpr(..) => "ForExpr",
+            CSTNode::SwitchStmt { .. } => "SwitchStmt",
+            CSTNode::DoWhileStmt { .. } => "DoWhileStmt",
+            CSTNode::LabeledStmt { .. } => "LabeledStmt",
             CSTNode::StructSpecifier { .. } => "StructSpecifier",
             CSTNode::Specifier { .. } => "Specifier",
             CSTNode::ParaDec { .. } => "ParaDec",
@@ -72,8 +251,8 @@ impl CSTNode {
             CSTNode::StmtList { .. } => "StmtList",
             CSTNode::Stmt { .. } => "Stmt",
             CSTNode::NormalStmt { .. } => "NormalStmt",
-            CSTNode::Program(_) => "Program",
-            CSTNode::Expression(_) => "Expression",
+            CSTNode::Program(..) => "Program",
+            CSTNode::Expression(..) => "Expression",
             CSTNode::FunCall { .. } => "FunCall",
             CSTNode::FunDec { .. } => "FunDec",
             CSTNode::VarList { .. } => "VarList",
@@ -82,6 +261,17 @@ impl CSTNode {
             CSTNode::LogicalOr { .. } => "LogicalOr",
             CSTNode::LogicalAnd { .. } => "LogicalAnd",
             CSTNode::LogicalAndPrime { .. } => "LogicalAndPrime",
+            CSTNode::Comma { .. } => "Comma",
+            CSTNode::CommaPrime { .. } => "CommaPrime",
+            CSTNode::Conditional { .. } => "Conditional",
+            CSTNode::BitOr { .. } => "BitOr",
+            CSTNode::BitOrPrime { .. } => "BitOrPrime",
+            CSTNode::BitXor { .. } => "BitXor",
+            CSTNode::BitXorPrime { .. } => "BitXorPrime",
+            CSTNode::BitAnd { .. } => "BitAnd",
+            CSTNode::BitAndPrime { .. } => "BitAndPrime",
+            CSTNode::Shift { .. } => "Shift",
+            CSTNode::ShiftPrime { .. } => "ShiftPrime",
             CSTNode::ExtDefList { .. } => "ExtDefList",
             CSTNode::ExtDef { .. } => "ExtDef",
             CSTNode::ExtDecList { .. } => "ExtDecList",
@@ -89,6 +279,7 @@ impl CSTNode {
             CSTNode::Def { .. } => "Def",
             CSTNode::DecList { .. } => "DecList",
             CSTNode::Dec { .. } => "Dec",
+            CSTNode::InitList { .. } => "InitList",
             CSTNode::CompSt { .. } => "CompSt",
             CSTNode::Arguments { .. } => "Arguments",
             CSTNode::ArgumentsTail { .. } => "ArgumentsTail",
@@ -101,7 +292,11 @@ impl CSTNode {
             CSTNode::Factor { .. } => "Factor",
             CSTNode::FactorPrime { .. } => "FactorPrime",
             CSTNode::Unary { .. } => "Unary",
+            CSTNode::Postfix { .. } => "Postfix",
+            CSTNode::Index { .. } => "Index",
+            CSTNode::Member { .. } => "Member",
             CSTNode::Primary { .. } => "Primary",
+            CSTNode::Error(..) => "Error",
         }
     }
     pub fn print_tree(&self) {
@@ -134,25 +329,50 @@ impl CSTNode {
     fn children(&self) -> Vec<&CSTNode> {
         let mut v: Vec<&CSTNode> = Vec::new();
         match self {
-            CSTNode::Assign {
-                logical_or,
+            CSTNode::Comma {
+                assign: conditional,
+                comma_prime: assign_prime,
+                ..
+            }
+            | CSTNode::Assign {
+                conditional,
                 assign_prime,
+                ..
             } => {
-                v.push(logical_or);
+                v.push(conditional);
                 if let Some(ap) = assign_prime {
                     v.push(ap);
                 }
             }
-            CSTNode::AssignPrime {
-                logical_or,
+            CSTNode::CommaPrime {
+                assign: conditional,
+                comma_prime: assign_prime,
+                ..
+            }
+            | CSTNode::AssignPrime {
+                conditional,
                 assign_prime,
                 ..
             } => {
-                v.push(logical_or);
+                v.push(conditional);
                 if let Some(ap) = assign_prime {
                     v.push(ap);
                 }
             }
+            CSTNode::Conditional {
+                logical_or,
+                expression,
+                conditional,
+                ..
+            } => {
+                v.push(logical_or);
+                if let Some(e) = expression {
+                    v.push(e);
+                }
+                if let Some(c) = conditional {
+                    v.push(c);
+                }
+            }
             CSTNode::UnMatchedStmt {
                 expression,
                 matched_stmt,
@@ -173,6 +393,53 @@ impl CSTNode {
                     v.push(s);
                 }
             }
+            CSTNode::ForStmt {
+                for_init,
+                cond,
+                step,
+                body,
+                ..
+            } => {
+                v.push(for_init);
+                v.push(cond);
+                v.push(step);
+                v.push(body);
+            }
+            CSTNode::ForInit {
+                def, expression, ..
+            } => {
+                if let Some(d) = def {
+                    v.push(d);
+                }
+                if let Some(e) = expression {
+                    v.push(e);
+                }
+            }
+            CSTNode::ForExpr(expression, _) => {
+                if let Some(e) = expression {
+                    v.push(e);
+                }
+            }
+            CSTNode::SwitchStmt {
+                expression, body, ..
+            } => {
+                v.push(expression);
+                v.push(body);
+            }
+            CSTNode::DoWhileStmt {
+                body, expression, ..
+            } => {
+                v.push(body);
+                v.push(expression);
+            }
+            CSTNode::LabeledStmt {
+                expression, stmt, ..
+            } => {
+                if let Some(e) = expression {
+                    v.push(e);
+                }
+                v.push(stmt);
+            }
             CSTNode::StructSpecifier { def_list, .. } => {
                 if let Some(d) = def_list {
                     v.push(d);
@@ -185,7 +452,7 @@ impl CSTNode {
                     v.push(s);
                 }
             }
-            CSTNode::ParaDec { spec, var_dec } => {
+            CSTNode::ParaDec { spec, var_dec, .. } => {
                 v.push(spec);
                 v.push(var_dec);
             }
@@ -209,7 +476,9 @@ impl CSTNode {
                     v.push(b);
                 }
             }
-            CSTNode::StmtList { stmt, stmt_list } => {
+            CSTNode::StmtList {
+                stmt, stmt_list, ..
+            } => {
                 v.push(stmt);
                 if let Some(sl) = stmt_list {
                     v.push(sl);
@@ -218,6 +487,7 @@ impl CSTNode {
             CSTNode::Stmt {
                 unmatched_stmt,
                 matched_stmt,
+                ..
             } => {
                 if let Some(us) = unmatched_stmt {
                     v.push(us);
@@ -236,7 +506,7 @@ impl CSTNode {
                     v.push(c);
                 }
             }
-            CSTNode::Program(node) | CSTNode::Expression(node) => {
+            CSTNode::Program(node, _) | CSTNode::Expression(node, _) => {
                 v.push(node);
             }
             CSTNode::FunCall { arguments, .. }
@@ -269,10 +539,32 @@ impl CSTNode {
             CSTNode::LogicalOr {
                 logical_and,
                 logical_or_prime,
+                ..
             }
             | CSTNode::LogicalAnd {
-                equality: logical_and,
+                bit_or: logical_and,
                 logical_and_prime: logical_or_prime,
+                ..
+            }
+            | CSTNode::BitOr {
+                bit_xor: logical_and,
+                bit_or_prime: logical_or_prime,
+                ..
+            }
+            | CSTNode::BitXor {
+                bit_and: logical_and,
+                bit_xor_prime: logical_or_prime,
+                ..
+            }
+            | CSTNode::BitAnd {
+                equality: logical_and,
+                bit_and_prime: logical_or_prime,
+                ..
+            }
+            | CSTNode::Shift {
+                term: logical_and,
+                shift_prime: logical_or_prime,
+                ..
             } => {
                 v.push(logical_and);
                 if let Some(p) = logical_or_prime {
@@ -285,9 +577,29 @@ impl CSTNode {
                 ..
             }
             | CSTNode::LogicalAndPrime {
-                equality: logical_and,
+                bit_or: logical_and,
                 logical_and_prime: logical_or_prime,
                 ..
+            }
+            | CSTNode::BitOrPrime {
+                bit_xor: logical_and,
+                bit_or_prime: logical_or_prime,
+                ..
+            }
+            | CSTNode::BitXorPrime {
+                bit_and: logical_and,
+                bit_xor_prime: logical_or_prime,
+                ..
+            }
+            | CSTNode::BitAndPrime {
+                equality: logical_and,
+                bit_and_prime: logical_or_prime,
+                ..
+            }
+            | CSTNode::ShiftPrime {
+                term: logical_and,
+                shift_prime: logical_or_prime,
+                ..
             } => {
                 v.push(logical_and);
                 if let Some(p) = logical_or_prime {
@@ -297,6 +609,7 @@ impl CSTNode {
             CSTNode::ExtDefList {
                 ext_def,
                 ext_def_list,
+                ..
             } => {
                 v.push(ext_def);
                 if let Some(edl) = ext_def_list {
@@ -321,7 +634,7 @@ impl CSTNode {
                     v.push(c);
                 }
             }
-            CSTNode::DefList { def, def_list } => {
+            CSTNode::DefList { def, def_list, .. } => {
                 v.push(def);
                 if let Some(dl) = def_list {
                     v.push(dl);
@@ -347,6 +660,7 @@ impl CSTNode {
                     v.push(e);
                 }
             }
+            CSTNode::InitList { items, .. } => v.extend(items),
             CSTNode::CompSt {
                 def_list,
                 stmt_list,
@@ -360,6 +674,7 @@ impl CSTNode {
             CSTNode::Arguments {
                 arguments_tail,
                 expression,
+                ..
             } => {
                 v.push(expression);
                 if let Some(at) = arguments_tail {
@@ -372,10 +687,12 @@ impl CSTNode {
             CSTNode::Equality {
                 comparison,
                 equality_prime,
+                ..
             }
             | CSTNode::Comparison {
-                term: comparison,
+                shift: comparison,
                 comparison_prime: equality_prime,
+                ..
             } => {
                 v.push(comparison);
                 if let Some(p) = equality_prime {
@@ -388,7 +705,7 @@ impl CSTNode {
                 ..
             }
             | CSTNode::ComparisonPrime {
-                term: comparison,
+                shift: comparison,
                 comparison_prime: equality_prime,
                 ..
             } => {
@@ -397,10 +714,13 @@ impl CSTNode {
                     v.push(p);
                 }
             }
-            CSTNode::Term { factor, term_prime }
+            CSTNode::Term {
+                factor, term_prime, ..
+            }
             | CSTNode::Factor {
                 unary: factor,
                 factor_prime: term_prime,
+                ..
             } => {
                 v.push(factor);
                 if let Some(p) = term_prime {
@@ -423,6 +743,20 @@ impl CSTNode {
             CSTNode::Unary { unary, .. } => {
                 v.push(unary);
             }
+            CSTNode::Postfix { primary, .. } => {
+                v.push(primary);
+            }
+            CSTNode::Index {
+                primary,
+                expression,
+                ..
+            } => {
+                v.push(primary);
+                v.push(expression);
+            }
+            CSTNode::Member { primary, .. } => {
+                v.push(primary);
+            }
             CSTNode::Primary {
                 expression,
                 fun_call,
@@ -435,6 +769,7 @@ impl CSTNode {
                     v.push(fc);
                 }
             }
+            CSTNode::Error(..) => {}
         }
         v
     }
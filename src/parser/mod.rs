@@ -1,4 +1,5 @@
 pub mod constant;
+pub mod grammar;
 pub mod helper;
 pub mod parse;
 pub mod types;
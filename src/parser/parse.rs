@@ -1,19 +1,35 @@
-use super::helper::error_handler;
-use super::types::{Action, NonTerm, State};
+use super::constant::{ACTION, ERROR, GOTO};
+use super::grammar::Prod;
+use super::helper::{reduce_span, syntax_error, term_index};
+use super::types::{Action, State, SyntaxError};
 use crate::lexer::lexer::Token;
-use crate::utils::types::PhraseType;
+use crate::utils::types::{Span, TokenKind};
 use std::vec::Vec;
 
+#[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub enum CSTNode {
+    Comma {
+        assign: Box<CSTNode>,
+        comma_prime: Option<Box<CSTNode>>,
+        span: Span,
+    },
+    CommaPrime {
+        op: String,
+        assign: Box<CSTNode>,
+        comma_prime: Option<Box<CSTNode>>,
+        span: Span,
+    },
     Assign {
-        logical_or: Box<CSTNode>,
+        conditional: Box<CSTNode>,
         assign_prime: Option<Box<CSTNode>>,
+        span: Span,
     },
     AssignPrime {
         op: String,
-        logical_or: Box<CSTNode>,
+        conditional: Box<CSTNode>,
         assign_prime: Option<Box<CSTNode>>,
+        span: Span,
     },
     UnMatchedStmt {
         while_stmt: Option<String>,
@@ -25,6 +41,52 @@ pub enum CSTNode {
         matched_stmt: Option<Box<CSTNode>>,
         unmatched_stmt: Option<Box<CSTNode>>,
         stmt: Option<Box<CSTNode>>,
+        span: Span,
+    },
+    ForStmt {
+        for_stmt: String,
+        lp: String,
+        for_init: Box<CSTNode>,
+        cond: Box<CSTNode>,
+        sepa: String,
+        step: Box<CSTNode>,
+        rp: String,
+        body: Box<CSTNode>,
+        span: Span,
+    },
+    ForInit {
+        def: Option<Box<CSTNode>>,
+        expression: Option<Box<CSTNode>>,
+        sepa: Option<String>,
+        span: Span,
+    },
+    // for 的条件和步进，省略时为 None
+    ForExpr(Option<Box<CSTNode>>, Span),
+    SwitchStmt {
+        switch_stmt: String,
+        lp: String,
+        expression: Box<CSTNode>,
+        rp: String,
+        body: Box<CSTNode>,
+        span: Span,
+    },
+    DoWhileStmt {
+        do_stmt: String,
+        body: Box<CSTNode>,
+        while_stmt: String,
+        lp: String,
+        expression: Box<CSTNode>,
+        rp: String,
+        sepa: String,
+        span: Span,
+    },
+    // case、default 和 goto 的标号，label 是关键字或者标号的名字，只有 case 有 expression
+    LabeledStmt {
+        label: String,
+        expression: Option<Box<CSTNode>>,
+        colon: String,
+        stmt: Box<CSTNode>,
+        span: Span,
     },
     StructSpecifier {
         struct_type: String,
@@ -32,14 +94,17 @@ pub enum CSTNode {
         lc: Option<String>,
         rc: Option<String>,
         def_list: Option<Box<CSTNode>>,
+        span: Span,
     },
     Specifier {
         specifier_type: Option<String>,
         struct_specifier: Option<Box<CSTNode>>,
+        span: Span,
     },
     ParaDec {
         spec: Box<CSTNode>,
         var_dec: Box<CSTNode>,
+        span: Span,
     },
     MatchedStmt {
         normal_stmt: Option<Box<CSTNode>>,
@@ -51,153 +116,269 @@ pub enum CSTNode {
         expression: Option<Box<CSTNode>>,
         matched_stmt_fore: Option<Box<CSTNode>>,
         matched_stmt_back: Option<Box<CSTNode>>,
+        span: Span,
     },
     StmtList {
         stmt: Box<CSTNode>,
         stmt_list: Option<Box<CSTNode>>,
+        span: Span,
     },
     Stmt {
         unmatched_stmt: Option<Box<CSTNode>>,
         matched_stmt: Option<Box<CSTNode>>,
+        span: Span,
     },
     NormalStmt {
         sepa: Option<String>,
         expression: Option<Box<CSTNode>>,
         compst: Option<Box<CSTNode>>,
         return_stmt: Option<String>,
+        // break、continue 或 goto
+        jump_stmt: Option<String>,
+        // goto 的标号
+        label: Option<String>,
+        span: Span,
     },
-    Program(Box<CSTNode>),
-    Expression(Box<CSTNode>),
+    Program(Box<CSTNode>, Span),
+    Expression(Box<CSTNode>, Span),
     FunCall {
         id: String,
         lp: String,
         arguments: Option<Box<CSTNode>>,
         rp: String,
+        span: Span,
     },
+    // pointers 是返回类型前面 '*' 的个数
     FunDec {
         id: String,
         lp: String,
         var_list: Option<Box<CSTNode>>,
         rp: String,
+        pointers: usize,
+        span: Span,
     },
     VarList {
         para_dec: Box<CSTNode>,
         sepa: Option<String>,
         var_list: Option<Box<CSTNode>>,
+        span: Span,
     },
+    // 指针声明 star 不是 None，数组声明 lt、literal、rt 不是 None
     VarDec {
         id: Option<String>,
+        star: Option<String>,
         var_dec: Option<Box<CSTNode>>,
         lt: Option<String>,
         rt: Option<String>,
         literal: Option<String>,
+        span: Span,
     },
     LogicalOrPrime {
         op: String,
         logical_and: Box<CSTNode>,
         logical_or_prime: Option<Box<CSTNode>>,
+        span: Span,
+    },
+    // 没有 '?' 时 expression 和 conditional 都是 None
+    Conditional {
+        logical_or: Box<CSTNode>,
+        expression: Option<Box<CSTNode>>,
+        conditional: Option<Box<CSTNode>>,
+        span: Span,
     },
     LogicalOr {
         logical_and: Box<CSTNode>,
         logical_or_prime: Option<Box<CSTNode>>,
+        span: Span,
     },
     LogicalAnd {
-        equality: Box<CSTNode>,
+        bit_or: Box<CSTNode>,
         logical_and_prime: Option<Box<CSTNode>>,
+        span: Span,
     },
     LogicalAndPrime {
         op: String,
-        equality: Box<CSTNode>,
+        bit_or: Box<CSTNode>,
         logical_and_prime: Option<Box<CSTNode>>,
+        span: Span,
+    },
+    BitOr {
+        bit_xor: Box<CSTNode>,
+        bit_or_prime: Option<Box<CSTNode>>,
+        span: Span,
+    },
+    BitOrPrime {
+        op: String,
+        bit_xor: Box<CSTNode>,
+        bit_or_prime: Option<Box<CSTNode>>,
+        span: Span,
+    },
+    BitXor {
+        bit_and: Box<CSTNode>,
+        bit_xor_prime: Option<Box<CSTNode>>,
+        span: Span,
+    },
+    BitXorPrime {
+        op: String,
+        bit_and: Box<CSTNode>,
+        bit_xor_prime: Option<Box<CSTNode>>,
+        span: Span,
+    },
+    BitAnd {
+        equality: Box<CSTNode>,
+        bit_and_prime: Option<Box<CSTNode>>,
+        span: Span,
+    },
+    BitAndPrime {
+        op: String,
+        equality: Box<CSTNode>,
+        bit_and_prime: Option<Box<CSTNode>>,
+        span: Span,
     },
     ExtDefList {
         ext_def: Box<CSTNode>,
         ext_def_list: Option<Box<CSTNode>>,
+        span: Span,
     },
     ExtDef {
+        // 写在前面的 extern 和 static，按出现的顺序
+        storage: Vec<String>,
         spec: Box<CSTNode>,
         ext_dec_list: Option<Box<CSTNode>>,
         fun_dec: Option<Box<CSTNode>>,
         compst: Option<Box<CSTNode>>,
         sepa: Option<String>,
+        span: Span,
     },
     ExtDecList {
         var_dec: Box<CSTNode>,
         sepa: Option<String>,
         ext_dec_list: Option<Box<CSTNode>>,
+        span: Span,
     },
     DefList {
         def: Box<CSTNode>,
         def_list: Option<Box<CSTNode>>,
+        span: Span,
     },
     Def {
         spec: Box<CSTNode>,
         dec_list: Box<CSTNode>,
         sepa: String,
+        span: Span,
     },
     DecList {
         dec: Box<CSTNode>,
         sepa: Option<String>,
         dec_list: Option<Box<CSTNode>>,
+        span: Span,
     },
     Dec {
         var_dec: Box<CSTNode>,
         op: Option<String>,
         expression: Option<Box<CSTNode>>,
+        span: Span,
+    },
+    // 花括号里的初始值，元素是表达式或者嵌套的 InitList
+    InitList {
+        items: Vec<CSTNode>,
+        span: Span,
     },
     CompSt {
         lc: String,
         def_list: Option<Box<CSTNode>>,
         stmt_list: Box<CSTNode>,
         rc: String,
+        span: Span,
     },
     Arguments {
         arguments_tail: Option<Box<CSTNode>>,
         expression: Box<CSTNode>,
+        span: Span,
     },
     ArgumentsTail {
         separator: String,
         expression: Box<CSTNode>,
+        span: Span,
     },
     Equality {
         comparison: Box<CSTNode>,
         equality_prime: Option<Box<CSTNode>>,
+        span: Span,
     },
     EqualityPrime {
         op: String,
         comparison: Box<CSTNode>,
         equality_prime: Option<Box<CSTNode>>,
+        span: Span,
     },
     ComparisonPrime {
         op: String,
-        term: Box<CSTNode>,
+        shift: Box<CSTNode>,
         comparison_prime: Option<Box<CSTNode>>,
+        span: Span,
     },
     Comparison {
-        term: Box<CSTNode>,
+        shift: Box<CSTNode>,
         comparison_prime: Option<Box<CSTNode>>,
+        span: Span,
+    },
+    Shift {
+        term: Box<CSTNode>,
+        shift_prime: Option<Box<CSTNode>>,
+        span: Span,
+    },
+    ShiftPrime {
+        op: String,
+        term: Box<CSTNode>,
+        shift_prime: Option<Box<CSTNode>>,
+        span: Span,
     },
     Term {
         factor: Box<CSTNode>,
         term_prime: Option<Box<CSTNode>>,
+        span: Span,
     },
     TermPrime {
         op: String,
         factor: Box<CSTNode>,
         term_prime: Option<Box<CSTNode>>,
+        span: Span,
     },
     Factor {
         unary: Box<CSTNode>,
         factor_prime: Option<Box<CSTNode>>,
+        span: Span,
     },
     FactorPrime {
         op: String,
         unary: Box<CSTNode>,
         factor_prime: Option<Box<CSTNode>>,
+        span: Span,
     },
     Unary {
         op: Option<String>,
         unary: Box<CSTNode>,
+        span: Span,
+    },
+    // 后缀的 ++ 和 --
+    Postfix {
+        primary: Box<CSTNode>,
+        op: String,
+        span: Span,
+    },
+    // 下标 a[i]
+    Index {
+        primary: Box<CSTNode>,
+        expression: Box<CSTNode>,
+        span: Span,
+    },
+    // 成员访问 s.x 和 p->x
+    Member {
+        primary: Box<CSTNode>,
+        op: String,
+        id: String,
+        span: Span,
     },
     Primary {
         symbol: Option<Token>,
@@ -205,28 +386,48 @@ pub enum CSTNode {
         rp: Option<String>,
         expression: Option<Box<CSTNode>>,
         fun_call: Option<Box<CSTNode>>,
+        span: Span,
     },
+    // 出错恢复时跳过的部分
+    Error(Span),
+}
+
+// 状态栈上每个位置对应的符号放在哪个栈里，出错退栈时据此弹出
+#[derive(Clone, Copy)]
+enum Slot {
+    Token,
+    Node,
+    Empty,
 }
 
-pub fn parse(tokens: &Vec<Token>) -> Vec<CSTNode> {
+// 出错后至少要再移进这么多个 token 才报告新的错误，避免一个错误引出一串错误
+const RECOVER_TOKENS: usize = 3;
+
+// 返回语法树和所有语法错误，有错误时树里对应的位置是 Error 节点
+// eof 是输入结束的位置，报告“found end of input”时用
+pub fn parse(tokens: &[Token], eof: Span) -> (Vec<CSTNode>, Vec<SyntaxError>) {
     let mut index: usize = 0;
-    let mut state: Vec<State> = vec![State::S0];
+    let mut state: Vec<State> = vec![State(0)];
     let mut sym: Vec<Token> = vec![];
     let mut cst: Vec<CSTNode> = vec![];
+    // 和 state 栈对齐，S0 对应一个空位置
+    let mut spans: Vec<Span> = vec![Span::default()];
+    let mut slots: Vec<Slot> = vec![Slot::Empty];
+    let mut errors: Vec<SyntaxError> = vec![];
+    let mut recovering: usize = 0;
+    // 输入结束时的 $
+    let eof = Token {
+        span: eof,
+        kind: TokenKind::Eof,
+    };
     loop {
-        let look = tokens.get(index);
-        let col = if let Some(tok) = look {
-            super::helper::term_index(tok)
-        } else {
-            32
-        };
+        let token = tokens.get(index).unwrap_or(&eof);
         let st = state.last().unwrap().to_index();
-        let action = &super::constant::ACTION[st][col];
-        let token = tokens.get(index).cloned().unwrap_or(Token {
-            pos: 0,
-            types: PhraseType::Separator,
-            value: String::from("$"),
-        });
+        // 文法里没有的 token 直接按出错处理
+        let action = match term_index(token) {
+            Some(col) => &ACTION[st][col],
+            None => &Action::Error,
+        };
         match action {
             Action::Shift(ns) => {
                 let symbol = token.clone();
@@ -237,197 +438,240 @@ pub fn parse(tokens: &Vec<Token>) -> Vec<CSTNode> {
                 //     look
                 // );
                 state.push(*ns);
+                spans.push(symbol.span);
+                slots.push(Slot::Token);
                 sym.push(symbol);
                 index += 1;
+                recovering = recovering.saturating_sub(1);
             }
-            Action::Reduce(rule) => {
-                let (lhs, rhs_len) = match rule {
-                    0 => (NonTerm::Accept, 0),
-                    1 => {
+            Action::Reduce(prod) => {
+                let rhs_len = prod.rhs_len();
+                let span = reduce_span(&spans, rhs_len);
+                match prod {
+                    Prod::Arguments => {
                         let arguments_tail = cst.pop().unwrap();
                         let expression = cst.pop().unwrap();
                         let node = CSTNode::Arguments {
+                            span,
                             arguments_tail: Some(Box::new(arguments_tail)),
                             expression: Box::new(expression),
                         };
                         cst.push(node);
-                        (NonTerm::Arguments, 2)
                     }
-                    2 => {
+                    Prod::ArgumentsLast => {
                         let expression = cst.pop().unwrap();
                         let node = CSTNode::Arguments {
+                            span,
                             arguments_tail: None,
                             expression: Box::new(expression),
                         };
                         cst.push(node);
-                        (NonTerm::Arguments, 1)
                     }
-                    3 => {
+                    Prod::ArgumentsTail => {
                         let sepa = sym.pop().unwrap();
                         let expression = cst.pop().unwrap();
                         let node = CSTNode::ArgumentsTail {
-                            separator: sepa.value,
+                            span,
+                            separator: sepa.to_string(),
                             expression: Box::new(expression),
                         };
                         cst.push(node);
-                        (NonTerm::ArgumentsTail, 2)
                     }
-                    4 => {
+                    Prod::CompSt => {
                         let rc = sym.pop().unwrap();
                         let lc = sym.pop().unwrap();
                         let stmt_list = cst.pop().unwrap();
                         let def_list = cst.pop().unwrap();
                         let node = CSTNode::CompSt {
-                            lc: lc.value,
+                            span,
+                            lc: lc.to_string(),
                             def_list: Some(Box::new(def_list)),
                             stmt_list: Box::new(stmt_list),
-                            rc: rc.value,
+                            rc: rc.to_string(),
                         };
                         cst.push(node);
-                        (NonTerm::CompSt, 4)
                     }
-                    5 => {
+                    Prod::CompStNoDefs => {
                         let rc = sym.pop().unwrap();
                         let lc = sym.pop().unwrap();
                         let stmt_list = cst.pop().unwrap();
                         let node = CSTNode::CompSt {
-                            lc: lc.value,
+                            span,
+                            lc: lc.to_string(),
                             def_list: None,
                             stmt_list: Box::new(stmt_list),
-                            rc: rc.value,
+                            rc: rc.to_string(),
                         };
                         cst.push(node);
-                        (NonTerm::CompSt, 3)
                     }
-                    6 => {
+                    Prod::Dec => {
                         let var_dec = cst.pop().unwrap();
                         let node = CSTNode::Dec {
+                            span,
                             var_dec: Box::new(var_dec),
                             op: None,
                             expression: None,
                         };
                         cst.push(node);
-                        (NonTerm::Dec, 1)
                     }
-                    7 => {
+                    Prod::DecInit => {
                         let expr = cst.pop().unwrap();
                         let var_dec = cst.pop().unwrap();
                         let op = sym.pop().unwrap();
                         let node = CSTNode::Dec {
+                            span,
                             var_dec: Box::new(var_dec),
-                            op: Some(op.value),
+                            op: Some(op.to_string()),
                             expression: Some(Box::new(expr)),
                         };
                         cst.push(node);
-                        (NonTerm::Dec, 3)
                     }
-                    8 => {
+                    // 初始值就是赋值表达式本身
+                    Prod::Initializer => {}
+                    Prod::InitializerList | Prod::InitializerListComma => {
+                        // 去掉花括号和最后多出来的逗号，span 把花括号也算上
+                        sym.pop();
+                        if *prod == Prod::InitializerListComma {
+                            sym.pop();
+                        }
+                        sym.pop();
+                        if let Some(CSTNode::InitList {
+                            span: list_span, ..
+                        }) = cst.last_mut()
+                        {
+                            *list_span = span;
+                        }
+                    }
+                    Prod::InitListFirst => {
+                        let item = cst.pop().unwrap();
+                        cst.push(CSTNode::InitList {
+                            items: vec![item],
+                            span,
+                        });
+                    }
+                    Prod::InitList => {
+                        let item = cst.pop().unwrap();
+                        sym.pop();
+                        if let Some(CSTNode::InitList {
+                            items,
+                            span: list_span,
+                        }) = cst.last_mut()
+                        {
+                            items.push(item);
+                            *list_span = span;
+                        }
+                    }
+                    Prod::DecListLast => {
                         let dec = cst.pop().unwrap();
                         let node = CSTNode::DecList {
+                            span,
                             dec: Box::new(dec),
                             sepa: None,
                             dec_list: None,
                         };
                         cst.push(node);
-                        (NonTerm::DecList, 1)
                     }
-                    9 => {
+                    Prod::DecList => {
                         let dec_list = cst.pop().unwrap();
                         let dec = cst.pop().unwrap();
                         let sepa = sym.pop().unwrap();
                         let node = CSTNode::DecList {
+                            span,
                             dec: Box::new(dec),
-                            sepa: Some(sepa.value),
+                            sepa: Some(sepa.to_string()),
                             dec_list: Some(Box::new(dec_list)),
                         };
                         cst.push(node);
-                        (NonTerm::DecList, 3)
                     }
-                    10 => {
+                    Prod::Def => {
                         let sepa = sym.pop().unwrap();
                         let dec_list = cst.pop().unwrap();
                         let spec = cst.pop().unwrap();
                         let node = CSTNode::Def {
+                            span,
                             spec: Box::new(spec),
                             dec_list: Box::new(dec_list),
-                            sepa: sepa.value,
+                            sepa: sepa.to_string(),
                         };
                         cst.push(node);
-                        (NonTerm::Def, 3)
                     }
-                    11 => {
+                    Prod::DefList => {
                         let def_list = cst.pop().unwrap();
                         let def = cst.pop().unwrap();
                         let node = CSTNode::DefList {
+                            span,
                             def: Box::new(def),
                             def_list: Some(Box::new(def_list)),
                         };
                         cst.push(node);
-                        (NonTerm::DefList, 2)
                     }
-                    12 => {
+                    Prod::DefListLast => {
                         let def = cst.pop().unwrap();
                         let node = CSTNode::DefList {
+                            span,
                             def: Box::new(def),
                             def_list: None,
                         };
                         cst.push(node);
-                        (NonTerm::DefList, 1)
                     }
-                    13 => {
+                    Prod::ExtDecListLast => {
                         let var_dec = cst.pop().unwrap();
                         let node = CSTNode::ExtDecList {
+                            span,
                             var_dec: Box::new(var_dec),
                             sepa: None,
                             ext_dec_list: None,
                         };
                         cst.push(node);
-                        (NonTerm::ExtDecList, 1)
                     }
-                    14 => {
+                    Prod::ExtDecList => {
                         let ext_dec_list = cst.pop().unwrap();
                         let var_dec = cst.pop().unwrap();
                         let sepa = sym.pop().unwrap();
                         let node = CSTNode::ExtDecList {
+                            span,
                             var_dec: Box::new(var_dec),
-                            sepa: Some(sepa.value),
+                            sepa: Some(sepa.to_string()),
                             ext_dec_list: Some(Box::new(ext_dec_list)),
                         };
                         cst.push(node);
-                        (NonTerm::ExtDecList, 3)
                     }
-                    15 => {
+                    Prod::ExtDefVars => {
                         let ext_dec_list = cst.pop().unwrap();
                         let spec = cst.pop().unwrap();
                         let sepa = sym.pop().unwrap();
                         let node = CSTNode::ExtDef {
+                            span,
+                            storage: vec![],
                             spec: Box::new(spec),
                             ext_dec_list: Some(Box::new(ext_dec_list)),
                             fun_dec: None,
                             compst: None,
-                            sepa: Some(sepa.value),
+                            sepa: Some(sepa.to_string()),
                         };
                         cst.push(node);
-                        (NonTerm::ExtDef, 3)
                     }
-                    16 => {
+                    Prod::ExtDefSpec => {
                         let spec = cst.pop().unwrap();
                         let sepa = sym.pop().unwrap();
                         let node = CSTNode::ExtDef {
+                            span,
+                            storage: vec![],
                             spec: Box::new(spec),
                             ext_dec_list: None,
                             fun_dec: None,
                             compst: None,
-                            sepa: Some(sepa.value),
+                            sepa: Some(sepa.to_string()),
                         };
                         cst.push(node);
-                        (NonTerm::ExtDef, 2)
                     }
-                    17 => {
+                    Prod::ExtDefFun => {
                         let compst = cst.pop().unwrap();
                         let fun_dec = cst.pop().unwrap();
                         let spec = cst.pop().unwrap();
                         let node = CSTNode::ExtDef {
+                            span,
+                            storage: vec![],
                             spec: Box::new(spec),
                             ext_dec_list: None,
                             fun_dec: Some(Box::new(fun_dec)),
@@ -435,84 +679,126 @@ pub fn parse(tokens: &Vec<Token>) -> Vec<CSTNode> {
                             sepa: None,
                         };
                         cst.push(node);
-                        (NonTerm::ExtDef, 3)
                     }
-                    18 => {
+                    Prod::ExtDefProto => {
+                        let sepa = sym.pop().unwrap();
+                        let fun_dec = cst.pop().unwrap();
+                        let spec = cst.pop().unwrap();
+                        let node = CSTNode::ExtDef {
+                            span,
+                            storage: vec![],
+                            spec: Box::new(spec),
+                            ext_dec_list: None,
+                            fun_dec: Some(Box::new(fun_dec)),
+                            compst: None,
+                            sepa: Some(sepa.to_string()),
+                        };
+                        cst.push(node);
+                    }
+                    // 出错恢复得到的 ExtDef 没有地方记存储类，直接丢掉
+                    Prod::ExtDefStorage => {
+                        let keyword = sym.pop().unwrap();
+                        if let Some(CSTNode::ExtDef {
+                            storage,
+                            span: def_span,
+                            ..
+                        }) = cst.last_mut()
+                        {
+                            storage.insert(0, keyword.to_string());
+                            *def_span = span;
+                        }
+                    }
+                    Prod::ExtDefList => {
                         let ext_def_list = cst.pop().unwrap();
                         let ext_def = cst.pop().unwrap();
                         let node = CSTNode::ExtDefList {
+                            span,
                             ext_def: Box::new(ext_def),
                             ext_def_list: Some(Box::new(ext_def_list)),
                         };
                         cst.push(node);
-                        (NonTerm::ExtDefList, 2)
                     }
-                    19 => {
+                    Prod::ExtDefListLast => {
                         let ext_def = cst.pop().unwrap();
                         let node = CSTNode::ExtDefList {
+                            span,
                             ext_def: Box::new(ext_def),
                             ext_def_list: None,
                         };
                         cst.push(node);
-                        (NonTerm::ExtDefList, 1)
                     }
-                    20 => {
+                    Prod::FunCall => {
                         let arguments = cst.pop().unwrap();
                         let rp = sym.pop().unwrap();
                         let lp = sym.pop().unwrap();
                         let id = sym.pop().unwrap();
                         let node = CSTNode::FunCall {
-                            id: id.value,
-                            lp: lp.value,
+                            span,
+                            id: id.to_string(),
+                            lp: lp.to_string(),
                             arguments: Some(Box::new(arguments)),
-                            rp: rp.value,
+                            rp: rp.to_string(),
                         };
                         cst.push(node);
-                        (NonTerm::FunCall, 4)
                     }
-                    21 => {
+                    Prod::FunCallNoArgs => {
                         let rp = sym.pop().unwrap();
                         let lp = sym.pop().unwrap();
                         let id = sym.pop().unwrap();
                         let node = CSTNode::FunCall {
-                            id: id.value,
-                            lp: lp.value,
+                            span,
+                            id: id.to_string(),
+                            lp: lp.to_string(),
                             arguments: None,
-                            rp: rp.value,
+                            rp: rp.to_string(),
                         };
                         cst.push(node);
-                        (NonTerm::FunCall, 3)
                     }
-                    22 => {
+                    Prod::FunDec => {
                         let var_list = cst.pop().unwrap();
                         let rp = sym.pop().unwrap();
                         let lp = sym.pop().unwrap();
                         let id = sym.pop().unwrap();
                         let node = CSTNode::FunDec {
-                            id: id.value,
-                            lp: lp.value,
+                            span,
+                            id: id.to_string(),
+                            lp: lp.to_string(),
                             var_list: Some(Box::new(var_list)),
-                            rp: rp.value,
+                            rp: rp.to_string(),
+                            pointers: 0,
                         };
                         cst.push(node);
-                        (NonTerm::FunDec, 4)
                     }
-                    23 => {
+                    Prod::FunDecNoParams => {
                         let rp = sym.pop().unwrap();
                         let lp = sym.pop().unwrap();
                         let id = sym.pop().unwrap();
                         let node = CSTNode::FunDec {
-                            id: id.value,
-                            lp: lp.value,
+                            span,
+                            id: id.to_string(),
+                            lp: lp.to_string(),
                             var_list: None,
-                            rp: rp.value,
+                            rp: rp.to_string(),
+                            pointers: 0,
                         };
                         cst.push(node);
-                        (NonTerm::FunDec, 3)
                     }
-                    24 => {
+                    Prod::FunDecPointer => {
+                        sym.pop();
+                        if let Some(CSTNode::FunDec {
+                            pointers,
+                            span: fun_span,
+                            ..
+                        }) = cst.last_mut()
+                        {
+                            *pointers += 1;
+                            *fun_span = span;
+                        }
+                    }
+                    Prod::MatchedNormal => {
                         let normal_stmt = cst.pop().unwrap();
                         let node = CSTNode::MatchedStmt {
+                            span,
                             normal_stmt: Some(Box::new(normal_stmt)),
                             while_stmt: None,
                             if_stmt: None,
@@ -524,19 +810,19 @@ pub fn parse(tokens: &Vec<Token>) -> Vec<CSTNode> {
                             rp: None,
                         };
                         cst.push(node);
-                        (NonTerm::MatchedStmt, 1)
                     }
-                    25 => {
+                    Prod::MatchedWhile => {
                         let rp = sym.pop().unwrap();
                         let lp = sym.pop().unwrap();
                         let while_stmt = sym.pop().unwrap();
                         let matched_stmt = cst.pop().unwrap();
                         let expression = cst.pop().unwrap();
                         let node = CSTNode::MatchedStmt {
+                            span,
                             normal_stmt: None,
-                            while_stmt: Some(while_stmt.value),
-                            lp: Some(lp.value),
-                            rp: Some(rp.value),
+                            while_stmt: Some(while_stmt.to_string()),
+                            lp: Some(lp.to_string()),
+                            rp: Some(rp.to_string()),
                             expression: Some(Box::new(expression)),
                             matched_stmt_fore: Some(Box::new(matched_stmt)),
                             matched_stmt_back: None,
@@ -544,9 +830,8 @@ pub fn parse(tokens: &Vec<Token>) -> Vec<CSTNode> {
                             else_stmt: None,
                         };
                         cst.push(node);
-                        (NonTerm::MatchedStmt, 5)
                     }
-                    26 => {
+                    Prod::MatchedIfElse => {
                         let else_stmt = sym.pop().unwrap();
                         let rp = sym.pop().unwrap();
                         let lp = sym.pop().unwrap();
@@ -555,191 +840,281 @@ pub fn parse(tokens: &Vec<Token>) -> Vec<CSTNode> {
                         let matched_stmt_fore = cst.pop().unwrap();
                         let expression = cst.pop().unwrap();
                         let node = CSTNode::MatchedStmt {
+                            span,
                             normal_stmt: None,
                             while_stmt: None,
-                            lp: Some(lp.value),
-                            rp: Some(rp.value),
+                            lp: Some(lp.to_string()),
+                            rp: Some(rp.to_string()),
                             expression: Some(Box::new(expression)),
                             matched_stmt_fore: Some(Box::new(matched_stmt_fore)),
                             matched_stmt_back: Some(Box::new(matched_stmt_back)),
-                            if_stmt: Some(if_stmt.value),
-                            else_stmt: Some(else_stmt.value),
+                            if_stmt: Some(if_stmt.to_string()),
+                            else_stmt: Some(else_stmt.to_string()),
                         };
                         cst.push(node);
-                        (NonTerm::MatchedStmt, 7)
                     }
-                    27 => {
+                    Prod::NormalExpr => {
                         let sepa = sym.pop().unwrap();
                         let expression = cst.pop().unwrap();
                         let node = CSTNode::NormalStmt {
+                            span,
                             expression: Some(Box::new(expression)),
-                            sepa: Some(sepa.value),
+                            sepa: Some(sepa.to_string()),
                             compst: None,
                             return_stmt: None,
+                            jump_stmt: None,
+                            label: None,
                         };
                         cst.push(node);
-                        (NonTerm::NormalStmt, 2)
                     }
-                    28 => {
+                    Prod::NormalCompSt => {
                         let compst = cst.pop().unwrap();
                         let node = CSTNode::NormalStmt {
+                            span,
                             compst: Some(Box::new(compst)),
                             return_stmt: None,
+                            jump_stmt: None,
+                            label: None,
                             sepa: None,
                             expression: None,
                         };
                         cst.push(node);
-                        (NonTerm::NormalStmt, 1)
                     }
-                    29 => {
+                    Prod::NormalReturn => {
                         let sepa = sym.pop().unwrap();
                         let return_stmt = sym.pop().unwrap();
                         let expression = cst.pop().unwrap();
                         let node = CSTNode::NormalStmt {
-                            return_stmt: Some(return_stmt.value),
+                            span,
+                            return_stmt: Some(return_stmt.to_string()),
+                            jump_stmt: None,
+                            label: None,
                             expression: Some(Box::new(expression)),
-                            sepa: Some(sepa.value),
+                            sepa: Some(sepa.to_string()),
+                            compst: None,
+                        };
+                        cst.push(node);
+                    }
+                    Prod::NormalBreak | Prod::NormalContinue => {
+                        let sepa = sym.pop().unwrap();
+                        let jump_stmt = sym.pop().unwrap();
+                        let node = CSTNode::NormalStmt {
+                            span,
+                            return_stmt: None,
+                            jump_stmt: Some(jump_stmt.to_string()),
+                            label: None,
+                            expression: None,
+                            sepa: Some(sepa.to_string()),
+                            compst: None,
+                        };
+                        cst.push(node);
+                    }
+                    Prod::NormalGoto => {
+                        let sepa = sym.pop().unwrap();
+                        let label = sym.pop().unwrap();
+                        let jump_stmt = sym.pop().unwrap();
+                        let node = CSTNode::NormalStmt {
+                            span,
+                            return_stmt: None,
+                            jump_stmt: Some(jump_stmt.to_string()),
+                            label: Some(label.to_string()),
+                            expression: None,
+                            sepa: Some(sepa.to_string()),
                             compst: None,
                         };
                         cst.push(node);
-                        (NonTerm::NormalStmt, 3)
                     }
-                    30 => {
+                    Prod::NormalDoWhile => {
+                        let sepa = sym.pop().unwrap();
+                        let rp = sym.pop().unwrap();
+                        let lp = sym.pop().unwrap();
+                        let while_stmt = sym.pop().unwrap();
+                        let do_stmt = sym.pop().unwrap();
+                        let expression = cst.pop().unwrap();
+                        let body = cst.pop().unwrap();
+                        let node = CSTNode::DoWhileStmt {
+                            span,
+                            do_stmt: do_stmt.to_string(),
+                            body: Box::new(body),
+                            while_stmt: while_stmt.to_string(),
+                            lp: lp.to_string(),
+                            expression: Box::new(expression),
+                            rp: rp.to_string(),
+                            sepa: sepa.to_string(),
+                        };
+                        cst.push(node);
+                    }
+                    // 没有名字的声明符也用 VarDec 表示，id 为空
+                    Prod::ParaDec | Prod::ParaDecAbstract => {
                         let var_dec = cst.pop().unwrap();
                         let spec = cst.pop().unwrap();
                         let node = CSTNode::ParaDec {
+                            span,
                             var_dec: Box::new(var_dec),
                             spec: Box::new(spec),
                         };
                         cst.push(node);
-                        (NonTerm::ParaDec, 2)
                     }
-                    31 => {
+                    Prod::ParaDecNoName => {
+                        let spec = cst.pop().unwrap();
+                        let node = CSTNode::ParaDec {
+                            span,
+                            var_dec: Box::new(CSTNode::VarDec {
+                                span,
+                                id: None,
+                                star: None,
+                                var_dec: None,
+                                lt: None,
+                                rt: None,
+                                literal: None,
+                            }),
+                            spec: Box::new(spec),
+                        };
+                        cst.push(node);
+                    }
+                    Prod::AbstractDec | Prod::AbstractDecLast => {
+                        let var_dec = if *prod == Prod::AbstractDec {
+                            Some(Box::new(cst.pop().unwrap()))
+                        } else {
+                            None
+                        };
+                        let star = sym.pop().unwrap();
+                        let node = CSTNode::VarDec {
+                            span,
+                            id: None,
+                            star: Some(star.to_string()),
+                            var_dec,
+                            lt: None,
+                            rt: None,
+                            literal: None,
+                        };
+                        cst.push(node);
+                    }
+                    Prod::Program => {
                         let ext_def_list = cst.pop().unwrap();
-                        let node = CSTNode::Program(Box::new(ext_def_list));
+                        let node = CSTNode::Program(Box::new(ext_def_list), span);
                         cst.push(node);
-                        (NonTerm::Program, 1)
                     }
-                    32 => {
+                    Prod::SpecifierType => {
                         let specifier_type = sym.pop().unwrap();
                         let node = CSTNode::Specifier {
-                            specifier_type: Some(specifier_type.value),
+                            span,
+                            specifier_type: Some(specifier_type.to_string()),
                             struct_specifier: None,
                         };
                         cst.push(node);
-                        (NonTerm::Specifier, 1)
                     }
-                    33 => {
+                    Prod::SpecifierStruct => {
                         let struct_specifier = cst.pop().unwrap();
                         let node = CSTNode::Specifier {
+                            span,
                             struct_specifier: Some(Box::new(struct_specifier)),
                             specifier_type: None,
                         };
                         cst.push(node);
-                        (NonTerm::Specifier, 1)
                     }
-                    34 => {
+                    Prod::StmtMatched => {
                         let matched_stmt = cst.pop().unwrap();
                         let node = CSTNode::Stmt {
+                            span,
                             matched_stmt: Some(Box::new(matched_stmt)),
                             unmatched_stmt: None,
                         };
                         cst.push(node);
-                        (NonTerm::Stmt, 1)
                     }
-                    35 => {
+                    Prod::StmtUnmatched => {
                         let unmatched_stmt = cst.pop().unwrap();
                         let node = CSTNode::Stmt {
+                            span,
                             unmatched_stmt: Some(Box::new(unmatched_stmt)),
                             matched_stmt: None,
                         };
                         cst.push(node);
-                        (NonTerm::Stmt, 1)
                     }
-                    36 => {
+                    Prod::StmtList => {
                         let stmt_list = cst.pop().unwrap();
                         let stmt = cst.pop().unwrap();
                         let node = CSTNode::StmtList {
+                            span,
                             stmt: Box::new(stmt),
                             stmt_list: Some(Box::new(stmt_list)),
                         };
                         cst.push(node);
-                        (NonTerm::StmtList, 2)
                     }
-                    37 => {
+                    Prod::StmtListLast => {
                         let stmt = cst.pop().unwrap();
                         let node = CSTNode::StmtList {
+                            span,
                             stmt: Box::new(stmt),
                             stmt_list: None,
                         };
                         cst.push(node);
-                        (NonTerm::StmtList, 1)
                     }
-                    38 => {
+                    Prod::StructDef => {
                         let rc = sym.pop().unwrap();
                         let lc = sym.pop().unwrap();
                         let id = sym.pop().unwrap();
                         let struct_type = sym.pop().unwrap();
                         let def_list = cst.pop().unwrap();
                         let node = CSTNode::StructSpecifier {
-                            struct_type: struct_type.value,
-                            id: Some(id.value),
-                            lc: Some(lc.value),
-                            rc: Some(rc.value),
+                            span,
+                            struct_type: struct_type.to_string(),
+                            id: Some(id.to_string()),
+                            lc: Some(lc.to_string()),
+                            rc: Some(rc.to_string()),
                             def_list: Some(Box::new(def_list)),
                         };
                         cst.push(node);
-                        (NonTerm::StructSpecifier, 5)
                     }
-                    39 => {
+                    Prod::StructDefAnon => {
                         let rc = sym.pop().unwrap();
                         let lc = sym.pop().unwrap();
                         let struct_type = sym.pop().unwrap();
                         let def_list = cst.pop().unwrap();
                         let node = CSTNode::StructSpecifier {
-                            struct_type: struct_type.value,
+                            span,
+                            struct_type: struct_type.to_string(),
                             id: None,
-                            lc: Some(lc.value),
-                            rc: Some(rc.value),
+                            lc: Some(lc.to_string()),
+                            rc: Some(rc.to_string()),
                             def_list: Some(Box::new(def_list)),
                         };
                         cst.push(node);
-                        (NonTerm::StructSpecifier, 4)
                     }
-                    40 => {
+                    Prod::StructDecl => {
                         let id = sym.pop().unwrap();
                         let struct_type = sym.pop().unwrap();
                         let node = CSTNode::StructSpecifier {
-                            struct_type: struct_type.value,
-                            id: Some(id.value),
+                            span,
+                            struct_type: struct_type.to_string(),
+                            id: Some(id.to_string()),
                             lc: None,
                             rc: None,
                             def_list: None,
                         };
                         cst.push(node);
-                        (NonTerm::StructSpecifier, 2)
                     }
-                    41 => {
+                    Prod::UnmatchedIf => {
                         let rp = sym.pop().unwrap();
                         let lp = sym.pop().unwrap();
                         let if_stmt = sym.pop().unwrap();
                         let stmt = cst.pop().unwrap();
                         let expression = cst.pop().unwrap();
                         let node = CSTNode::UnMatchedStmt {
+                            span,
                             while_stmt: None,
-                            lp: Some(lp.value),
+                            lp: Some(lp.to_string()),
                             expression: Some(Box::new(expression)),
-                            rp: Some(rp.value),
+                            rp: Some(rp.to_string()),
                             unmatched_stmt: None,
-                            if_stmt: Some(if_stmt.value),
+                            if_stmt: Some(if_stmt.to_string()),
                             else_stmt: None,
                             stmt: Some(Box::new(stmt)),
                             matched_stmt: None,
                         };
                         cst.push(node);
-                        (NonTerm::UnMatchedStmt, 5)
                     }
-                    42 => {
+                    Prod::UnmatchedIfElse => {
                         let else_stmt = sym.pop().unwrap();
                         let rp = sym.pop().unwrap();
                         let lp = sym.pop().unwrap();
@@ -748,30 +1123,31 @@ pub fn parse(tokens: &Vec<Token>) -> Vec<CSTNode> {
                         let matched_stmt = cst.pop().unwrap();
                         let expression = cst.pop().unwrap();
                         let node = CSTNode::UnMatchedStmt {
+                            span,
                             while_stmt: None,
-                            lp: Some(lp.value),
+                            lp: Some(lp.to_string()),
                             expression: Some(Box::new(expression)),
-                            rp: Some(rp.value),
+                            rp: Some(rp.to_string()),
                             unmatched_stmt: Some(Box::new(unmatched_stmt)),
-                            if_stmt: Some(if_stmt.value),
-                            else_stmt: Some(else_stmt.value),
+                            if_stmt: Some(if_stmt.to_string()),
+                            else_stmt: Some(else_stmt.to_string()),
                             stmt: None,
                             matched_stmt: Some(Box::new(matched_stmt)),
                         };
                         cst.push(node);
-                        (NonTerm::UnMatchedStmt, 7)
                     }
-                    43 => {
+                    Prod::UnmatchedWhile => {
                         let rp = sym.pop().unwrap();
                         let lp = sym.pop().unwrap();
                         let while_stmt = sym.pop().unwrap();
                         let unmatched_stmt = cst.pop().unwrap();
                         let expression = cst.pop().unwrap();
                         let node = CSTNode::UnMatchedStmt {
-                            while_stmt: Some(while_stmt.value),
-                            lp: Some(lp.value),
+                            span,
+                            while_stmt: Some(while_stmt.to_string()),
+                            lp: Some(lp.to_string()),
                             expression: Some(Box::new(expression)),
-                            rp: Some(rp.value),
+                            rp: Some(rp.to_string()),
                             unmatched_stmt: Some(Box::new(unmatched_stmt)),
                             if_stmt: None,
                             else_stmt: None,
@@ -779,318 +1155,710 @@ pub fn parse(tokens: &Vec<Token>) -> Vec<CSTNode> {
                             matched_stmt: None,
                         };
                         cst.push(node);
-                        (NonTerm::UnMatchedStmt, 5)
                     }
-                    44 => {
+                    // 循环体是 MatchedStmt 还是 UnMatchedStmt 只影响 else 的配对
+                    Prod::MatchedFor | Prod::UnmatchedFor => {
+                        let rp = sym.pop().unwrap();
+                        let sepa = sym.pop().unwrap();
+                        let lp = sym.pop().unwrap();
+                        let for_stmt = sym.pop().unwrap();
+                        let body = cst.pop().unwrap();
+                        let step = cst.pop().unwrap();
+                        let cond = cst.pop().unwrap();
+                        let for_init = cst.pop().unwrap();
+                        let node = CSTNode::ForStmt {
+                            span,
+                            for_stmt: for_stmt.to_string(),
+                            lp: lp.to_string(),
+                            for_init: Box::new(for_init),
+                            cond: Box::new(cond),
+                            sepa: sepa.to_string(),
+                            step: Box::new(step),
+                            rp: rp.to_string(),
+                            body: Box::new(body),
+                        };
+                        cst.push(node);
+                    }
+                    Prod::MatchedSwitch | Prod::UnmatchedSwitch => {
+                        let rp = sym.pop().unwrap();
+                        let lp = sym.pop().unwrap();
+                        let switch_stmt = sym.pop().unwrap();
+                        let body = cst.pop().unwrap();
+                        let expression = cst.pop().unwrap();
+                        let node = CSTNode::SwitchStmt {
+                            span,
+                            switch_stmt: switch_stmt.to_string(),
+                            lp: lp.to_string(),
+                            expression: Box::new(expression),
+                            rp: rp.to_string(),
+                            body: Box::new(body),
+                        };
+                        cst.push(node);
+                    }
+                    Prod::MatchedCase | Prod::UnmatchedCase => {
+                        let colon = sym.pop().unwrap();
+                        let label = sym.pop().unwrap();
+                        let stmt = cst.pop().unwrap();
+                        let expression = cst.pop().unwrap();
+                        let node = CSTNode::LabeledStmt {
+                            span,
+                            label: label.to_string(),
+                            expression: Some(Box::new(expression)),
+                            colon: colon.to_string(),
+                            stmt: Box::new(stmt),
+                        };
+                        cst.push(node);
+                    }
+                    Prod::MatchedLabel | Prod::UnmatchedLabel => {
+                        let colon = sym.pop().unwrap();
+                        let label = sym.pop().unwrap();
+                        let stmt = cst.pop().unwrap();
+                        let node = CSTNode::LabeledStmt {
+                            span,
+                            label: label.to_string(),
+                            expression: None,
+                            colon: colon.to_string(),
+                            stmt: Box::new(stmt),
+                        };
+                        cst.push(node);
+                    }
+                    Prod::MatchedDefault | Prod::UnmatchedDefault => {
+                        let colon = sym.pop().unwrap();
+                        let label = sym.pop().unwrap();
+                        let stmt = cst.pop().unwrap();
+                        let node = CSTNode::LabeledStmt {
+                            span,
+                            label: label.to_string(),
+                            expression: None,
+                            colon: colon.to_string(),
+                            stmt: Box::new(stmt),
+                        };
+                        cst.push(node);
+                    }
+                    Prod::ForInitDef => {
+                        let sepa = sym.pop().unwrap();
+                        let dec_list = cst.pop().unwrap();
+                        let spec = cst.pop().unwrap();
+                        let def = CSTNode::Def {
+                            span,
+                            spec: Box::new(spec),
+                            dec_list: Box::new(dec_list),
+                            sepa: sepa.to_string(),
+                        };
+                        let node = CSTNode::ForInit {
+                            span,
+                            def: Some(Box::new(def)),
+                            expression: None,
+                            sepa: None,
+                        };
+                        cst.push(node);
+                    }
+                    Prod::ForInitExpr => {
+                        let sepa = sym.pop().unwrap();
+                        let expression = cst.pop().unwrap();
+                        let node = CSTNode::ForInit {
+                            span,
+                            def: None,
+                            expression: Some(Box::new(expression)),
+                            sepa: Some(sepa.to_string()),
+                        };
+                        cst.push(node);
+                    }
+                    Prod::ForInitEmpty => {
+                        let sepa = sym.pop().unwrap();
+                        let node = CSTNode::ForInit {
+                            span,
+                            def: None,
+                            expression: None,
+                            sepa: Some(sepa.to_string()),
+                        };
+                        cst.push(node);
+                    }
+                    Prod::ForExpr => {
+                        let expression = cst.pop().unwrap();
+                        cst.push(CSTNode::ForExpr(Some(Box::new(expression)), span));
+                    }
+                    Prod::ForExprEmpty => {
+                        cst.push(CSTNode::ForExpr(None, span));
+                    }
+                    Prod::VarDecPointer => {
+                        let star = sym.pop().unwrap();
+                        let var_dec = cst.pop().unwrap();
+                        let node = CSTNode::VarDec {
+                            span,
+                            id: None,
+                            star: Some(star.to_string()),
+                            var_dec: Some(Box::new(var_dec)),
+                            lt: None,
+                            rt: None,
+                            literal: None,
+                        };
+                        cst.push(node);
+                    }
+                    // DirectDec 直接当 VarDec 用
+                    Prod::VarDecDirect => {}
+                    Prod::VarDecId => {
                         let id = sym.pop().unwrap();
                         let node = CSTNode::VarDec {
-                            id: Some(id.value),
+                            span,
+                            id: Some(id.to_string()),
+                            star: None,
                             var_dec: None,
                             lt: None,
                             rt: None,
                             literal: None,
                         };
                         cst.push(node);
-                        (NonTerm::VarDec, 1)
                     }
-                    45 => {
+                    Prod::VarDecArray => {
                         let rt = sym.pop().unwrap();
                         let literal = sym.pop().unwrap();
                         let lt = sym.pop().unwrap();
                         let var_dec = cst.pop().unwrap();
                         let node = CSTNode::VarDec {
+                            span,
+                            id: None,
+                            star: None,
+                            var_dec: Some(Box::new(var_dec)),
+                            lt: Some(lt.to_string()),
+                            rt: Some(rt.to_string()),
+                            literal: Some(literal.to_string()),
+                        };
+                        cst.push(node);
+                    }
+                    Prod::VarDecArrayUnsized => {
+                        let rt = sym.pop().unwrap();
+                        let lt = sym.pop().unwrap();
+                        let var_dec = cst.pop().unwrap();
+                        let node = CSTNode::VarDec {
+                            span,
                             id: None,
+                            star: None,
                             var_dec: Some(Box::new(var_dec)),
-                            lt: Some(lt.value),
-                            rt: Some(rt.value),
-                            literal: Some(literal.value),
+                            lt: Some(lt.to_string()),
+                            rt: Some(rt.to_string()),
+                            literal: None,
                         };
                         cst.push(node);
-                        (NonTerm::VarDec, 4)
                     }
-                    46 => {
+                    Prod::VarList => {
                         let var_list = cst.pop().unwrap();
                         let para_dec = cst.pop().unwrap();
                         let sepa = sym.pop().unwrap();
                         let node = CSTNode::VarList {
+                            span,
                             para_dec: Box::new(para_dec),
-                            sepa: Some(sepa.value),
+                            sepa: Some(sepa.to_string()),
                             var_list: Some(Box::new(var_list)),
                         };
                         cst.push(node);
-                        (NonTerm::VarList, 3)
                     }
-                    47 => {
+                    Prod::VarListLast => {
                         let para_dec = cst.pop().unwrap();
                         let node = CSTNode::VarList {
+                            span,
                             para_dec: Box::new(para_dec),
                             sepa: None,
                             var_list: None,
                         };
                         cst.push(node);
-                        (NonTerm::VarList, 1)
                     }
-                    48 => {
+                    Prod::CommaChain => {
+                        let comma_prime = cst.pop().unwrap();
+                        let assign = cst.pop().unwrap();
+                        let node = CSTNode::Comma {
+                            span,
+                            assign: Box::new(assign),
+                            comma_prime: Some(Box::new(comma_prime)),
+                        };
+                        cst.push(node);
+                    }
+                    Prod::Comma => {
+                        let assign = cst.pop().unwrap();
+                        let node = CSTNode::Comma {
+                            span,
+                            assign: Box::new(assign),
+                            comma_prime: None,
+                        };
+                        cst.push(node);
+                    }
+                    Prod::CommaPrime => {
+                        let op = sym.pop().unwrap();
+                        let comma_prime = cst.pop().unwrap();
+                        let assign = cst.pop().unwrap();
+                        let node = CSTNode::CommaPrime {
+                            span,
+                            op: op.to_string(),
+                            assign: Box::new(assign),
+                            comma_prime: Some(Box::new(comma_prime)),
+                        };
+                        cst.push(node);
+                    }
+                    Prod::CommaPrimeLast => {
+                        let op = sym.pop().unwrap();
+                        let assign = cst.pop().unwrap();
+                        let node = CSTNode::CommaPrime {
+                            span,
+                            op: op.to_string(),
+                            assign: Box::new(assign),
+                            comma_prime: None,
+                        };
+                        cst.push(node);
+                    }
+                    Prod::Conditional => {
                         let logical_or = cst.pop().unwrap();
-                        let node = CSTNode::Assign {
+                        let node = CSTNode::Conditional {
+                            span,
+                            logical_or: Box::new(logical_or),
+                            expression: None,
+                            conditional: None,
+                        };
+                        cst.push(node);
+                    }
+                    Prod::ConditionalTernary => {
+                        // '?' 和 ':'
+                        sym.pop();
+                        sym.pop();
+                        let conditional = cst.pop().unwrap();
+                        let expression = cst.pop().unwrap();
+                        let logical_or = cst.pop().unwrap();
+                        let node = CSTNode::Conditional {
+                            span,
                             logical_or: Box::new(logical_or),
+                            expression: Some(Box::new(expression)),
+                            conditional: Some(Box::new(conditional)),
+                        };
+                        cst.push(node);
+                    }
+                    Prod::Assign => {
+                        let conditional = cst.pop().unwrap();
+                        let node = CSTNode::Assign {
+                            span,
+                            conditional: Box::new(conditional),
                             assign_prime: None,
                         };
                         cst.push(node);
-                        (NonTerm::Assign, 1)
                     }
-                    49 => {
+                    Prod::AssignChain => {
                         let assign_prime = cst.pop().unwrap();
-                        let logical_or = cst.pop().unwrap();
+                        let conditional = cst.pop().unwrap();
                         let node = CSTNode::Assign {
-                            logical_or: Box::new(logical_or),
+                            span,
+                            conditional: Box::new(conditional),
                             assign_prime: Some(Box::new(assign_prime)),
                         };
                         cst.push(node);
-                        (NonTerm::Assign, 2)
                     }
-                    50 => {
+                    Prod::AssignPrime => {
                         let assign_op = sym.pop().unwrap();
                         let assign_prime = cst.pop().unwrap();
-                        let logical_or = cst.pop().unwrap();
+                        let conditional = cst.pop().unwrap();
                         let node = CSTNode::AssignPrime {
-                            op: assign_op.value,
-                            logical_or: Box::new(logical_or),
+                            span,
+                            op: assign_op.to_string(),
+                            conditional: Box::new(conditional),
                             assign_prime: Some(Box::new(assign_prime)),
                         };
                         cst.push(node);
-                        (NonTerm::AssignPrime, 3)
                     }
-                    51 => {
+                    Prod::AssignPrimeLast => {
                         let assign_op = sym.pop().unwrap();
-                        let logical_or = cst.pop().unwrap();
+                        let conditional = cst.pop().unwrap();
                         let node = CSTNode::AssignPrime {
-                            op: assign_op.value,
-                            logical_or: Box::new(logical_or),
+                            span,
+                            op: assign_op.to_string(),
+                            conditional: Box::new(conditional),
                             assign_prime: None,
                         };
                         cst.push(node);
-                        (NonTerm::AssignPrime, 2)
                     }
-                    52 => {
+                    Prod::ComparisonChain => {
                         let comparison_prime = cst.pop().unwrap();
-                        let term = cst.pop().unwrap();
+                        let shift = cst.pop().unwrap();
                         let node = CSTNode::Comparison {
-                            term: Box::new(term),
+                            span,
+                            shift: Box::new(shift),
                             comparison_prime: Some(Box::new(comparison_prime)),
                         };
                         cst.push(node);
-                        (NonTerm::Comparison, 2)
                     }
-                    53 => {
-                        let term = cst.pop().unwrap();
+                    Prod::Comparison => {
+                        let shift = cst.pop().unwrap();
                         let node = CSTNode::Comparison {
-                            term: Box::new(term),
+                            span,
+                            shift: Box::new(shift),
                             comparison_prime: None,
                         };
                         cst.push(node);
-                        (NonTerm::Comparison, 1)
                     }
-                    54..58 => {
+                    Prod::ComparisonPrime => {
                         let op = sym.pop().unwrap();
                         let comparison_prime = cst.pop().unwrap();
-                        let term = cst.pop().unwrap();
+                        let shift = cst.pop().unwrap();
                         let node = CSTNode::ComparisonPrime {
-                            op: op.value,
-                            term: Box::new(term),
+                            span,
+                            op: op.to_string(),
+                            shift: Box::new(shift),
                             comparison_prime: Some(Box::new(comparison_prime)),
                         };
                         cst.push(node);
-                        (NonTerm::ComparisonPrime, 3)
                     }
-                    58..62 => {
+                    Prod::ComparisonPrimeLast => {
                         let op = sym.pop().unwrap();
-                        let term = cst.pop().unwrap();
+                        let shift = cst.pop().unwrap();
                         let node = CSTNode::ComparisonPrime {
-                            op: op.value,
-                            term: Box::new(term),
+                            span,
+                            op: op.to_string(),
+                            shift: Box::new(shift),
                             comparison_prime: None,
                         };
                         cst.push(node);
-                        (NonTerm::ComparisonPrime, 2)
                     }
-                    62 => {
+                    Prod::ShiftChain => {
+                        let shift_prime = cst.pop().unwrap();
+                        let term = cst.pop().unwrap();
+                        let node = CSTNode::Shift {
+                            span,
+                            term: Box::new(term),
+                            shift_prime: Some(Box::new(shift_prime)),
+                        };
+                        cst.push(node);
+                    }
+                    Prod::Shift => {
+                        let term = cst.pop().unwrap();
+                        let node = CSTNode::Shift {
+                            span,
+                            term: Box::new(term),
+                            shift_prime: None,
+                        };
+                        cst.push(node);
+                    }
+                    Prod::ShiftPrime => {
+                        let op = sym.pop().unwrap();
+                        let shift_prime = cst.pop().unwrap();
+                        let term = cst.pop().unwrap();
+                        let node = CSTNode::ShiftPrime {
+                            span,
+                            op: op.to_string(),
+                            term: Box::new(term),
+                            shift_prime: Some(Box::new(shift_prime)),
+                        };
+                        cst.push(node);
+                    }
+                    Prod::ShiftPrimeLast => {
+                        let op = sym.pop().unwrap();
+                        let term = cst.pop().unwrap();
+                        let node = CSTNode::ShiftPrime {
+                            span,
+                            op: op.to_string(),
+                            term: Box::new(term),
+                            shift_prime: None,
+                        };
+                        cst.push(node);
+                    }
+                    Prod::EqualityChain => {
                         let equality_prime = cst.pop().unwrap();
                         let comparison = cst.pop().unwrap();
                         let node = CSTNode::Equality {
+                            span,
                             comparison: Box::new(comparison),
                             equality_prime: Some(Box::new(equality_prime)),
                         };
                         cst.push(node);
-                        (NonTerm::Equality, 2)
                     }
-                    63 => {
+                    Prod::Equality => {
                         let comparison = cst.pop().unwrap();
                         let node = CSTNode::Equality {
+                            span,
                             comparison: Box::new(comparison),
                             equality_prime: None,
                         };
                         cst.push(node);
-                        (NonTerm::Equality, 1)
                     }
-                    64 | 65 => {
+                    Prod::EqualityPrime => {
                         let op = sym.pop().unwrap();
                         let equality_prime = cst.pop().unwrap();
                         let comparison = cst.pop().unwrap();
                         let node = CSTNode::EqualityPrime {
-                            op: op.value,
+                            span,
+                            op: op.to_string(),
                             comparison: Box::new(comparison),
                             equality_prime: Some(Box::new(equality_prime)),
                         };
                         cst.push(node);
-                        (NonTerm::EqualityPrime, 3)
                     }
-                    66 | 67 => {
+                    Prod::EqualityPrimeLast => {
                         let op = sym.pop().unwrap();
                         let comparison = cst.pop().unwrap();
                         let node = CSTNode::EqualityPrime {
-                            op: op.value,
+                            span,
+                            op: op.to_string(),
                             comparison: Box::new(comparison),
                             equality_prime: None,
                         };
                         cst.push(node);
-                        (NonTerm::EqualityPrime, 2)
                     }
-                    68 => {
+                    Prod::Expression => {
                         let assign = cst.pop().unwrap();
-                        let node = CSTNode::Expression(Box::new(assign));
+                        let node = CSTNode::Expression(Box::new(assign), span);
                         cst.push(node);
-                        (NonTerm::Expression, 1)
                     }
-                    69 => {
+                    Prod::FactorChain => {
                         let factor_prime = cst.pop().unwrap();
                         let unary = cst.pop().unwrap();
                         let node = CSTNode::Factor {
+                            span,
                             unary: Box::new(unary),
                             factor_prime: Some(Box::new(factor_prime)),
                         };
                         cst.push(node);
-                        (NonTerm::Factor, 2)
                     }
-                    70 => {
+                    Prod::Factor => {
                         let unary = cst.pop().unwrap();
                         let node = CSTNode::Factor {
+                            span,
                             unary: Box::new(unary),
                             factor_prime: None,
                         };
                         cst.push(node);
-                        (NonTerm::Factor, 1)
                     }
-                    71 | 72 => {
+                    Prod::FactorPrime => {
                         let op = sym.pop().unwrap();
                         let factor_prime = cst.pop().unwrap();
                         let unary = cst.pop().unwrap();
                         let node = CSTNode::FactorPrime {
-                            op: op.value,
+                            span,
+                            op: op.to_string(),
                             unary: Box::new(unary),
                             factor_prime: Some(Box::new(factor_prime)),
                         };
                         cst.push(node);
-                        (NonTerm::FactorPrime, 3)
                     }
-                    73 | 74 => {
+                    Prod::FactorPrimeLast => {
                         let op = sym.pop().unwrap();
                         let unary = cst.pop().unwrap();
                         let node = CSTNode::FactorPrime {
-                            op: op.value,
+                            span,
+                            op: op.to_string(),
                             unary: Box::new(unary),
                             factor_prime: None,
                         };
                         cst.push(node);
-                        (NonTerm::FactorPrime, 2)
                     }
-                    75 => {
+                    Prod::LogicalAndChain => {
                         let logical_and_prime = cst.pop().unwrap();
-                        let equality = cst.pop().unwrap();
+                        let bit_or = cst.pop().unwrap();
                         let node = CSTNode::LogicalAnd {
-                            equality: Box::new(equality),
+                            span,
+                            bit_or: Box::new(bit_or),
                             logical_and_prime: Some(Box::new(logical_and_prime)),
                         };
                         cst.push(node);
-                        (NonTerm::LogicalAnd, 2)
                     }
-                    76 => {
-                        let equality = cst.pop().unwrap();
+                    Prod::LogicalAnd => {
+                        let bit_or = cst.pop().unwrap();
                         let node = CSTNode::LogicalAnd {
-                            equality: Box::new(equality),
+                            span,
+                            bit_or: Box::new(bit_or),
                             logical_and_prime: None,
                         };
                         cst.push(node);
-                        (NonTerm::LogicalAnd, 1)
                     }
-                    77 => {
+                    Prod::LogicalAndPrime => {
                         let op = sym.pop().unwrap();
                         let logical_and_prime = cst.pop().unwrap();
-                        let equality = cst.pop().unwrap();
+                        let bit_or = cst.pop().unwrap();
                         let node = CSTNode::LogicalAndPrime {
-                            op: op.value,
-                            equality: Box::new(equality),
+                            span,
+                            op: op.to_string(),
+                            bit_or: Box::new(bit_or),
                             logical_and_prime: Some(Box::new(logical_and_prime)),
                         };
                         cst.push(node);
-                        (NonTerm::LogicalAndPrime, 3)
                     }
-                    78 => {
+                    Prod::LogicalAndPrimeLast => {
                         let op = sym.pop().unwrap();
-                        let equality = cst.pop().unwrap();
+                        let bit_or = cst.pop().unwrap();
                         let node = CSTNode::LogicalAndPrime {
-                            op: op.value,
-                            equality: Box::new(equality),
+                            span,
+                            op: op.to_string(),
+                            bit_or: Box::new(bit_or),
                             logical_and_prime: None,
                         };
                         cst.push(node);
-                        (NonTerm::LogicalAndPrime, 2)
                     }
-                    79 => {
+                    Prod::BitOrChain => {
+                        let bit_or_prime = cst.pop().unwrap();
+                        let bit_xor = cst.pop().unwrap();
+                        let node = CSTNode::BitOr {
+                            span,
+                            bit_xor: Box::new(bit_xor),
+                            bit_or_prime: Some(Box::new(bit_or_prime)),
+                        };
+                        cst.push(node);
+                    }
+                    Prod::BitOr => {
+                        let bit_xor = cst.pop().unwrap();
+                        let node = CSTNode::BitOr {
+                            span,
+                            bit_xor: Box::new(bit_xor),
+                            bit_or_prime: None,
+                        };
+                        cst.push(node);
+                    }
+                    Prod::BitOrPrime => {
+                        let op = sym.pop().unwrap();
+                        let bit_or_prime = cst.pop().unwrap();
+                        let bit_xor = cst.pop().unwrap();
+                        let node = CSTNode::BitOrPrime {
+                            span,
+                            op: op.to_string(),
+                            bit_xor: Box::new(bit_xor),
+                            bit_or_prime: Some(Box::new(bit_or_prime)),
+                        };
+                        cst.push(node);
+                    }
+                    Prod::BitOrPrimeLast => {
+                        let op = sym.pop().unwrap();
+                        let bit_xor = cst.pop().unwrap();
+                        let node = CSTNode::BitOrPrime {
+                            span,
+                            op: op.to_string(),
+                            bit_xor: Box::new(bit_xor),
+                            bit_or_prime: None,
+                        };
+                        cst.push(node);
+                    }
+                    Prod::BitXorChain => {
+                        let bit_xor_prime = cst.pop().unwrap();
+                        let bit_and = cst.pop().unwrap();
+                        let node = CSTNode::BitXor {
+                            span,
+                            bit_and: Box::new(bit_and),
+                            bit_xor_prime: Some(Box::new(bit_xor_prime)),
+                        };
+                        cst.push(node);
+                    }
+                    Prod::BitXor => {
+                        let bit_and = cst.pop().unwrap();
+                        let node = CSTNode::BitXor {
+                            span,
+                            bit_and: Box::new(bit_and),
+                            bit_xor_prime: None,
+                        };
+                        cst.push(node);
+                    }
+                    Prod::BitXorPrime => {
+                        let op = sym.pop().unwrap();
+                        let bit_xor_prime = cst.pop().unwrap();
+                        let bit_and = cst.pop().unwrap();
+                        let node = CSTNode::BitXorPrime {
+                            span,
+                            op: op.to_string(),
+                            bit_and: Box::new(bit_and),
+                            bit_xor_prime: Some(Box::new(bit_xor_prime)),
+                        };
+                        cst.push(node);
+                    }
+                    Prod::BitXorPrimeLast => {
+                        let op = sym.pop().unwrap();
+                        let bit_and = cst.pop().unwrap();
+                        let node = CSTNode::BitXorPrime {
+                            span,
+                            op: op.to_string(),
+                            bit_and: Box::new(bit_and),
+                            bit_xor_prime: None,
+                        };
+                        cst.push(node);
+                    }
+                    Prod::BitAndChain => {
+                        let bit_and_prime = cst.pop().unwrap();
+                        let equality = cst.pop().unwrap();
+                        let node = CSTNode::BitAnd {
+                            span,
+                            equality: Box::new(equality),
+                            bit_and_prime: Some(Box::new(bit_and_prime)),
+                        };
+                        cst.push(node);
+                    }
+                    Prod::BitAnd => {
+                        let equality = cst.pop().unwrap();
+                        let node = CSTNode::BitAnd {
+                            span,
+                            equality: Box::new(equality),
+                            bit_and_prime: None,
+                        };
+                        cst.push(node);
+                    }
+                    Prod::BitAndPrime => {
+                        let op = sym.pop().unwrap();
+                        let bit_and_prime = cst.pop().unwrap();
+                        let equality = cst.pop().unwrap();
+                        let node = CSTNode::BitAndPrime {
+                            span,
+                            op: op.to_string(),
+                            equality: Box::new(equality),
+                            bit_and_prime: Some(Box::new(bit_and_prime)),
+                        };
+                        cst.push(node);
+                    }
+                    Prod::BitAndPrimeLast => {
+                        let op = sym.pop().unwrap();
+                        let equality = cst.pop().unwrap();
+                        let node = CSTNode::BitAndPrime {
+                            span,
+                            op: op.to_string(),
+                            equality: Box::new(equality),
+                            bit_and_prime: None,
+                        };
+                        cst.push(node);
+                    }
+                    Prod::LogicalOrChain => {
                         let logical_or_prime = cst.pop().unwrap();
                         let logical_and = cst.pop().unwrap();
                         let node = CSTNode::LogicalOr {
+                            span,
                             logical_and: Box::new(logical_and),
                             logical_or_prime: Some(Box::new(logical_or_prime)),
                         };
                         cst.push(node);
-                        (NonTerm::LogicalOr, 2)
                     }
-                    80 => {
+                    Prod::LogicalOr => {
                         let logical_and = cst.pop().unwrap();
                         let node = CSTNode::LogicalOr {
+                            span,
                             logical_and: Box::new(logical_and),
                             logical_or_prime: None,
                         };
                         cst.push(node);
-                        (NonTerm::LogicalOr, 1)
                     }
-                    81 => {
+                    Prod::LogicalOrPrime => {
                         let op = sym.pop().unwrap();
                         let logical_or_prime = cst.pop().unwrap();
                         let logical_and = cst.pop().unwrap();
                         let node = CSTNode::LogicalOrPrime {
-                            op: op.value,
+                            span,
+                            op: op.to_string(),
                             logical_and: Box::new(logical_and),
                             logical_or_prime: Some(Box::new(logical_or_prime)),
                         };
                         cst.push(node);
-                        (NonTerm::LogicalOrPrime, 3)
                     }
-                    82 => {
+                    Prod::LogicalOrPrimeLast => {
                         let op = sym.pop().unwrap();
                         let logical_and = cst.pop().unwrap();
                         let node = CSTNode::LogicalOrPrime {
-                            op: op.value,
+                            span,
+                            op: op.to_string(),
                             logical_and: Box::new(logical_and),
                             logical_or_prime: None,
                         };
                         cst.push(node);
-                        (NonTerm::LogicalOrPrime, 2)
                     }
-                    83..87 => {
+                    Prod::PrimaryToken => {
                         let symbol = sym.pop().unwrap();
                         let node = CSTNode::Primary {
+                            span,
                             symbol: Some(symbol),
                             fun_call: None,
                             expression: None,
@@ -1098,25 +1866,25 @@ pub fn parse(tokens: &Vec<Token>) -> Vec<CSTNode> {
                             rp: None,
                         };
                         cst.push(node);
-                        (NonTerm::Primary, 1)
                     }
-                    87 => {
+                    Prod::PrimaryParen => {
                         let rp = sym.pop().unwrap();
                         let lp = sym.pop().unwrap();
                         let expression = cst.pop().unwrap();
                         let node = CSTNode::Primary {
+                            span,
                             symbol: None,
-                            lp: Some(lp.value),
-                            rp: Some(rp.value),
+                            lp: Some(lp.to_string()),
+                            rp: Some(rp.to_string()),
                             expression: Some(Box::new(expression)),
                             fun_call: None,
                         };
                         cst.push(node);
-                        (NonTerm::Primary, 3)
                     }
-                    88 => {
+                    Prod::PrimaryCall => {
                         let fun_call = cst.pop().unwrap();
                         let node = CSTNode::Primary {
+                            span,
                             symbol: None,
                             lp: None,
                             rp: None,
@@ -1124,81 +1892,135 @@ pub fn parse(tokens: &Vec<Token>) -> Vec<CSTNode> {
                             fun_call: Some(Box::new(fun_call)),
                         };
                         cst.push(node);
-                        (NonTerm::Primary, 1)
                     }
-                    89 => {
+                    Prod::PrimaryIndex => {
+                        // '[' 和 ']'
+                        sym.pop();
+                        sym.pop();
+                        let expression = cst.pop().unwrap();
+                        let primary = cst.pop().unwrap();
+                        let node = CSTNode::Index {
+                            span,
+                            primary: Box::new(primary),
+                            expression: Box::new(expression),
+                        };
+                        cst.push(node);
+                    }
+                    Prod::PrimaryMember => {
+                        let id = sym.pop().unwrap();
+                        let op = sym.pop().unwrap();
+                        let primary = cst.pop().unwrap();
+                        let node = CSTNode::Member {
+                            span,
+                            primary: Box::new(primary),
+                            op: op.to_string(),
+                            id: id.to_string(),
+                        };
+                        cst.push(node);
+                    }
+                    Prod::TermChain => {
                         let term_prime = cst.pop().unwrap();
                         let factor = cst.pop().unwrap();
                         let node = CSTNode::Term {
+                            span,
                             factor: Box::new(factor),
                             term_prime: Some(Box::new(term_prime)),
                         };
                         cst.push(node);
-                        (NonTerm::Term, 2)
                     }
-                    90 => {
+                    Prod::Term => {
                         let factor = cst.pop().unwrap();
                         let node = CSTNode::Term {
+                            span,
                             factor: Box::new(factor),
                             term_prime: None,
                         };
                         cst.push(node);
-                        (NonTerm::Term, 1)
                     }
-                    91 | 92 => {
+                    Prod::TermPrime => {
                         let term_prime = cst.pop().unwrap();
                         let factor = cst.pop().unwrap();
                         let op = sym.pop().unwrap();
                         let node = CSTNode::TermPrime {
-                            op: op.value,
+                            span,
+                            op: op.to_string(),
                             factor: Box::new(factor),
                             term_prime: Some(Box::new(term_prime)),
                         };
                         cst.push(node);
-                        (NonTerm::TermPrime, 3)
                     }
-                    93 | 94 => {
+                    Prod::TermPrimeLast => {
                         let factor = cst.pop().unwrap();
                         let op = sym.pop().unwrap();
                         let node = CSTNode::TermPrime {
-                            op: op.value,
+                            span,
+                            op: op.to_string(),
                             factor: Box::new(factor),
                             term_prime: None,
                         };
                         cst.push(node);
-                        (NonTerm::TermPrime, 2)
                     }
-                    96 => {
+                    Prod::UnaryPrimary => {
                         let primary = cst.pop().unwrap();
                         let node = CSTNode::Unary {
+                            span,
                             op: None,
                             unary: Box::new(primary),
                         };
                         cst.push(node);
-                        (NonTerm::Unary, 1)
                     }
-                    95 | 97 => {
+                    Prod::UnaryPostfix => {
+                        let op = sym.pop().unwrap();
+                        let primary = cst.pop().unwrap();
+                        let node = CSTNode::Postfix {
+                            span,
+                            primary: Box::new(primary),
+                            op: op.to_string(),
+                        };
+                        cst.push(node);
+                    }
+                    Prod::UnaryOp => {
                         let op = sym.pop().unwrap();
                         let unary = cst.pop().unwrap();
                         let node = CSTNode::Unary {
-                            op: Some(op.value),
+                            span,
+                            op: Some(op.to_string()),
                             unary: Box::new(unary),
                         };
                         cst.push(node);
-                        (NonTerm::Unary, 2)
                     }
-                    _ => unreachable!(),
-                };
+                    // 出错恢复的产生式，把 error 后面同步用的符号一起丢掉
+                    Prod::ExtDefError | Prod::NormalError => {
+                        sym.pop();
+                        cst.push(CSTNode::Error(span));
+                    }
+                    Prod::ExtDefErrorBody | Prod::DefErrorSync => {
+                        cst.pop();
+                        cst.push(CSTNode::Error(span));
+                    }
+                    Prod::DefError => {
+                        sym.pop();
+                        cst.pop();
+                        cst.push(CSTNode::Error(span));
+                    }
+                    Prod::ExtDefErrorSync | Prod::StmtListError => {
+                        cst.push(CSTNode::Error(span));
+                    }
+                }
                 for _ in 0..rhs_len {
                     // let last_state = state.last().unwrap();
                     // println!("pop state {:?}", last_state);
                     state.pop();
+                    spans.pop();
+                    slots.pop();
                 }
                 // 根据 GOTO 表推进
                 let st2 = state.last().unwrap().to_index();
-                if let Some(tgt) = &super::constant::GOTO[st2][lhs.to_index()] {
+                if let Some(tgt) = &GOTO[st2][prod.lhs() as usize] {
                     // println!("reduce push {:?} {:?} {:?}", st2, *tgt, look);
                     state.push(*tgt);
+                    spans.push(span);
+                    slots.push(Slot::Node);
                 }
             }
             Action::Accept => {
@@ -1207,34 +2029,46 @@ pub fn parse(tokens: &Vec<Token>) -> Vec<CSTNode> {
             }
 
             Action::Error => {
-                let tok = look.unwrap();
-                error_handler(&state, tok);
-                break;
-                // skip error token
-                //     loop {
-                //         let look = tokens.get(index);
-                //         match look {
-                //             Some(token) => {
-                //                 if token.types == PhraseType::Separator {
-                //                     index += 1;
-                //                     break;
-                //                 } else {
-                //                     index += 1;
-                //                 }
-                //             }
-                //             None => break,
-                //         }
-                //     }
-                //     if let Some(token) = tokens.get(index) {
-                //         println!("start over token {:?}", token);
-                //         state.clear();
-                //         state.push(State::S0);
-                //         continue;
-                //     } else {
-                //         break;
-                //     }
+                if recovering == 0 {
+                    errors.push(syntax_error(
+                        &state,
+                        &sym,
+                        index.checked_sub(1).and_then(|k| tokens.get(k)),
+                        token,
+                    ));
+                } else if recovering == RECOVER_TOKENS {
+                    // 刚恢复还没移进任何 token 就又出错，丢掉这个 token
+                    if token.kind == TokenKind::Eof {
+                        break;
+                    }
+                    index += 1;
+                }
+                recovering = RECOVER_TOKENS;
+                // 退栈直到某个状态能移进 error，退到底说明无法恢复
+                loop {
+                    if let Action::Shift(ns) = ACTION[state.last().unwrap().to_index()][ERROR] {
+                        state.push(ns);
+                        spans.push(token.span);
+                        slots.push(Slot::Empty);
+                        break;
+                    }
+                    if state.len() == 1 {
+                        return (cst, errors);
+                    }
+                    state.pop();
+                    spans.pop();
+                    match slots.pop() {
+                        Some(Slot::Token) => {
+                            sym.pop();
+                        }
+                        Some(Slot::Node) => {
+                            cst.pop();
+                        }
+                        _ => {}
+                    }
+                }
             }
         }
     }
-    cst
+    (cst, errors)
 }
@@ -1,268 +1,45 @@
+use super::grammar::Prod;
+use crate::utils::types::Span;
+use std::fmt;
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum Action {
     Shift(State),
-    Reduce(u8), // 编号见产生式列表
+    Reduce(Prod),
     Accept,
     Error,
 }
 
-#[repr(usize)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
-pub enum State {
-    S0 = 0,
-    S1 = 1,
-    S2 = 2,
-    S3 = 3,
-    S4 = 4,
-    S5 = 5,
-    S6 = 6,
-    S7 = 7,
-    S8 = 8,
-    S9 = 9,
-    S10 = 10,
-    S11 = 11,
-    S12 = 12,
-    S13 = 13,
-    S14 = 14,
-    S15 = 15,
-    S16 = 16,
-    S17 = 17,
-    S18 = 18,
-    S19 = 19,
-    S20 = 20,
-    S21 = 21,
-    S22 = 22,
-    S23 = 23,
-    S24 = 24,
-    S25 = 25,
-    S26 = 26,
-    S27 = 27,
-    S28 = 28,
-    S29 = 29,
-    S30 = 30,
-    S31 = 31,
-    S32 = 32,
-    S33 = 33,
-    S34 = 34,
-    S35 = 35,
-    S36 = 36,
-    S37 = 37,
-    S38 = 38,
-    S39 = 39,
-    S40 = 40,
-    S41 = 41,
-    S42 = 42,
-    S43 = 43,
-    S44 = 44,
-    S45 = 45,
-    S46 = 46,
-    S47 = 47,
-    S48 = 48,
-    S49 = 49,
-    S50 = 50,
-    S51 = 51,
-    S52 = 52,
-    S53 = 53,
-    S54 = 54,
-    S55 = 55,
-    S56 = 56,
-    S57 = 57,
-    S58 = 58,
-    S59 = 59,
-    S60 = 60,
-    S61 = 61,
-    S62 = 62,
-    S63 = 63,
-    S64 = 64,
-    S65 = 65,
-    S66 = 66,
-    S67 = 67,
-    S68 = 68,
-    S69 = 69,
-    S70 = 70,
-    S71 = 71,
-    S72 = 72,
-    S73 = 73,
-    S74 = 74,
-    S75 = 75,
-    S76 = 76,
-    S77 = 77,
-    S78 = 78,
-    S79 = 79,
-    S80 = 80,
-    S81 = 81,
-    S82 = 82,
-    S83 = 83,
-    S84 = 84,
-    S85 = 85,
-    S86 = 86,
-    S87 = 87,
-    S88 = 88,
-    S89 = 89,
-    S90 = 90,
-    S91 = 91,
-    S92 = 92,
-    S93 = 93,
-    S94 = 94,
-    S95 = 95,
-    S96 = 96,
-    S97 = 97,
-    S98 = 98,
-    S99 = 99,
-    S100 = 100,
-    S101 = 101,
-    S102 = 102,
-    S103 = 103,
-    S104 = 104,
-    S105 = 105,
-    S106 = 106,
-    S107 = 107,
-    S108 = 108,
-    S109 = 109,
-    S110 = 110,
-    S111 = 111,
-    S112 = 112,
-    S113 = 113,
-    S114 = 114,
-    S115 = 115,
-    S116 = 116,
-    S117 = 117,
-    S118 = 118,
-    S119 = 119,
-    S120 = 120,
-    S121 = 121,
-    S122 = 122,
-    S123 = 123,
-    S124 = 124,
-    S125 = 125,
-    S126 = 126,
-    S127 = 127,
-    S128 = 128,
-    S129 = 129,
-    S130 = 130,
-    S131 = 131,
-    S132 = 132,
-    S133 = 133,
-    S134 = 134,
-    S135 = 135,
-    S136 = 136,
-    S137 = 137,
-    S138 = 138,
-    S139 = 139,
-    S140 = 140,
-    S141 = 141,
-    S142 = 142,
-    S143 = 143,
-    S144 = 144,
-    S145 = 145,
-    S146 = 146,
-    S147 = 147,
-    S148 = 148,
-    S149 = 149,
-    S150 = 150,
-    S151 = 151,
-    S152 = 152,
-    S153 = 153,
-    S154 = 154,
-    S155 = 155,
-}
+// 分析表的状态编号
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct State(pub usize);
 
 impl State {
     pub fn to_index(self) -> usize {
-        self as usize
+        self.0
     }
 }
 
-#[repr(usize)]
-#[derive(Debug)]
-pub enum NonTerm {
-    Accept,
-    Arguments,
-    ArgumentsTail,
-    CompSt,
-    Dec,
-    DecList,
-    Def,
-    DefList,
-    ExtDecList,
-    ExtDef,
-    ExtDefList,
-    FunCall,
-    FunDec,
-    MatchedStmt,
-    NormalStmt,
-    ParaDec,
-    Program,
-    Specifier,
-    Stmt,
-    StmtList,
-    StructSpecifier,
-    UnMatchedStmt,
-    VarDec,
-    VarList,
-    Assign,
-    AssignPrime,
-    Comparison,
-    ComparisonPrime,
-    Equality,
-    EqualityPrime,
-    Expression,
-    Factor,
-    FactorPrime,
-    LogicalAnd,
-    LogicalAndPrime,
-    LogicalOr,
-    LogicalOrPrime,
-    Primary,
-    Term,
-    TermPrime,
-    Unary,
+impl fmt::Debug for State {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "S{}", self.0)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SyntaxError {
+    pub span: Span,
+    pub message: String,
+    // 宏展开回溯之类的附加说明，每条一行
+    pub notes: Vec<String>,
 }
 
-impl NonTerm {
-    pub fn to_index(&self) -> usize {
-        match self {
-            NonTerm::Accept => 0,
-            NonTerm::Arguments => 1,
-            NonTerm::ArgumentsTail => 2,
-            NonTerm::CompSt => 3,
-            NonTerm::Dec => 4,
-            NonTerm::DecList => 5,
-            NonTerm::Def => 6,
-            NonTerm::DefList => 7,
-            NonTerm::ExtDecList => 8,
-            NonTerm::ExtDef => 9,
-            NonTerm::ExtDefList => 10,
-            NonTerm::FunCall => 11,
-            NonTerm::FunDec => 12,
-            NonTerm::MatchedStmt => 13,
-            NonTerm::NormalStmt => 14,
-            NonTerm::ParaDec => 15,
-            NonTerm::Program => 16,
-            NonTerm::Specifier => 17,
-            NonTerm::Stmt => 18,
-            NonTerm::StmtList => 19,
-            NonTerm::StructSpecifier => 20,
-            NonTerm::UnMatchedStmt => 21,
-            NonTerm::VarDec => 22,
-            NonTerm::VarList => 23,
-            NonTerm::Assign => 24,
-            NonTerm::AssignPrime => 25,
-            NonTerm::Comparison => 26,
-            NonTerm::ComparisonPrime => 27,
-            NonTerm::Equality => 28,
-            NonTerm::EqualityPrime => 29,
-            NonTerm::Expression => 30,
-            NonTerm::Factor => 31,
-            NonTerm::FactorPrime => 32,
-            NonTerm::LogicalAnd => 33,
-            NonTerm::LogicalAndPrime => 34,
-            NonTerm::LogicalOr => 35,
-            NonTerm::LogicalOrPrime => 36,
-            NonTerm::Primary => 37,
-            NonTerm::Term => 38,
-            NonTerm::TermPrime => 39,
-            NonTerm::Unary => 40,
+impl fmt::Display for SyntaxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: error: {}", self.span, self.message)?;
+        for note in &self.notes {
+            write!(f, "\n{}", note)?;
         }
+        Ok(())
     }
 }
@@ -0,0 +1,200 @@
+use super::types::{PPToken, PPTokenType};
+
+// #if/#elif 常量表达式求值，按 C 的优先级递归下降
+// 进入这里之前 defined 已经替换、宏已经展开，剩下的标识符都按 0 处理
+pub fn eval(tokens: &[PPToken]) -> Result<i64, String> {
+    let tokens: Vec<&PPToken> = tokens
+        .iter()
+        .filter(|t| t.types != PPTokenType::Placemarker)
+        .collect();
+    if tokens.is_empty() {
+        return Err(String::from("#if with no expression"));
+    }
+    let mut parser = ExprParser { tokens, pos: 0 };
+    let value = parser.conditional()?;
+    if let Some(tok) = parser.peek() {
+        return Err(format!(
+            "missing binary operator before token \"{}\"",
+            tok.value
+        ));
+    }
+    Ok(value)
+}
+
+struct ExprParser<'a> {
+    tokens: Vec<&'a PPToken>,
+    pos: usize,
+}
+
+// 二元运算符的优先级，数字越大结合越紧
+fn binary_precedence(op: &str) -> Option<u8> {
+    match op {
+        "||" => Some(1),
+        "&&" => Some(2),
+        "|" => Some(3),
+        "^" => Some(4),
+        "&" => Some(5),
+        "==" | "!=" => Some(6),
+        "<" | "<=" | ">" | ">=" => Some(7),
+        "<<" | ">>" => Some(8),
+        "+" | "-" => Some(9),
+        "*" | "/" | "%" => Some(10),
+        _ => None,
+    }
+}
+
+impl<'a> ExprParser<'a> {
+    fn peek(&self) -> Option<&'a PPToken> {
+        self.tokens.get(self.pos).copied()
+    }
+
+    fn next(&mut self) -> Option<&'a PPToken> {
+        let tok = self.peek();
+        self.pos += 1;
+        tok
+    }
+
+    fn expect(&mut self, p: &str) -> Result<(), String> {
+        match self.next() {
+            Some(tok) if tok.is_punct(p) => Ok(()),
+            Some(tok) => Err(format!(
+                "expected '{}' in expression, found \"{}\"",
+                p, tok.value
+            )),
+            None => Err(format!("expected '{}' at end of expression", p)),
+        }
+    }
+
+    fn conditional(&mut self) -> Result<i64, String> {
+        let cond = self.binary(1)?;
+        if self.peek().is_some_and(|t| t.is_punct("?")) {
+            self.pos += 1;
+            let then_v = self.conditional()?;
+            self.expect(":")?;
+            let else_v = self.conditional()?;
+            Ok(if cond != 0 { then_v } else { else_v })
+        } else {
+            Ok(cond)
+        }
+    }
+
+    fn binary(&mut self, min_prec: u8) -> Result<i64, String> {
+        let mut lhs = self.unary()?;
+        loop {
+            let op = match self.peek() {
+                Some(tok) if tok.types == PPTokenType::Punct => tok.value.clone(),
+                _ => break,
+            };
+            let prec = match binary_precedence(&op) {
+                Some(p) if p >= min_prec => p,
+                _ => break,
+            };
+            self.pos += 1;
+            let rhs = self.binary(prec + 1)?;
+            lhs = match op.as_str() {
+                "||" => ((lhs != 0) || (rhs != 0)) as i64,
+                "&&" => ((lhs != 0) && (rhs != 0)) as i64,
+                "|" => lhs | rhs,
+                "^" => lhs ^ rhs,
+                "&" => lhs & rhs,
+                "==" => (lhs == rhs) as i64,
+                "!=" => (lhs != rhs) as i64,
+                "<" => (lhs < rhs) as i64,
+                "<=" => (lhs <= rhs) as i64,
+                ">" => (lhs > rhs) as i64,
+                ">=" => (lhs >= rhs) as i64,
+                "<<" => lhs.wrapping_shl(rhs as u32),
+                ">>" => lhs.wrapping_shr(rhs as u32),
+                "+" => lhs.wrapping_add(rhs),
+                "-" => lhs.wrapping_sub(rhs),
+                "*" => lhs.wrapping_mul(rhs),
+                "/" | "%" => {
+                    if rhs == 0 {
+                        return Err(String::from("division by zero in #if"));
+                    }
+                    if op == "/" {
+                        lhs.wrapping_div(rhs)
+                    } else {
+                        lhs.wrapping_rem(rhs)
+                    }
+                }
+                _ => unreachable!(),
+            };
+        }
+        Ok(lhs)
+    }
+
+    fn unary(&mut self) -> Result<i64, String> {
+        let tok = match self.next() {
+            Some(tok) => tok,
+            None => return Err(String::from("#if expression ends unexpectedly")),
+        };
+        match tok.types {
+            PPTokenType::Punct => match tok.value.as_str() {
+                "(" => {
+                    let v = self.conditional()?;
+                    self.expect(")")?;
+                    Ok(v)
+                }
+                "+" => self.unary(),
+                "-" => Ok(self.unary()?.wrapping_neg()),
+                "~" => Ok(!self.unary()?),
+                "!" => Ok((self.unary()? == 0) as i64),
+                _ => Err(format!(
+                    "token \"{}\" is not valid in preprocessor expressions",
+                    tok.value
+                )),
+            },
+            PPTokenType::Number => parse_number(&tok.value),
+            PPTokenType::Char => parse_char(&tok.value),
+            // 未定义的标识符按 0 处理
+            PPTokenType::Ident => Ok(0),
+            _ => Err(format!(
+                "token \"{}\" is not valid in preprocessor expressions",
+                tok.value
+            )),
+        }
+    }
+}
+
+pub fn parse_number(text: &str) -> Result<i64, String> {
+    let digits = text.trim_end_matches(['u', 'U', 'l', 'L']);
+    let lower = digits.to_ascii_lowercase();
+    let parsed = if let Some(hex) = lower.strip_prefix("0x") {
+        u64::from_str_radix(hex, 16)
+    } else if let Some(bin) = lower.strip_prefix("0b") {
+        u64::from_str_radix(bin, 2)
+    } else if lower.len() > 1 && lower.starts_with('0') {
+        u64::from_str_radix(&lower[1..], 8)
+    } else {
+        lower.parse::<u64>()
+    };
+    parsed
+        .map(|v| v as i64)
+        .map_err(|_| format!("invalid integer constant \"{}\" in #if", text))
+}
+
+fn parse_char(text: &str) -> Result<i64, String> {
+    let body = text
+        .trim_start_matches(['L', 'u', 'U'])
+        .trim_start_matches('\'')
+        .trim_end_matches('\'');
+    let mut chars = body.chars();
+    let value = match chars.next() {
+        Some('\\') => match chars.next() {
+            Some('n') => 10,
+            Some('t') => 9,
+            Some('r') => 13,
+            Some('0') => 0,
+            Some('a') => 7,
+            Some('b') => 8,
+            Some('f') => 12,
+            Some('v') => 11,
+            Some(c) => c as i64,
+            None => return Err(format!("invalid character constant {} in #if", text)),
+        },
+        Some(c) => c as i64,
+        None => return Err(String::from("empty character constant in #if")),
+    };
+    Ok(value)
+}
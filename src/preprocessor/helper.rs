@@ -0,0 +1,345 @@
+use super::types::{LineOrigin, PPToken, PPTokenType};
+use crate::utils::session::file_source;
+use crate::utils::types::Span;
+use once_cell::sync::Lazy;
+use std::sync::Mutex;
+
+// 预处理输出的行号 -> 源码位置，供后续阶段报错时使用
+static LINE_MAP: Lazy<Mutex<Vec<LineOrigin>>> = Lazy::new(|| Mutex::new(Vec::new()));
+
+pub fn set_line_map(lines: &[LineOrigin]) {
+    let mut map = LINE_MAP.lock().unwrap();
+    *map = lines.to_vec();
+}
+
+// row 从 1 开始
+pub fn origin_of(row: usize) -> Option<LineOrigin> {
+    let map = LINE_MAP.lock().unwrap();
+    if row == 0 {
+        None
+    } else {
+        map.get(row - 1).cloned()
+    }
+}
+
+// 预处理后第 row 行、第 col 列开始的 len 个字符，映射回源文件中的位置
+pub fn span_of(row: usize, col: usize, len: usize) -> Span {
+    let origin = match origin_of(row) {
+        Some(origin) => origin,
+        None => return Span::default(),
+    };
+    let src = file_source(origin.file_id);
+    let line_end = src[origin.line_start.min(src.len())..]
+        .find('\n')
+        .map_or(src.len(), |k| origin.line_start + k);
+    let text = &src[origin.line_start.min(line_end)..line_end];
+    // 按字符数换算字节偏移，超出行尾的部分截断
+    let byte_at = |n: usize| text.char_indices().nth(n).map_or(text.len(), |(k, _)| k);
+    let lo = origin.line_start + byte_at(col.saturating_sub(1));
+    let hi = origin.line_start + byte_at(col.saturating_sub(1) + len);
+    Span {
+        file: origin.file_id,
+        line: origin.line,
+        col: col as u32,
+        lo,
+        hi: hi.max(lo),
+    }
+}
+
+// 宏展开回溯，最内层在前，和 gcc 的 note 顺序一致
+pub fn expansion_notes(span: Span) -> Vec<String> {
+    let map = LINE_MAP.lock().unwrap();
+    let mut notes = Vec::new();
+    for origin in map
+        .iter()
+        .filter(|o| o.file_id == span.file && o.line == span.line)
+    {
+        for exp in origin.expansions.iter().rev() {
+            let note = format!(
+                "note: in expansion of macro '{}' (defined at {}:{})",
+                exp.name, exp.def_file, exp.def_line
+            );
+            if !notes.contains(&note) {
+                notes.push(note);
+            }
+        }
+    }
+    notes
+}
+
+pub type SourceChar = (char, u32, u32);
+
+// 续行拼接、去掉注释，把文件切成逻辑行，每个字符保留物理行列号
+// 第二个返回值是没有闭合的块注释开始的位置
+pub fn split_logical_lines(src: &str) -> (Vec<Vec<SourceChar>>, Option<(u32, u32)>) {
+    #[derive(PartialEq)]
+    enum Mode {
+        Normal,
+        Quote(char),
+        LineComment,
+        BlockComment,
+    }
+    let chars: Vec<char> = src.chars().collect();
+    let mut lines = Vec::new();
+    let mut current: Vec<SourceChar> = Vec::new();
+    let mut mode = Mode::Normal;
+    let (mut line, mut col) = (1u32, 1u32);
+    let mut comment_start = (1u32, 1u32);
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        // 续行
+        if c == '\\' {
+            let mut j = i + 1;
+            if j < chars.len() && chars[j] == '\r' {
+                j += 1;
+            }
+            if j < chars.len() && chars[j] == '\n' {
+                i = j + 1;
+                line += 1;
+                col = 1;
+                continue;
+            }
+        }
+        if c == '\r' && i + 1 < chars.len() && chars[i + 1] == '\n' {
+            i += 1;
+            continue;
+        }
+        if c == '\n' {
+            if mode != Mode::BlockComment {
+                mode = Mode::Normal;
+                lines.push(std::mem::take(&mut current));
+            }
+            line += 1;
+            col = 1;
+            i += 1;
+            continue;
+        }
+        let next = chars.get(i + 1).copied();
+        match mode {
+            Mode::Normal => {
+                if c == '/' && next == Some('/') {
+                    current.push((' ', line, col));
+                    mode = Mode::LineComment;
+                } else if c == '/' && next == Some('*') {
+                    current.push((' ', line, col));
+                    mode = Mode::BlockComment;
+                    comment_start = (line, col);
+                    i += 2;
+                    col += 2;
+                    continue;
+                } else {
+                    if c == '"' || c == '\'' {
+                        mode = Mode::Quote(c);
+                    }
+                    current.push((c, line, col));
+                }
+            }
+            Mode::Quote(q) => {
+                current.push((c, line, col));
+                if c == '\\'
+                    && let Some(n) = next
+                {
+                    current.push((n, line, col + 1));
+                    i += 2;
+                    col += 2;
+                    continue;
+                } else if c == q {
+                    mode = Mode::Normal;
+                }
+            }
+            Mode::LineComment => {}
+            Mode::BlockComment => {
+                if c == '*' && next == Some('/') {
+                    mode = Mode::Normal;
+                    i += 2;
+                    col += 2;
+                    continue;
+                }
+            }
+        }
+        i += 1;
+        col += 1;
+    }
+    if !current.is_empty() {
+        lines.push(current);
+    }
+    let unterminated = (mode == Mode::BlockComment).then_some(comment_start);
+    (lines, unterminated)
+}
+
+const PUNCTUATORS: [&str; 54] = [
+    "%:%:", "...", "<<=", ">>=", "->", "++", "--", "<<", ">>", "<=", ">=", "==", "!=", "&&", "||",
+    "*=", "/=", "%=", "+=", "-=", "&=", "^=", "|=", "##", "<:", ":>", "<%", "%>", "%:", "[", "]",
+    "(", ")", "{", "}", ".", "&", "*", "+", "-", "~", "!", "/", "%", "<", ">", "^", "|", "?", ":",
+    ";", "=", ",", "#",
+];
+
+fn is_ident_start(c: char) -> bool {
+    c.is_ascii_alphabetic() || c == '_' || c == '$'
+}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_' || c == '$'
+}
+
+pub fn tokenize(chars: &[SourceChar]) -> Vec<PPToken> {
+    let mut tokens: Vec<PPToken> = Vec::new();
+    let mut i = 0;
+    let mut spaced = false;
+    while i < chars.len() {
+        let (c, line, col) = chars[i];
+        if c == ' ' || c == '\t' || c == '\x0b' || c == '\x0c' {
+            spaced = true;
+            i += 1;
+            continue;
+        }
+        let start = i;
+        let at = |k: usize| chars.get(k).map(|x| x.0);
+        let types = if c.is_ascii_digit()
+            || (c == '.' && at(i + 1).is_some_and(|n| n.is_ascii_digit()))
+        {
+            // pp-number
+            i += 1;
+            while let Some(n) = at(i) {
+                if matches!(n, 'e' | 'E' | 'p' | 'P') && matches!(at(i + 1), Some('+') | Some('-'))
+                {
+                    i += 2;
+                } else if is_ident_char(n) || n == '.' {
+                    i += 1;
+                } else {
+                    break;
+                }
+            }
+            PPTokenType::Number
+        } else if c == '"'
+            || c == '\''
+            || (matches!(c, 'L' | 'u' | 'U') && lit_prefix_len(chars, i) > 0)
+        {
+            i += lit_prefix_len(chars, i);
+            let quote = chars[i].0;
+            i += 1;
+            while let Some(n) = at(i) {
+                i += 1;
+                if n == '\\' {
+                    i += 1;
+                } else if n == quote {
+                    break;
+                }
+            }
+            i = i.min(chars.len());
+            if quote == '"' {
+                PPTokenType::Str
+            } else {
+                PPTokenType::Char
+            }
+        } else if is_ident_start(c) {
+            while at(i).is_some_and(is_ident_char) {
+                i += 1;
+            }
+            PPTokenType::Ident
+        } else if let Some(p) = PUNCTUATORS
+            .iter()
+            .find(|p| p.chars().enumerate().all(|(k, pc)| at(i + k) == Some(pc)))
+        {
+            i += p.len();
+            PPTokenType::Punct
+        } else {
+            i += 1;
+            PPTokenType::Other
+        };
+        let value: String = chars[start..i].iter().map(|x| x.0).collect();
+        let mut tok = PPToken::new(types, value, line, col);
+        tok.spaced = spaced;
+        tokens.push(tok);
+        spaced = false;
+    }
+    tokens
+}
+
+// 字符串/字符字面量前缀 L u U u8 的长度，不是字面量返回 0
+fn lit_prefix_len(chars: &[SourceChar], i: usize) -> usize {
+    let at = |k: usize| chars.get(k).map(|x| x.0);
+    match (at(i), at(i + 1), at(i + 2)) {
+        (Some('u'), Some('8'), Some('"')) => 2,
+        (Some('L' | 'u' | 'U'), Some('"' | '\''), _) => 1,
+        _ => 0,
+    }
+}
+
+// 两个记号直接相连时是否会被重新识别成别的记号
+pub fn need_space(prev: &PPToken, next: &PPToken) -> bool {
+    use PPTokenType::*;
+    match (prev.types, next.types) {
+        (Ident | Number, Ident | Number) => true,
+        (Number, Punct) => {
+            next.value.starts_with('.')
+                || next.value.starts_with('+')
+                || next.value.starts_with('-')
+        }
+        (Punct, Number) => next.value.starts_with('.'),
+        (Punct, Punct) => {
+            let joined = format!("{}{}", prev.value, next.value);
+            PUNCTUATORS
+                .iter()
+                .any(|p| p.len() > prev.value.len() && joined.starts_with(p))
+                || joined.starts_with("//")
+                || joined.starts_with("/*")
+        }
+        (Ident, Str | Char) => matches!(prev.value.as_str(), "L" | "u" | "U" | "u8"),
+        _ => false,
+    }
+}
+
+pub fn spell(tokens: &[PPToken]) -> String {
+    let mut s = String::new();
+    for (i, tok) in tokens.iter().enumerate() {
+        if tok.types == PPTokenType::Placemarker {
+            continue;
+        }
+        if i > 0 && (tok.spaced || need_space(&tokens[i - 1], tok)) {
+            s.push(' ');
+        }
+        s.push_str(&tok.value);
+    }
+    s
+}
+
+// # 运算符：实参转成字符串字面量
+pub fn stringify(tokens: &[PPToken]) -> String {
+    let mut s = String::from("\"");
+    let mut first = true;
+    for tok in tokens {
+        if tok.types == PPTokenType::Placemarker {
+            continue;
+        }
+        if !first && tok.spaced {
+            s.push(' ');
+        }
+        first = false;
+        if matches!(tok.types, PPTokenType::Str | PPTokenType::Char) {
+            for c in tok.value.chars() {
+                if c == '"' || c == '\\' {
+                    s.push('\\');
+                }
+                s.push(c);
+            }
+        } else {
+            s.push_str(&tok.value);
+        }
+    }
+    s.push('"');
+    s
+}
+
+pub fn quote_file_name(name: &str) -> String {
+    let mut s = String::from("\"");
+    for c in name.chars() {
+        if c == '"' || c == '\\' {
+            s.push('\\');
+        }
+        s.push(c);
+    }
+    s.push('"');
+    s
+}
@@ -0,0 +1,9 @@
+// #if 常量表达式
+pub mod expr;
+// helper functions
+pub mod helper;
+// 模块入口
+pub mod preprocess;
+pub mod types;
+
+pub const MAX_INCLUDE_DEPTH: usize = 200;
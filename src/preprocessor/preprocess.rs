@@ -0,0 +1,1025 @@
+use super::MAX_INCLUDE_DEPTH;
+use super::expr::eval;
+use super::helper::{
+    need_space, quote_file_name, set_line_map, spell, split_logical_lines, stringify, tokenize,
+};
+use super::types::{Expansion, LineOrigin, Macro, PPError, PPToken, PPTokenType, Preprocessed};
+use crate::utils::session::register_file;
+use crate::utils::types::Span;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+// 预定义宏，目标是 rv32im ilp32 的裸机环境
+const PREDEFINED: [(&str, &str); 6] = [
+    ("__STDC__", "1"),
+    ("__STDC_VERSION__", "201112L"),
+    ("__STDC_HOSTED__", "0"),
+    ("__quickcc__", "1"),
+    ("__riscv", "1"),
+    ("__riscv_xlen", "32"),
+];
+
+// 当前正在处理的文件
+struct FileCtx {
+    path: PathBuf,
+    // #line 可以修改的文件名和行号
+    name: String,
+    line_delta: i64,
+    file_id: u32,
+    src: Arc<str>,
+    // 每个物理行的起始字节偏移
+    line_starts: Vec<usize>,
+}
+
+impl FileCtx {
+    fn presumed_line(&self, line: u32) -> u32 {
+        (line as i64 + self.line_delta).max(1) as u32
+    }
+
+    // 文件最后一行的行尾，文件以换行结尾时最后一行是换行前的那一行
+    fn end_span(&self) -> Span {
+        let body = self.src.strip_suffix('\n').unwrap_or(&self.src);
+        let start = body.rfind('\n').map_or(0, |k| k + 1);
+        let line = self.line_starts.partition_point(|&s| s <= start) as u32;
+        Span {
+            file: self.file_id,
+            line: self.presumed_line(line),
+            col: body[start..].chars().count() as u32 + 1,
+            lo: body.len(),
+            hi: body.len(),
+        }
+    }
+}
+
+// 条件编译栈中的一层
+struct CondFrame {
+    // 当前分支是否生效
+    active: bool,
+    // 已经有分支生效过，后面的 #elif/#else 都跳过
+    taken: bool,
+    seen_else: bool,
+    line: u32,
+}
+
+pub struct Preprocessor {
+    include_dirs: Vec<PathBuf>,
+    macros: HashMap<String, Macro>,
+    pragma_once: HashSet<PathBuf>,
+    out: Preprocessed,
+    errors: Vec<PPError>,
+    depth: usize,
+}
+
+// 预处理入口：展开 filepath，返回展开后的文本和行号映射
+pub fn preprocess(
+    filepath: &str,
+    include_dirs: &[String],
+    defines: &[String],
+) -> Result<Preprocessed, Vec<PPError>> {
+    let mut pp = Preprocessor::new(include_dirs);
+    for def in defines {
+        pp.define_from_command_line(def);
+    }
+    pp.process_file(Path::new(filepath), filepath.to_string(), None);
+    set_line_map(&pp.out.lines);
+    if pp.errors.is_empty() {
+        Ok(pp.out)
+    } else {
+        Err(pp.errors)
+    }
+}
+
+impl Preprocessor {
+    pub fn new(include_dirs: &[String]) -> Preprocessor {
+        let mut pp = Preprocessor {
+            include_dirs: include_dirs.iter().map(PathBuf::from).collect(),
+            macros: HashMap::new(),
+            pragma_once: HashSet::new(),
+            out: Preprocessed::default(),
+            errors: Vec::new(),
+            depth: 0,
+        };
+        for (name, value) in PREDEFINED {
+            pp.define_builtin(name, value);
+        }
+        pp
+    }
+
+    fn define_builtin(&mut self, name: &str, value: &str) {
+        let chars: Vec<(char, u32, u32)> = value.chars().map(|c| (c, 0, 0)).collect();
+        let body = tokenize(&chars);
+        self.macros.insert(
+            name.to_string(),
+            Macro {
+                name: name.to_string(),
+                params: None,
+                variadic: false,
+                body,
+                file: String::from("<built-in>"),
+                line: 0,
+            },
+        );
+    }
+
+    // -DNAME 或 -DNAME=VALUE
+    fn define_from_command_line(&mut self, def: &str) {
+        let (name, value) = match def.split_once('=') {
+            Some((n, v)) => (n, v),
+            None => (def, "1"),
+        };
+        let text = format!("{} {}", name, value);
+        let chars: Vec<(char, u32, u32)> = text.chars().map(|c| (c, 0, 0)).collect();
+        let tokens = tokenize(&chars);
+        let ctx = FileCtx {
+            path: PathBuf::new(),
+            name: String::from("<command line>"),
+            line_delta: 0,
+            file_id: 0,
+            src: Arc::from(""),
+            line_starts: vec![0],
+        };
+        self.define(&tokens, &ctx, 0);
+    }
+
+    fn error(&mut self, ctx: &FileCtx, line: u32, col: u32, message: String) {
+        self.errors.push(PPError {
+            file: ctx.name.clone(),
+            line: ctx.presumed_line(line),
+            col,
+            message,
+        });
+    }
+
+    fn warning(&self, ctx: &FileCtx, line: u32, col: u32, message: String) {
+        eprintln!(
+            "{}:{}:{}: warning: {}",
+            ctx.name,
+            ctx.presumed_line(line),
+            col,
+            message
+        );
+    }
+
+    // 源文件必须是 UTF-8，不合法的字节在换成 U+FFFD 之前报告出来，列号按字符计
+    fn check_utf8(&mut self, buf: &[u8], name: &str) {
+        let mut rest = buf;
+        while let Err(e) = std::str::from_utf8(rest) {
+            let offset = buf.len() - rest.len() + e.valid_up_to();
+            let line_start = buf[..offset]
+                .iter()
+                .rposition(|&b| b == b'\n')
+                .map_or(0, |k| k + 1);
+            self.errors.push(PPError {
+                file: name.to_string(),
+                line: buf[..offset].iter().filter(|&&b| b == b'\n').count() as u32 + 1,
+                col: String::from_utf8_lossy(&buf[line_start..offset])
+                    .chars()
+                    .count() as u32
+                    + 1,
+                message: format!(
+                    "invalid UTF-8 byte 0x{:02x} at offset {}",
+                    buf[offset], offset
+                ),
+            });
+            let Some(len) = e.error_len() else {
+                break;
+            };
+            rest = &buf[offset + len..];
+        }
+    }
+
+    fn process_file(&mut self, path: &Path, name: String, from: Option<(&FileCtx, u32, u32)>) {
+        if self.depth > MAX_INCLUDE_DEPTH {
+            if let Some((ctx, line, col)) = from {
+                self.error(ctx, line, col, String::from("#include nested too deeply"));
+            }
+            return;
+        }
+        let canonical = fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+        if self.pragma_once.contains(&canonical) {
+            return;
+        }
+        let buf = match fs::read(path) {
+            Ok(buf) => buf,
+            Err(e) => {
+                let message = format!("{}: {}", name, e);
+                match from {
+                    Some((ctx, line, col)) => self.error(ctx, line, col, message),
+                    None => self.errors.push(PPError {
+                        file: name.clone(),
+                        line: 0,
+                        col: 0,
+                        message,
+                    }),
+                }
+                return;
+            }
+        };
+        self.check_utf8(&buf, &name);
+        let src: Arc<str> = Arc::from(String::from_utf8_lossy(&buf).as_ref());
+        let (lines, unterminated) = split_logical_lines(&src);
+        let mut line_starts = vec![0];
+        line_starts.extend(src.match_indices('\n').map(|(k, _)| k + 1));
+        let mut ctx = FileCtx {
+            path: canonical,
+            file_id: register_file(&name, src.clone()),
+            name,
+            line_delta: 0,
+            src,
+            line_starts,
+        };
+        if let Some((line, col)) = unterminated {
+            self.error(&ctx, line, col, String::from("unterminated comment"));
+        }
+        let mut conds: Vec<CondFrame> = Vec::new();
+        let mut idx = 0;
+        while idx < lines.len() {
+            let mut tokens = tokenize(&lines[idx]);
+            idx += 1;
+            if tokens.is_empty() {
+                continue;
+            }
+            if tokens[0].is_punct("#") || tokens[0].is_punct("%:") {
+                self.directive(&tokens, &mut ctx, &mut conds);
+                continue;
+            }
+            if !conds.iter().all(|f| f.active) {
+                continue;
+            }
+            // 函数式宏的实参可以跨行，把后面的行拼进来
+            while idx < lines.len() && self.has_unfinished_call(&tokens) {
+                let next = tokenize(&lines[idx]);
+                if next
+                    .first()
+                    .is_some_and(|t| t.is_punct("#") || t.is_punct("%:"))
+                {
+                    break;
+                }
+                tokens.extend(next);
+                idx += 1;
+            }
+            let expanded = self.expand(tokens, &ctx);
+            self.emit(expanded, &ctx);
+        }
+        if let Some(frame) = conds.last() {
+            let line = frame.line;
+            self.error(
+                &ctx,
+                line,
+                1,
+                String::from("unterminated conditional directive"),
+            );
+        }
+        // 主文件读完的地方就是输入结束的地方
+        if from.is_none() {
+            self.out.eof = ctx.end_span();
+        }
+    }
+
+    fn has_unfinished_call(&self, tokens: &[PPToken]) -> bool {
+        let mut i = 0;
+        while i < tokens.len() {
+            let tok = &tokens[i];
+            let is_func_macro = tok.types == PPTokenType::Ident
+                && self
+                    .macros
+                    .get(&tok.value)
+                    .is_some_and(|m| m.params.is_some());
+            if is_func_macro {
+                if i + 1 == tokens.len() {
+                    return true;
+                }
+                if tokens[i + 1].is_punct("(") {
+                    let mut depth = 0;
+                    let mut j = i + 1;
+                    while j < tokens.len() {
+                        if tokens[j].is_punct("(") {
+                            depth += 1;
+                        } else if tokens[j].is_punct(")") {
+                            depth -= 1;
+                            if depth == 0 {
+                                break;
+                            }
+                        }
+                        j += 1;
+                    }
+                    if j == tokens.len() {
+                        return true;
+                    }
+                    i = j;
+                }
+            }
+            i += 1;
+        }
+        false
+    }
+
+    fn directive(&mut self, tokens: &[PPToken], ctx: &mut FileCtx, conds: &mut Vec<CondFrame>) {
+        let hash = &tokens[0];
+        let (line, col) = (hash.line, hash.col);
+        let active = conds.iter().all(|f| f.active);
+        let name = match tokens.get(1) {
+            // 空指令
+            None => return,
+            Some(tok) => tok.value.clone(),
+        };
+        let rest = &tokens[2..];
+        match name.as_str() {
+            "if" | "ifdef" | "ifndef" => {
+                let value = if !active {
+                    false
+                } else if name == "if" {
+                    self.condition(rest, ctx, line, col)
+                } else {
+                    match rest.first() {
+                        Some(tok) if tok.types == PPTokenType::Ident => {
+                            self.macros.contains_key(&tok.value) == (name == "ifdef")
+                        }
+                        _ => {
+                            self.error(ctx, line, col, format!("macro name missing in #{}", name));
+                            false
+                        }
+                    }
+                };
+                conds.push(CondFrame {
+                    active: value,
+                    // 外层不生效时，整个条件组都跳过
+                    taken: value || !active,
+                    seen_else: false,
+                    line,
+                });
+            }
+            "elif" => {
+                let parent_active =
+                    conds.len() < 2 || conds[..conds.len() - 1].iter().all(|f| f.active);
+                let (taken, seen_else) = match conds.last() {
+                    Some(frame) => (frame.taken, frame.seen_else),
+                    None => {
+                        self.error(ctx, line, col, String::from("#elif without #if"));
+                        return;
+                    }
+                };
+                if seen_else {
+                    self.error(ctx, line, col, String::from("#elif after #else"));
+                }
+                let value = !taken && parent_active && self.condition(rest, ctx, line, col);
+                let frame = conds.last_mut().unwrap();
+                frame.active = value;
+                frame.taken = taken || value;
+            }
+            "else" => match conds.last_mut() {
+                Some(frame) => {
+                    let seen = frame.seen_else;
+                    frame.seen_else = true;
+                    frame.active = !frame.taken;
+                    frame.taken = true;
+                    if seen {
+                        self.error(ctx, line, col, String::from("#else after #else"));
+                    }
+                }
+                None => self.error(ctx, line, col, String::from("#else without #if")),
+            },
+            "endif" => {
+                if conds.pop().is_none() {
+                    self.error(ctx, line, col, String::from("#endif without #if"));
+                }
+            }
+            _ if !active => {}
+            "define" => self.define(rest, ctx, line),
+            "undef" => match rest.first() {
+                Some(tok) if tok.types == PPTokenType::Ident => {
+                    self.macros.remove(&tok.value);
+                }
+                _ => self.error(ctx, line, col, String::from("macro name missing in #undef")),
+            },
+            "include" => self.include(rest, ctx, line, col),
+            "line" => self.line_directive(rest, ctx, line, col),
+            "error" => self.error(ctx, line, col, format!("#error {}", spell(rest))),
+            "warning" => self.warning(ctx, line, col, format!("#warning {}", spell(rest))),
+            "pragma" => {
+                if rest.first().is_some_and(|t| t.is_ident("once")) {
+                    self.pragma_once.insert(ctx.path.clone());
+                }
+                // 其他 pragma 忽略
+            }
+            _ if tokens[1].types == PPTokenType::Number => {
+                // GNU 行标记 # 33 "file"
+                self.line_directive(&tokens[1..], ctx, line, col)
+            }
+            _ => self.error(
+                ctx,
+                line,
+                col,
+                format!("invalid preprocessing directive #{}", name),
+            ),
+        }
+    }
+
+    fn condition(&mut self, rest: &[PPToken], ctx: &FileCtx, line: u32, col: u32) -> bool {
+        // 先处理 defined，再展开宏
+        let mut replaced: Vec<PPToken> = Vec::new();
+        let mut i = 0;
+        while i < rest.len() {
+            let tok = &rest[i];
+            if tok.is_ident("defined") {
+                let (name, next) = if rest.get(i + 1).is_some_and(|t| t.is_punct("(")) {
+                    match (rest.get(i + 2), rest.get(i + 3)) {
+                        (Some(n), Some(rp)) if rp.is_punct(")") => (Some(n), i + 4),
+                        _ => (None, i + 2),
+                    }
+                } else {
+                    (rest.get(i + 1), i + 2)
+                };
+                match name {
+                    Some(n) if n.types == PPTokenType::Ident => {
+                        let value = if self.macros.contains_key(&n.value) {
+                            "1"
+                        } else {
+                            "0"
+                        };
+                        let mut t =
+                            PPToken::new(PPTokenType::Number, value.to_string(), tok.line, tok.col);
+                        t.spaced = tok.spaced;
+                        replaced.push(t);
+                        i = next;
+                        continue;
+                    }
+                    _ => {
+                        self.error(
+                            ctx,
+                            line,
+                            col,
+                            String::from("operator \"defined\" requires an identifier"),
+                        );
+                        return false;
+                    }
+                }
+            }
+            replaced.push(tok.clone());
+            i += 1;
+        }
+        let expanded = self.expand(replaced, ctx);
+        match eval(&expanded) {
+            Ok(v) => v != 0,
+            Err(message) => {
+                self.error(ctx, line, col, message);
+                false
+            }
+        }
+    }
+
+    fn define(&mut self, rest: &[PPToken], ctx: &FileCtx, line: u32) {
+        let name_tok = match rest.first() {
+            Some(tok) if tok.types == PPTokenType::Ident => tok,
+            Some(tok) => {
+                let col = tok.col;
+                self.error(
+                    ctx,
+                    line,
+                    col,
+                    String::from("macro names must be identifiers"),
+                );
+                return;
+            }
+            None => {
+                self.error(
+                    ctx,
+                    line,
+                    1,
+                    String::from("no macro name given in #define directive"),
+                );
+                return;
+            }
+        };
+        let name = name_tok.value.clone();
+        if name == "defined" {
+            self.error(
+                ctx,
+                line,
+                name_tok.col,
+                String::from("\"defined\" cannot be used as a macro name"),
+            );
+            return;
+        }
+        let mut params: Option<Vec<String>> = None;
+        let mut variadic = false;
+        let mut i = 1;
+        // 名字后紧跟 ( 才是函数式宏
+        if rest.get(1).is_some_and(|t| t.is_punct("(") && !t.spaced) {
+            let mut list = Vec::new();
+            i = 2;
+            loop {
+                match rest.get(i) {
+                    Some(t) if t.is_punct(")") && list.is_empty() && !variadic => {
+                        i += 1;
+                        break;
+                    }
+                    Some(t) if t.is_punct("...") => {
+                        variadic = true;
+                        list.push(String::from("__VA_ARGS__"));
+                        i += 1;
+                    }
+                    Some(t) if t.types == PPTokenType::Ident => {
+                        if list.contains(&t.value) {
+                            let col = t.col;
+                            self.error(
+                                ctx,
+                                line,
+                                col,
+                                format!("duplicate macro parameter \"{}\"", t.value),
+                            );
+                            return;
+                        }
+                        list.push(t.value.clone());
+                        i += 1;
+                        // GNU 具名可变参数 args...
+                        if rest.get(i).is_some_and(|t| t.is_punct("...")) {
+                            variadic = true;
+                            i += 1;
+                        }
+                    }
+                    _ => {
+                        self.error(
+                            ctx,
+                            line,
+                            name_tok.col,
+                            String::from("expected parameter name in macro parameter list"),
+                        );
+                        return;
+                    }
+                }
+                match rest.get(i) {
+                    Some(t) if t.is_punct(",") && !variadic => i += 1,
+                    Some(t) if t.is_punct(")") => {
+                        i += 1;
+                        break;
+                    }
+                    _ => {
+                        self.error(
+                            ctx,
+                            line,
+                            name_tok.col,
+                            String::from("expected ',' or ')' in macro parameter list"),
+                        );
+                        return;
+                    }
+                }
+            }
+            params = Some(list);
+        }
+        let mut body: Vec<PPToken> = rest[i..].to_vec();
+        if let Some(first) = body.first_mut() {
+            first.spaced = false;
+        }
+        let is_paste = |t: &PPToken| t.is_punct("##") || t.is_punct("%:%:");
+        if body.first().is_some_and(is_paste) || body.last().is_some_and(is_paste) {
+            self.error(
+                ctx,
+                line,
+                name_tok.col,
+                String::from("'##' cannot appear at either end of a macro expansion"),
+            );
+            return;
+        }
+        if let Some(list) = &params {
+            for (k, t) in body.iter().enumerate() {
+                if (t.is_punct("#") || t.is_punct("%:"))
+                    && !body
+                        .get(k + 1)
+                        .is_some_and(|p| p.types == PPTokenType::Ident && list.contains(&p.value))
+                {
+                    self.error(
+                        ctx,
+                        line,
+                        t.col,
+                        String::from("'#' is not followed by a macro parameter"),
+                    );
+                    return;
+                }
+            }
+        }
+        let mac = Macro {
+            name: name.clone(),
+            params,
+            variadic,
+            body,
+            file: ctx.name.clone(),
+            line: ctx.presumed_line(line),
+        };
+        if let Some(old) = self.macros.get(&name)
+            && !same_definition(old, &mac)
+        {
+            self.warning(ctx, line, name_tok.col, format!("\"{}\" redefined", name));
+        }
+        self.macros.insert(name, mac);
+    }
+
+    fn include(&mut self, rest: &[PPToken], ctx: &FileCtx, line: u32, col: u32) {
+        let mut tokens = rest.to_vec();
+        // 第一次不匹配时先展开宏再试一次
+        for attempt in 0..2 {
+            let target = match tokens.first() {
+                Some(t) if t.quoted().is_some() => Some((t.quoted().unwrap().to_string(), true)),
+                Some(t) if t.is_punct("<") => {
+                    let end = tokens.iter().position(|t| t.is_punct(">"));
+                    end.map(|end| {
+                        let mut name = String::new();
+                        for (k, t) in tokens[1..end].iter().enumerate() {
+                            if k > 0 && t.spaced {
+                                name.push(' ');
+                            }
+                            name.push_str(&t.value);
+                        }
+                        (name, false)
+                    })
+                }
+                _ => None,
+            };
+            if let Some((name, quoted)) = target {
+                match self.find_include(&name, quoted, ctx) {
+                    Some(path) => {
+                        self.depth += 1;
+                        let display = path.to_string_lossy().to_string();
+                        self.process_file(&path, display, Some((ctx, line, col)));
+                        self.depth -= 1;
+                    }
+                    None => self.error(ctx, line, col, format!("'{}' file not found", name)),
+                }
+                return;
+            }
+            if attempt == 0 {
+                tokens = self.expand(tokens, ctx);
+            }
+        }
+        self.error(
+            ctx,
+            line,
+            col,
+            String::from("#include expects \"FILENAME\" or <FILENAME>"),
+        );
+    }
+
+    fn find_include(&self, name: &str, quoted: bool, ctx: &FileCtx) -> Option<PathBuf> {
+        let mut dirs: Vec<PathBuf> = Vec::new();
+        if quoted && let Some(dir) = ctx.path.parent() {
+            dirs.push(dir.to_path_buf());
+        }
+        dirs.extend(self.include_dirs.iter().cloned());
+        if Path::new(name).is_absolute() {
+            let path = PathBuf::from(name);
+            return path.is_file().then_some(path);
+        }
+        dirs.into_iter().map(|d| d.join(name)).find(|p| p.is_file())
+    }
+
+    fn line_directive(&mut self, rest: &[PPToken], ctx: &mut FileCtx, line: u32, col: u32) {
+        let tokens = self.expand(rest.to_vec(), ctx);
+        let number = tokens
+            .first()
+            .filter(|t| t.types == PPTokenType::Number)
+            .and_then(|t| t.value.parse::<u32>().ok());
+        match number {
+            Some(n) => {
+                // 文件名不对时整条指令不生效，错误报在指令原来的行号上
+                if let Some(file) = tokens.get(1) {
+                    let Some(name) = file.quoted() else {
+                        self.error(
+                            ctx,
+                            line,
+                            file.col,
+                            format!("invalid filename \"{}\"", file.value),
+                        );
+                        return;
+                    };
+                    ctx.name = name.to_string();
+                    ctx.file_id = register_file(&ctx.name, ctx.src.clone());
+                }
+                // 指令的下一行行号为 n
+                let last_line = rest.last().map_or(line, |t| t.line);
+                ctx.line_delta = n as i64 - (last_line as i64 + 1);
+            }
+            None => self.error(
+                ctx,
+                line,
+                col,
+                String::from("#line directive requires a positive integer argument"),
+            ),
+        }
+    }
+
+    // 宏展开，用 hideset 阻止递归展开
+    fn expand(&mut self, tokens: Vec<PPToken>, ctx: &FileCtx) -> Vec<PPToken> {
+        let mut input: VecDeque<PPToken> = tokens.into();
+        let mut output: Vec<PPToken> = Vec::new();
+        while let Some(tok) = input.pop_front() {
+            if tok.types != PPTokenType::Ident || tok.hideset.contains(&tok.value) {
+                output.push(tok);
+                continue;
+            }
+            if tok.value == "__FILE__" || tok.value == "__LINE__" {
+                let (types, value) = if tok.value == "__FILE__" {
+                    (PPTokenType::Str, quote_file_name(&ctx.name))
+                } else {
+                    (PPTokenType::Number, ctx.presumed_line(tok.line).to_string())
+                };
+                let mut t = PPToken::new(types, value, tok.line, tok.col);
+                t.spaced = tok.spaced;
+                t.expansions = tok.expansions.clone();
+                output.push(t);
+                continue;
+            }
+            let mac = match self.macros.get(&tok.value) {
+                Some(m) => m.clone(),
+                None => {
+                    output.push(tok);
+                    continue;
+                }
+            };
+            let mut hideset = tok.hideset.clone();
+            let body = match &mac.params {
+                None => mac.body.clone(),
+                Some(params) => {
+                    if !input.front().is_some_and(|t| t.is_punct("(")) {
+                        output.push(tok);
+                        continue;
+                    }
+                    let (args, rparen) = match self.collect_args(&mut input, &mac, params.len()) {
+                        Some(r) => r,
+                        None => {
+                            self.error(
+                                ctx,
+                                tok.line,
+                                tok.col,
+                                format!(
+                                    "unterminated argument list invoking macro \"{}\"",
+                                    mac.name
+                                ),
+                            );
+                            output.push(tok);
+                            continue;
+                        }
+                    };
+                    if args.len() != params.len() {
+                        self.error(
+                            ctx,
+                            tok.line,
+                            tok.col,
+                            format!(
+                                "macro \"{}\" requires {} arguments, but {} given",
+                                mac.name,
+                                params.len(),
+                                args.len()
+                            ),
+                        );
+                        output.push(tok);
+                        continue;
+                    }
+                    hideset.retain(|h| rparen.hideset.contains(h));
+                    self.substitute(&mac, params, &args, ctx)
+                }
+            };
+            hideset.push(mac.name.clone());
+            let expansion = Expansion {
+                name: mac.name.clone(),
+                def_file: mac.file.clone(),
+                def_line: mac.line,
+                use_line: ctx.presumed_line(tok.line),
+            };
+            for (k, mut t) in body.into_iter().enumerate().rev() {
+                for h in &hideset {
+                    if !t.hideset.contains(h) {
+                        t.hideset.push(h.clone());
+                    }
+                }
+                t.line = tok.line;
+                t.col = tok.col;
+                let mut expansions = tok.expansions.clone();
+                expansions.push(expansion.clone());
+                t.expansions = expansions;
+                if k == 0 {
+                    t.spaced = tok.spaced;
+                }
+                input.push_front(t);
+            }
+        }
+        output
+    }
+
+    // 收集实参，返回实参列表和右括号
+    fn collect_args(
+        &self,
+        input: &mut VecDeque<PPToken>,
+        mac: &Macro,
+        nparams: usize,
+    ) -> Option<(Vec<Vec<PPToken>>, PPToken)> {
+        let mut taken: Vec<PPToken> = vec![input.pop_front().unwrap()];
+        let mut args: Vec<Vec<PPToken>> = vec![vec![]];
+        let mut depth = 0;
+        loop {
+            let t = match input.pop_front() {
+                Some(t) => t,
+                None => {
+                    // 恢复输入，交给调用方报错
+                    for t in taken.into_iter().rev() {
+                        input.push_front(t);
+                    }
+                    return None;
+                }
+            };
+            taken.push(t.clone());
+            if t.is_punct(")") && depth == 0 {
+                // F() 对于无参宏是 0 个实参
+                if nparams == 0 && args.len() == 1 && args[0].is_empty() {
+                    args.clear();
+                }
+                // 可变参数为空的情况 F(a) 对应 F(a, ...)
+                if mac.variadic && args.len() + 1 == nparams {
+                    args.push(vec![]);
+                }
+                return Some((args, t));
+            }
+            if t.is_punct(",") && depth == 0 && !(mac.variadic && args.len() == nparams) {
+                args.push(vec![]);
+                continue;
+            }
+            if t.is_punct("(") {
+                depth += 1;
+            } else if t.is_punct(")") {
+                depth -= 1;
+            }
+            args.last_mut().unwrap().push(t);
+        }
+    }
+
+    fn substitute(
+        &mut self,
+        mac: &Macro,
+        params: &[String],
+        args: &[Vec<PPToken>],
+        ctx: &FileCtx,
+    ) -> Vec<PPToken> {
+        let param_index = |t: Option<&PPToken>| -> Option<usize> {
+            t.filter(|t| t.types == PPTokenType::Ident)
+                .and_then(|t| params.iter().position(|p| *p == t.value))
+        };
+        let is_paste =
+            |t: Option<&PPToken>| t.is_some_and(|t| t.is_punct("##") || t.is_punct("%:%:"));
+        let placemarker = |t: &PPToken| {
+            let mut p = PPToken::new(PPTokenType::Placemarker, String::new(), t.line, t.col);
+            p.spaced = t.spaced;
+            p
+        };
+        let body = &mac.body;
+        let mut out: Vec<PPToken> = Vec::new();
+        let mut i = 0;
+        while i < body.len() {
+            let t = &body[i];
+            if (t.is_punct("#") || t.is_punct("%:"))
+                && let Some(idx) = param_index(body.get(i + 1))
+            {
+                let mut s = PPToken::new(PPTokenType::Str, stringify(&args[idx]), t.line, t.col);
+                s.spaced = t.spaced;
+                out.push(s);
+                i += 2;
+                continue;
+            }
+            if is_paste(Some(t)) {
+                let rhs: Vec<PPToken> = match param_index(body.get(i + 1)) {
+                    Some(idx) if args[idx].is_empty() => vec![placemarker(&body[i + 1])],
+                    Some(idx) => args[idx].clone(),
+                    None => vec![body[i + 1].clone()],
+                };
+                let lhs = out.pop().unwrap_or_else(|| placemarker(t));
+                let mut rhs = rhs.into_iter();
+                let first = rhs.next().unwrap();
+                out.push(self.paste(lhs, first, ctx));
+                out.extend(rhs);
+                i += 2;
+                continue;
+            }
+            if let Some(idx) = param_index(Some(t)) {
+                let mut toks = if is_paste(body.get(i + 1)) {
+                    if args[idx].is_empty() {
+                        vec![placemarker(t)]
+                    } else {
+                        args[idx].clone()
+                    }
+                } else {
+                    self.expand(args[idx].clone(), ctx)
+                };
+                if let Some(first) = toks.first_mut() {
+                    first.spaced = t.spaced;
+                }
+                out.extend(toks);
+                i += 1;
+                continue;
+            }
+            out.push(t.clone());
+            i += 1;
+        }
+        out.retain(|t| t.types != PPTokenType::Placemarker);
+        out
+    }
+
+    // ## 运算符：拼接两个记号，结果必须是一个合法记号
+    fn paste(&mut self, lhs: PPToken, rhs: PPToken, ctx: &FileCtx) -> PPToken {
+        if lhs.types == PPTokenType::Placemarker {
+            let mut r = rhs;
+            r.spaced = lhs.spaced;
+            return r;
+        }
+        if rhs.types == PPTokenType::Placemarker {
+            return lhs;
+        }
+        let text = format!("{}{}", lhs.value, rhs.value);
+        let chars: Vec<(char, u32, u32)> = text.chars().map(|c| (c, lhs.line, lhs.col)).collect();
+        let mut tokens = tokenize(&chars);
+        if tokens.len() != 1 {
+            self.error(
+                ctx,
+                lhs.line,
+                lhs.col,
+                format!(
+                    "pasting \"{}\" and \"{}\" does not give a valid preprocessing token",
+                    lhs.value, rhs.value
+                ),
+            );
+            return lhs;
+        }
+        let mut t = tokens.pop().unwrap();
+        t.spaced = lhs.spaced;
+        t.hideset = lhs.hideset;
+        t.expansions = lhs.expansions;
+        t
+    }
+
+    // 输出展开后的记号，源码换行的地方照样换行，列号尽量保持原样
+    fn emit(&mut self, tokens: Vec<PPToken>, ctx: &FileCtx) {
+        let mut line: Option<u32> = None;
+        let mut text = String::new();
+        let mut width = 0;
+        let mut expansions: Vec<Expansion> = Vec::new();
+        let mut prev: Option<PPToken> = None;
+        for tok in tokens {
+            if line.is_none_or(|l| tok.line > l) {
+                if let Some(l) = line {
+                    self.flush_line(&mut text, ctx, l, &mut expansions);
+                }
+                line = Some(tok.line);
+                width = 0;
+                prev = None;
+            }
+            if width + 1 < tok.col as usize {
+                text.push_str(&" ".repeat(tok.col as usize - 1 - width));
+                width = tok.col as usize - 1;
+            } else if let Some(p) = &prev
+                && (tok.spaced || need_space(p, &tok))
+            {
+                text.push(' ');
+                width += 1;
+            }
+            text.push_str(&tok.value);
+            width += tok.value.chars().count();
+            for exp in &tok.expansions {
+                if !expansions.contains(exp) {
+                    expansions.push(exp.clone());
+                }
+            }
+            prev = Some(tok);
+        }
+        if let Some(l) = line {
+            self.flush_line(&mut text, ctx, l, &mut expansions);
+        }
+    }
+
+    fn flush_line(
+        &mut self,
+        text: &mut String,
+        ctx: &FileCtx,
+        line: u32,
+        expansions: &mut Vec<Expansion>,
+    ) {
+        self.out.text.push_str(text);
+        self.out.text.push('\n');
+        self.out.lines.push(LineOrigin {
+            file: ctx.name.clone(),
+            file_id: ctx.file_id,
+            line: ctx.presumed_line(line),
+            line_start: ctx.line_starts.get(line as usize - 1).copied().unwrap_or(0),
+            expansions: std::mem::take(expansions),
+        });
+        text.clear();
+    }
+}
+
+fn same_definition(a: &Macro, b: &Macro) -> bool {
+    a.params == b.params
+        && a.body.len() == b.body.len()
+        && a.body
+            .iter()
+            .zip(b.body.iter())
+            .enumerate()
+            .all(|(k, (x, y))| x.value == y.value && (k == 0 || x.spaced == y.spaced))
+}
@@ -0,0 +1,116 @@
+use crate::utils::types::Span;
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PPTokenType {
+    Ident,
+    Number,
+    Char,
+    Str,
+    Punct,
+    Other,
+    // ## 两侧为空实参时使用的占位符
+    Placemarker,
+}
+
+// 一次宏展开的记录：展开了哪个宏，宏定义在哪里，在哪一行被调用
+#[derive(Debug, Clone, PartialEq)]
+pub struct Expansion {
+    pub name: String,
+    pub def_file: String,
+    pub def_line: u32,
+    pub use_line: u32,
+}
+
+#[derive(Debug, Clone)]
+pub struct PPToken {
+    pub types: PPTokenType,
+    pub value: String,
+    // 前面是否有空白，字符串化和输出时需要
+    pub spaced: bool,
+    pub line: u32,
+    pub col: u32,
+    // 已经展开过的宏，防止递归展开
+    pub hideset: Vec<String>,
+    // 宏展开回溯，最外层在前
+    pub expansions: Vec<Expansion>,
+}
+
+impl PPToken {
+    pub fn new(types: PPTokenType, value: String, line: u32, col: u32) -> PPToken {
+        PPToken {
+            types,
+            value,
+            spaced: false,
+            line,
+            col,
+            hideset: vec![],
+            expansions: vec![],
+        }
+    }
+
+    pub fn is_punct(&self, p: &str) -> bool {
+        self.types == PPTokenType::Punct && self.value == p
+    }
+
+    pub fn is_ident(&self, id: &str) -> bool {
+        self.types == PPTokenType::Ident && self.value == id
+    }
+
+    // 去掉双引号的字符串内容，没有闭合的引号时返回 None
+    pub fn quoted(&self) -> Option<&str> {
+        if self.types != PPTokenType::Str || self.value.len() < 2 {
+            return None;
+        }
+        self.value.strip_prefix('"')?.strip_suffix('"')
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Macro {
+    pub name: String,
+    // None 表示对象式宏
+    pub params: Option<Vec<String>>,
+    pub variadic: bool,
+    pub body: Vec<PPToken>,
+    pub file: String,
+    pub line: u32,
+}
+
+// 预处理输出中每一行对应的源码位置
+#[derive(Debug, Clone, PartialEq)]
+pub struct LineOrigin {
+    pub file: String,
+    // 源文件编号，见 utils::session::register_file
+    pub file_id: u32,
+    pub line: u32,
+    // 对应物理行在源文件中的字节偏移
+    pub line_start: usize,
+    pub expansions: Vec<Expansion>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Preprocessed {
+    pub text: String,
+    pub lines: Vec<LineOrigin>,
+    // 输入结束的位置：主文件最后一行的行尾
+    pub eof: Span,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PPError {
+    pub file: String,
+    pub line: u32,
+    pub col: u32,
+    pub message: String,
+}
+
+impl fmt::Display for PPError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}:{}: error: {}",
+            self.file, self.line, self.col, self.message
+        )
+    }
+}
@@ -2,9 +2,38 @@ use crate::ast::types::ASTNode;
 use crate::ir::irgen::IrType;
 use crate::lexer::lexer::*;
 use crate::parser::parse::CSTNode;
+use once_cell::sync::Lazy;
+use std::sync::Mutex;
 
-use super::types::PhraseType;
+use super::types::Symbol;
+use std::collections::HashMap;
 
+// 标识符驻留：同名标识符共用一个 Symbol，字符串只保存一份
+#[derive(Default)]
+struct Interner {
+    symbols: HashMap<&'static str, Symbol>,
+    names: Vec<&'static str>,
+}
+
+static INTERNER: Lazy<Mutex<Interner>> = Lazy::new(|| Mutex::new(Interner::default()));
+
+pub fn intern(name: &str) -> Symbol {
+    let mut interner = INTERNER.lock().unwrap();
+    if let Some(sym) = interner.symbols.get(name) {
+        return *sym;
+    }
+    let name: &'static str = Box::leak(name.to_string().into_boxed_str());
+    let sym = Symbol(interner.names.len() as u32);
+    interner.names.push(name);
+    interner.symbols.insert(name, sym);
+    sym
+}
+
+pub fn resolve(sym: Symbol) -> &'static str {
+    INTERNER.lock().unwrap().names[sym.0 as usize]
+}
+
+#[allow(dead_code)]
 pub fn print_cst(node: &Vec<CSTNode>) {
     println!("{:?}", node.len());
     for val in node {
@@ -20,9 +49,10 @@ pub fn print_ast(node: &Vec<ASTNode>) {
     }
 }
 
+#[allow(dead_code)]
 pub fn print_tokens(tokens: &Vec<Token>) {
     for token in tokens {
-        println!("{:?} {:?}", token.types, token.value);
+        println!("{:?} {}", token.kind, token);
     }
 }
 
@@ -31,23 +61,3 @@ pub fn print_ir(tokens: &Vec<IrType>) {
         println!("{:?}", token);
     }
 }
-
-pub fn print_err_info(err_info: Vec<String>) {
-    for info in err_info {
-        println!("{:?}", info);
-    }
-}
-
-pub fn symbol_is_literal(symbol: &PhraseType) -> bool {
-    match symbol {
-        PhraseType::Identifier => false,
-        PhraseType::Bool
-        | PhraseType::String
-        | PhraseType::Hex
-        | PhraseType::Oct
-        | PhraseType::Dec
-        | PhraseType::Float
-        | PhraseType::Char => true,
-        _ => unreachable!(),
-    }
-}
@@ -1,2 +1,3 @@
 pub mod helper;
+pub mod session;
 pub mod types;
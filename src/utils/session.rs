@@ -0,0 +1,115 @@
+use super::types::SourceFile;
+use std::cell::RefCell;
+use std::sync::Arc;
+
+// 一次编译读入的源文件，Span::file 是这里的下标，0 号留给内建位置
+pub struct SourceMap {
+    files: Vec<SourceFile>,
+}
+
+impl SourceMap {
+    fn new() -> SourceMap {
+        SourceMap {
+            files: vec![SourceFile {
+                name: String::from("<built-in>"),
+                src: Arc::from(""),
+            }],
+        }
+    }
+
+    fn add_file(&mut self, name: &str, src: Arc<str>) -> u32 {
+        self.files.push(SourceFile {
+            name: name.to_string(),
+            src,
+        });
+        (self.files.len() - 1) as u32
+    }
+}
+
+thread_local! {
+    // 当前线程上正在进行的编译会话的源文件表
+    static ACTIVE: RefCell<Option<SourceMap>> = const { RefCell::new(None) };
+}
+
+// 编译会话：会话期间读入的源文件都登记在它的源文件表里，会话结束时一起释放
+// 会话之外不登记源文件，位置里的文件名都显示为 <input>
+pub struct Session {
+    // 外层会话的源文件表，这个会话结束时放回去
+    outer: Option<SourceMap>,
+}
+
+impl Session {
+    pub fn new() -> Session {
+        let outer = ACTIVE.with(|active| active.replace(Some(SourceMap::new())));
+        Session { outer }
+    }
+}
+
+impl Default for Session {
+    fn default() -> Session {
+        Session::new()
+    }
+}
+
+impl Drop for Session {
+    fn drop(&mut self) {
+        ACTIVE.with(|active| active.replace(self.outer.take()));
+    }
+}
+
+// 在当前会话里登记一个源文件，返回它的编号；没有会话时返回 0
+pub fn register_file(name: &str, src: Arc<str>) -> u32 {
+    ACTIVE.with(|active| {
+        active
+            .borrow_mut()
+            .as_mut()
+            .map_or(0, |files| files.add_file(name, src))
+    })
+}
+
+pub fn file_name(id: u32) -> String {
+    ACTIVE.with(|active| match active.borrow().as_ref() {
+        Some(files) => files
+            .files
+            .get(id as usize)
+            .map_or_else(|| String::from("<unknown>"), |f| f.name.clone()),
+        None => String::from("<input>"),
+    })
+}
+
+pub fn file_source(id: u32) -> Arc<str> {
+    ACTIVE.with(|active| {
+        active
+            .borrow()
+            .as_ref()
+            .and_then(|files| files.files.get(id as usize))
+            .map_or_else(|| Arc::from(""), |f| f.src.clone())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn files_live_as_long_as_the_session() {
+        assert_eq!(register_file("a.c", Arc::from("int a;")), 0);
+        assert_eq!(file_name(0), "<input>");
+        {
+            let _session = Session::new();
+            let id = register_file("a.c", Arc::from("int a;"));
+            assert_eq!(id, 1);
+            assert_eq!(file_name(id), "a.c");
+            assert_eq!(&*file_source(id), "int a;");
+            {
+                // 内层会话有自己的源文件表
+                let _inner = Session::new();
+                assert_eq!(file_name(id), "<unknown>");
+            }
+            assert_eq!(file_name(id), "a.c");
+        }
+        // 会话结束后源文件随之释放
+        assert_eq!(file_name(1), "<input>");
+        assert_eq!(&*file_source(1), "");
+    }
+}
@@ -1,15 +1,394 @@
+use super::helper::resolve;
+use super::session::file_name;
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+// 标识符在 INTERNER 中的编号
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Symbol(pub u32);
+
+impl Symbol {
+    pub fn as_str(&self) -> &'static str {
+        resolve(*self)
+    }
+}
+
+impl fmt::Debug for Symbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Symbol({:?})", self.as_str())
+    }
+}
+
+impl fmt::Display for Symbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
-pub enum PhraseType {
-    Hex,
-    Dec,
-    Oct,
-    Keyword,
+pub enum TokenKind {
+    Ident(Symbol),
+    // 整数常量的值和类型
+    IntLit(u64, NumType),
+    // 浮点常量的值和类型
+    FloatLit(f64, NumType),
+    // 字符常量，类型是 int
+    CharLit(i64),
+    // 字符串字面量的字节，不含结尾的 \0
+    StrLit(Vec<u8>),
+    BoolLit(bool),
+    // 关键字，uint 和 String 是 quickcc 的扩展
+    Auto,
+    Break,
+    Case,
+    Char,
+    Const,
+    Continue,
+    Default,
+    Do,
+    Double,
+    Else,
+    Enum,
+    Extern,
+    Float,
+    For,
+    Goto,
+    If,
+    Inline,
+    Int,
+    Long,
+    Register,
+    Restrict,
+    Return,
+    Short,
+    Signed,
+    Sizeof,
+    Static,
+    Struct,
+    Switch,
+    Typedef,
+    Union,
+    Unsigned,
+    Void,
+    Volatile,
+    While,
+    Alignas,
+    Alignof,
+    Atomic,
     Bool,
-    Identifier,
-    Operator,
-    Separator,
-    Unknown,
-    String,
+    Complex,
+    Generic,
+    Imaginary,
+    Noreturn,
+    StaticAssert,
+    ThreadLocal,
+    UInt,
+    StringKw,
+    // 运算符和分隔符
+    LBracket,
+    RBracket,
+    LParen,
+    RParen,
+    LBrace,
+    RBrace,
+    Dot,
+    Arrow,
+    PlusPlus,
+    MinusMinus,
+    Amp,
+    Star,
+    Plus,
+    Minus,
+    Tilde,
+    Bang,
+    Slash,
+    Percent,
+    Shl,
+    Shr,
+    Lt,
+    Gt,
+    Le,
+    Ge,
+    EqEq,
+    Ne,
+    Caret,
+    Pipe,
+    AmpAmp,
+    PipePipe,
+    Question,
+    Colon,
+    Semi,
+    Ellipsis,
+    Assign,
+    StarAssign,
+    SlashAssign,
+    PercentAssign,
+    PlusAssign,
+    MinusAssign,
+    ShlAssign,
+    ShrAssign,
+    AmpAssign,
+    CaretAssign,
+    PipeAssign,
+    Comma,
+    Hash,
+    HashHash,
+    Eof,
+}
+
+// 关键字和运算符的拼写只在这两张表里维护
+static KEYWORDS: &[(&str, TokenKind)] = &[
+    ("auto", TokenKind::Auto),
+    ("break", TokenKind::Break),
+    ("case", TokenKind::Case),
+    ("char", TokenKind::Char),
+    ("const", TokenKind::Const),
+    ("continue", TokenKind::Continue),
+    ("default", TokenKind::Default),
+    ("do", TokenKind::Do),
+    ("double", TokenKind::Double),
+    ("else", TokenKind::Else),
+    ("enum", TokenKind::Enum),
+    ("extern", TokenKind::Extern),
+    ("float", TokenKind::Float),
+    ("for", TokenKind::For),
+    ("goto", TokenKind::Goto),
+    ("if", TokenKind::If),
+    ("inline", TokenKind::Inline),
+    ("int", TokenKind::Int),
+    ("long", TokenKind::Long),
+    ("register", TokenKind::Register),
+    ("restrict", TokenKind::Restrict),
+    ("return", TokenKind::Return),
+    ("short", TokenKind::Short),
+    ("signed", TokenKind::Signed),
+    ("sizeof", TokenKind::Sizeof),
+    ("static", TokenKind::Static),
+    ("struct", TokenKind::Struct),
+    ("switch", TokenKind::Switch),
+    ("typedef", TokenKind::Typedef),
+    ("union", TokenKind::Union),
+    ("unsigned", TokenKind::Unsigned),
+    ("void", TokenKind::Void),
+    ("volatile", TokenKind::Volatile),
+    ("while", TokenKind::While),
+    ("_Alignas", TokenKind::Alignas),
+    ("_Alignof", TokenKind::Alignof),
+    ("_Atomic", TokenKind::Atomic),
+    ("_Bool", TokenKind::Bool),
+    ("_Complex", TokenKind::Complex),
+    ("_Generic", TokenKind::Generic),
+    ("_Imaginary", TokenKind::Imaginary),
+    ("_Noreturn", TokenKind::Noreturn),
+    ("_Static_assert", TokenKind::StaticAssert),
+    ("_Thread_local", TokenKind::ThreadLocal),
+    ("uint", TokenKind::UInt),
+    ("String", TokenKind::StringKw),
+];
+
+static PUNCTUATORS: &[(&str, TokenKind)] = &[
+    ("[", TokenKind::LBracket),
+    ("]", TokenKind::RBracket),
+    ("(", TokenKind::LParen),
+    (")", TokenKind::RParen),
+    ("{", TokenKind::LBrace),
+    ("}", TokenKind::RBrace),
+    (".", TokenKind::Dot),
+    ("->", TokenKind::Arrow),
+    ("++", TokenKind::PlusPlus),
+    ("--", TokenKind::MinusMinus),
+    ("&", TokenKind::Amp),
+    ("*", TokenKind::Star),
+    ("+", TokenKind::Plus),
+    ("-", TokenKind::Minus),
+    ("~", TokenKind::Tilde),
+    ("!", TokenKind::Bang),
+    ("/", TokenKind::Slash),
+    ("%", TokenKind::Percent),
+    ("<<", TokenKind::Shl),
+    (">>", TokenKind::Shr),
+    ("<", TokenKind::Lt),
+    (">", TokenKind::Gt),
+    ("<=", TokenKind::Le),
+    (">=", TokenKind::Ge),
+    ("==", TokenKind::EqEq),
+    ("!=", TokenKind::Ne),
+    ("^", TokenKind::Caret),
+    ("|", TokenKind::Pipe),
+    ("&&", TokenKind::AmpAmp),
+    ("||", TokenKind::PipePipe),
+    ("?", TokenKind::Question),
+    (":", TokenKind::Colon),
+    (";", TokenKind::Semi),
+    ("...", TokenKind::Ellipsis),
+    ("=", TokenKind::Assign),
+    ("*=", TokenKind::StarAssign),
+    ("/=", TokenKind::SlashAssign),
+    ("%=", TokenKind::PercentAssign),
+    ("+=", TokenKind::PlusAssign),
+    ("-=", TokenKind::MinusAssign),
+    ("<<=", TokenKind::ShlAssign),
+    (">>=", TokenKind::ShrAssign),
+    ("&=", TokenKind::AmpAssign),
+    ("^=", TokenKind::CaretAssign),
+    ("|=", TokenKind::PipeAssign),
+    (",", TokenKind::Comma),
+    ("#", TokenKind::Hash),
+    ("##", TokenKind::HashHash),
+    // 双字符替代写法
+    ("<:", TokenKind::LBracket),
+    (":>", TokenKind::RBracket),
+    ("<%", TokenKind::LBrace),
+    ("%>", TokenKind::RBrace),
+    ("%:", TokenKind::Hash),
+    ("%:%:", TokenKind::HashHash),
+];
+
+// 按拼写查表，每个 token 都要查一次
+static KEYWORD_MAP: Lazy<HashMap<&str, TokenKind>> =
+    Lazy::new(|| KEYWORDS.iter().cloned().collect());
+static PUNCTUATOR_MAP: Lazy<HashMap<&str, TokenKind>> =
+    Lazy::new(|| PUNCTUATORS.iter().cloned().collect());
+
+impl TokenKind {
+    pub fn keyword(word: &str) -> Option<TokenKind> {
+        KEYWORD_MAP.get(word).cloned()
+    }
+
+    pub fn punctuator(word: &str) -> Option<TokenKind> {
+        PUNCTUATOR_MAP.get(word).cloned()
+    }
+
+    // 关键字和运算符的拼写，其它 token 返回 None
+    pub fn as_str(&self) -> Option<&'static str> {
+        KEYWORDS
+            .iter()
+            .chain(PUNCTUATORS.iter())
+            .find(|(_, k)| k == self)
+            .map(|(s, _)| *s)
+    }
+
+    pub fn is_literal(&self) -> bool {
+        matches!(
+            self,
+            TokenKind::IntLit(..)
+                | TokenKind::FloatLit(..)
+                | TokenKind::CharLit(_)
+                | TokenKind::StrLit(_)
+                | TokenKind::BoolLit(_)
+        )
+    }
+
+    // 基本类型的类型说明符
+    pub fn is_type_keyword(&self) -> bool {
+        matches!(
+            self,
+            TokenKind::Int
+                | TokenKind::UInt
+                | TokenKind::Float
+                | TokenKind::Double
+                | TokenKind::Char
+                | TokenKind::StringKw
+        )
+    }
+}
+
+impl fmt::Display for TokenKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TokenKind::Ident(sym) => write!(f, "{}", sym),
+            TokenKind::IntLit(v, _) => write!(f, "{}", v),
+            TokenKind::FloatLit(v, _) => write!(f, "{:?}", v),
+            TokenKind::CharLit(v) => write!(f, "{}", v),
+            TokenKind::StrLit(bytes) => write!(f, "\"{}\"", bytes.escape_ascii()),
+            TokenKind::BoolLit(b) => write!(f, "{}", b),
+            TokenKind::Eof => write!(f, "$"),
+            kind => write!(f, "{}", kind.as_str().unwrap_or_default()),
+        }
+    }
+}
+
+// 数值字面量的类型，按 ILP32：int 和 long 是 32 位，long long 是 64 位
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NumType {
+    Int,
+    UInt,
+    Long,
+    ULong,
+    LongLong,
+    ULongLong,
     Float,
-    Char,
+    Double,
+    LongDouble,
+}
+
+impl NumType {
+    pub fn name(&self) -> &'static str {
+        match self {
+            NumType::Int => "int",
+            NumType::UInt => "unsigned int",
+            NumType::Long => "long",
+            NumType::ULong => "unsigned long",
+            NumType::LongLong => "long long",
+            NumType::ULongLong => "unsigned long long",
+            NumType::Float => "float",
+            NumType::Double => "double",
+            NumType::LongDouble => "long double",
+        }
+    }
+
+    // 整数类型能表示的最大值
+    pub fn max_value(&self) -> u64 {
+        match self {
+            NumType::Int | NumType::Long => i32::MAX as u64,
+            NumType::UInt | NumType::ULong => u32::MAX as u64,
+            NumType::LongLong => i64::MAX as u64,
+            _ => u64::MAX,
+        }
+    }
+
+    pub fn is_unsigned(&self) -> bool {
+        matches!(self, NumType::UInt | NumType::ULong | NumType::ULongLong)
+    }
+}
+
+// 源码位置：文件编号、行、列，以及在原文件中的字节范围 [lo, hi)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Span {
+    pub file: u32,
+    pub line: u32,
+    pub col: u32,
+    pub lo: usize,
+    pub hi: usize,
+}
+
+impl Span {
+    // 从 self 开始到 other 结束
+    pub fn to(self, other: Span) -> Span {
+        if self == Span::default() {
+            return other;
+        }
+        if other.file == self.file && other.hi > self.hi {
+            Span {
+                hi: other.hi,
+                ..self
+            }
+        } else {
+            self
+        }
+    }
+}
+
+impl fmt::Display for Span {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}:{}", file_name(self.file), self.line, self.col)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SourceFile {
+    pub name: String,
+    pub src: Arc<str>,
 }
@@ -0,0 +1,225 @@
+// 数组的下标、退化和花括号初始值
+mod common;
+
+use common::{compile, has};
+
+#[test]
+fn initialized_global_arrays_go_to_data() {
+    let out = compile(
+        "int g[5] = {1, 2, 3};\nint m[2][3] = {{1, 2}, {4}};\nchar s[4] = {'a', 'b'};\nint *p[2] = {g, &g[2]};\nint z[3] = {0};\nint main() { return g[0]; }\n",
+    )
+    .ok();
+    let (data, bss) = out.asm.split_once(".section .bss").unwrap();
+    // 没写的元素补 0
+    assert!(data.contains("g:\n    .word 1\n    .word 2\n    .word 3\n    .zero 8\n"));
+    assert!(data.contains("m:\n    .word 1\n    .word 2\n    .word 0\n    .word 4\n    .zero 8\n"));
+    assert!(data.contains("s:\n    .byte 97\n    .byte 98\n    .zero 2\n"));
+    assert!(data.contains("p:\n    .word g\n    .word g+8\n"));
+    // 全是 0 的数组和没有初始值一样
+    assert!(bss.contains("z:\n    .zero 12\n"));
+}
+
+#[test]
+fn unsized_array_takes_length_from_initializer() {
+    let out = compile(
+        "int e[] = {7, 8, 9,};\nint f[][2] = {1, 2, 3};\nint main() { return e[2] + f[1][0]; }\n",
+    )
+    .ok();
+    assert!(
+        out.asm
+            .contains("e:\n    .word 7\n    .word 8\n    .word 9\n")
+    );
+    assert!(
+        out.asm
+            .contains("f:\n    .word 1\n    .word 2\n    .word 3\n    .zero 4\n")
+    );
+}
+
+#[test]
+fn local_initializer_stores_every_element() {
+    let out = compile("int main() {\n    int a[4] = {10, 20};\n    return a[1];\n}\n").ok();
+    let stores: Vec<&str> = out
+        .stdout
+        .lines()
+        .filter(|line| line.contains("op: \"STORE\""))
+        .collect();
+    assert_eq!(stores.len(), 4);
+    assert!(stores[0].contains("src1: \"10\""));
+    assert!(stores[1].contains("src1: \"20\""));
+    assert!(stores[3].contains("src1: \"0\""));
+}
+
+#[test]
+fn bad_array_initializers_are_diagnosed() {
+    let out = compile(
+        "int a[2] = {1, 2, 3};\nint c = {1, 2};\nstruct S { int x; } s = {1};\nint *p[1] = {5};\nint main() {\n    int r = {{1}};\n    int u[] = 1;\n    return 0;\n}\n",
+    )
+    .err();
+    assert!(
+        out.stderr
+            .contains("main.c:1:19: error: excess elements in array initializer")
+    );
+    assert!(
+        out.stderr
+            .contains("main.c:2:13: error: excess elements in scalar initializer")
+    );
+    assert!(
+        out.stderr
+            .contains("main.c:3:25: error: invalid initializer")
+    );
+    assert!(
+        out.stderr
+            .contains("main.c:4:14: error: initialization of 'int *' from 'int' makes pointer from integer without a cast")
+    );
+    assert!(
+        out.stderr
+            .contains("main.c:6:14: error: braces around scalar initializer")
+    );
+    assert!(
+        out.stderr
+            .contains("main.c:7:15: error: invalid initializer")
+    );
+}
+
+#[test]
+fn subscripts_scale_each_dimension() {
+    let out = compile(
+        "int main() {\n    int m[3][4];\n    int i;\n    i = 2;\n    m[i][1] = 5;\n    return m[i][1];\n}\n",
+    )
+    .ok();
+    let ir = out.ir();
+    // 一行 16 个字节，一个元素 4 个字节
+    let expected = [
+        "ADDR m 48 t0",
+        "* i 16 t1",
+        "+ t0 t1 t2",
+        "+ t2 4 t3",
+        "STORE 5 t3",
+    ];
+    assert!(
+        ir.windows(expected.len()).any(|w| w == expected),
+        "{:?}",
+        ir
+    );
+}
+
+#[test]
+fn arrays_decay_to_pointers_in_calls() {
+    let out = compile(
+        "int sum(int v[], int n) {\n    int i;\n    int s;\n    s = 0;\n    for (i = 0; i < n; i++) {\n        s += v[i];\n    }\n    return s;\n}\nint main() {\n    int m[3][4];\n    return sum(m[2], 4);\n}\n",
+    )
+    .ok();
+    let ir = out.ir();
+    // 形参 v 就是指针，按下标读要先算地址
+    assert!(has(&ir, "+ v t0 t1"), "{:?}", ir);
+    assert!(has(&ir, "LOAD t1 t2"), "{:?}", ir);
+    // m[2] 是第三行的地址
+    assert!(has(&ir, "+ t7 32 t8"), "{:?}", ir);
+    assert!(has(&ir, "ARG t8"), "{:?}", ir);
+}
+
+#[test]
+fn bad_array_uses_are_diagnosed() {
+    let out = compile(
+        "int main() {\n    int a[2];\n    int b[2];\n    int c[];\n    a = b;\n    return 0;\n}\n",
+    )
+    .err();
+    assert!(
+        out.stderr
+            .contains("main.c:4:9: error: array size missing in 'c'")
+    );
+    assert!(
+        out.stderr
+            .contains("main.c:5:5: error: assignment to expression with array type")
+    );
+}
+
+#[test]
+fn large_frames_stay_within_immediates() {
+    let out = compile(
+        "int fill(int *p, int n) {\n    int i;\n    for (i = 0; i < n; i++) {\n        p[i] = i;\n    }\n    return n;\n}\nint sum(char c) {\n    int buf[600];\n    int x;\n    int *px;\n    char *pc;\n    px = &x;\n    pc = &c;\n    *px = fill(buf, 600);\n    return buf[599] + x + *pc;\n}\nint main() { return sum(3); }\n",
+    )
+    .ok();
+    // 栈帧超过 2KB，偏移要先装进寄存器
+    assert!(out.asm.contains("    add sp, sp, t0\n"), "{}", out.asm);
+    for line in out.asm.lines() {
+        let imm = match line.trim().split_once(' ') {
+            Some(("addi", operands)) => operands.rsplit(", ").next().unwrap(),
+            Some(("lw" | "sw" | "lb" | "sb", operands)) => operands
+                .rsplit(", ")
+                .next()
+                .unwrap()
+                .split('(')
+                .next()
+                .unwrap(),
+            _ => continue,
+        };
+        let imm: i64 = imm.parse().unwrap();
+        assert!((-2048..2048).contains(&imm), "{}", line);
+    }
+}
+
+#[test]
+fn bad_array_sizes_are_diagnosed() {
+    let out = compile(
+        "int a[2147483648];\nint b[1073741824][2];\nint ok[536870911];\nint main() {\n    int z[0];\n    return 0;\n}\n",
+    )
+    .err();
+    for message in [
+        "main.c:1:5: error: size of array 'a' is too large",
+        "main.c:2:5: error: size of array 'b' is too large",
+        "main.c:5:9: error: size of array 'z' is zero",
+    ] {
+        assert!(
+            out.stderr.contains(message),
+            "missing {}\n{}",
+            message,
+            out.stderr
+        );
+    }
+    assert!(!out.stderr.contains("'ok'"), "{}", out.stderr);
+    assert!(!out.stderr.contains("array size missing"), "{}", out.stderr);
+}
+
+#[test]
+fn char_arrays_take_string_initializers() {
+    let out = compile(
+        "char g[] = \"hi\";\nchar m[2][4] = {\"ab\", {\"cd\"}};\nint main() {\n    char s[4] = \"abc\";\n    char t[] = \"xy\";\n    char u[2] = \"xy\";\n    return s[2] + t[1] + u[1] + g[1] + m[1][1];\n}\n",
+    )
+    .ok();
+    // 全局的写进数据段，放得下时带上结尾的 0
+    assert!(
+        out.asm
+            .contains("g:\n    .byte 104\n    .byte 105\n    .zero 1\n"),
+        "{}",
+        out.asm
+    );
+    assert!(
+        out.asm.contains(
+            "m:\n    .byte 97\n    .byte 98\n    .byte 0\n    .byte 0\n    .byte 99\n    .byte 100\n    .zero 2\n"
+        ),
+        "{}",
+        out.asm
+    );
+    // 局部的逐个字节写进去，[] 的长度是字符串长度加 1
+    let ir = out.ir();
+    assert!(has(&ir, "ADDR s 4 t0"), "{:?}", ir);
+    assert!(has(&ir, "STOREB 0 t3"), "{:?}", ir);
+    assert!(has(&ir, "ADDR t 3 t4"), "{:?}", ir);
+    assert!(has(&ir, "STOREB 0 t6"), "{:?}", ir);
+    // 刚好放满时不写结尾的 0
+    assert!(has(&ir, "ADDR u 2 t7"), "{:?}", ir);
+    assert!(has(&ir, "STOREB 121 t8"), "{:?}", ir);
+    assert!(!has(&ir, "STOREB 0 t8"), "{:?}", ir);
+}
+
+#[test]
+fn too_long_string_initializer_warns() {
+    let out = compile("int main() {\n    char v[2] = \"xyz\";\n    return v[1];\n}\n").ok();
+    assert!(
+        out.stderr
+            .contains("main.c:2:17: warning: initializer-string for array of 'char' is too long"),
+        "{}",
+        out.stderr
+    );
+}
@@ -0,0 +1,95 @@
+// 集成测试共用的工具：把源码写进临时目录，用编出来的 quickcc 编译，收集输出和汇编
+#![allow(dead_code)]
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+pub struct Output {
+    pub success: bool,
+    // 打印的 AST 和 IR
+    pub stdout: String,
+    pub stderr: String,
+    // 生成的汇编，编译失败时为空
+    pub asm: String,
+}
+
+impl Output {
+    // 编译必须成功，失败时把诊断信息带进断言
+    pub fn ok(self) -> Output {
+        assert!(self.success, "compile failed:\n{}", self.stderr);
+        self
+    }
+
+    pub fn err(self) -> Output {
+        assert!(!self.success, "compile should fail:\n{}", self.stdout);
+        self
+    }
+
+    // 打印出来的四元式，每条写成 "op src1 src2 rd"，省掉空的操作数
+    pub fn ir(&self) -> Vec<String> {
+        self.stdout
+            .lines()
+            .filter_map(|line| line.strip_prefix("IrType { "))
+            .map(|line| {
+                let fields = line.split(", span:").next().unwrap();
+                fields
+                    .split(", ")
+                    .map(|field| field.split_once(": ").unwrap().1.trim_matches('"'))
+                    .filter(|value| !value.is_empty())
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })
+            .collect()
+    }
+}
+
+// ir() 里有没有这条四元式
+pub fn has(quads: &[String], quad: &str) -> bool {
+    quads.iter().any(|q| q == quad)
+}
+
+static NEXT_DIR: AtomicUsize = AtomicUsize::new(0);
+
+// 每次编译用单独的目录，测试并行跑时生成的 .asm 互不覆盖
+pub fn scratch_dir() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "quickcc-test-{}-{}",
+        std::process::id(),
+        NEXT_DIR.fetch_add(1, Ordering::SeqCst)
+    ));
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+pub fn write(dir: &Path, name: &str, contents: impl AsRef<[u8]>) {
+    let path = dir.join(name);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, contents).unwrap();
+}
+
+// 在 dir 里运行 quickcc，最后一个参数是要编译的文件
+pub fn quickcc(dir: &Path, args: &[&str]) -> Output {
+    let out = Command::new(env!("CARGO_BIN_EXE_quickcc"))
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .unwrap();
+    let file = Path::new(args.last().unwrap());
+    let asm_path = dir.join(file.with_extension("asm").file_name().unwrap());
+    Output {
+        success: out.status.success(),
+        stdout: String::from_utf8_lossy(&out.stdout).into_owned(),
+        stderr: String::from_utf8_lossy(&out.stderr).into_owned(),
+        asm: fs::read_to_string(asm_path).unwrap_or_default(),
+    }
+}
+
+pub fn compile(src: &str) -> Output {
+    let dir = scratch_dir();
+    write(&dir, "main.c", src);
+    let out = quickcc(&dir, &["main.c"]);
+    fs::remove_dir_all(dir).unwrap();
+    out
+}
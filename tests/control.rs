@@ -0,0 +1,205 @@
+// 循环、switch 和跳转语句
+mod common;
+
+use common::{compile, has};
+
+#[test]
+fn for_init_declarations_get_their_own_names() {
+    let out = compile(
+        "int main() {\n    int k = 7;\n    int s = 0;\n    for (int k = 0; k < 3; k++)\n        s += k;\n    return k;\n}\n",
+    )
+    .ok();
+    let ir = out.ir();
+    // 循环里的 k 和外面的 k 是两个变量，循环结束后用回外面的
+    assert!(has(&ir, "MOV 7 k"), "{:?}", ir);
+    assert!(has(&ir, "MOV 0 k.1"), "{:?}", ir);
+    assert!(has(&ir, "< k.1 3 t3"), "{:?}", ir);
+    assert!(has(&ir, "+ s k.1 t0"), "{:?}", ir);
+    assert!(has(&ir, "RET k"), "{:?}", ir);
+}
+
+#[test]
+fn block_declarations_do_not_clobber_outer_variables() {
+    let out = compile(
+        "int g;\nint main() {\n    int t = 0;\n    for (int k = 0; k < 3; k++) {\n        int k = 5;\n        t += k;\n    }\n    for (int k = 10; k < 12; k++)\n        t += k;\n    {\n        int g = 100;\n        t += g;\n    }\n    return t + g;\n}\n",
+    )
+    .ok();
+    let ir = out.ir();
+    // 每个作用域里的声明都有自己的名字
+    assert!(has(&ir, "MOV 0 k"), "{:?}", ir);
+    assert!(has(&ir, "MOV 5 k.1"), "{:?}", ir);
+    assert!(has(&ir, "+ k 1 t2"), "{:?}", ir);
+    assert!(has(&ir, "MOV 10 k.2"), "{:?}", ir);
+    // 和全局变量同名的局部变量也换个名字
+    assert!(has(&ir, "MOV 100 g.0"), "{:?}", ir);
+    assert!(has(&ir, "+ t 100 t8"), "{:?}", ir);
+    assert!(has(&ir, "+ t g t9"), "{:?}", ir);
+}
+
+#[test]
+fn label_errors_are_reported_alongside_other_errors() {
+    let out = compile(
+        "struct A { int x; };\nint main() {\n    struct A s;\n    int x;\n    x = s + 1;\n    goto missing;\ndup:\n    x = 1;\ndup:\n    x = 2;\n    break;\n    return x;\n}\n",
+    )
+    .err();
+    // 每条诊断只出现一次
+    for message in [
+        "main.c:5:9: error: invalid operands to binary + (have 'struct A' and 'int')",
+        "main.c:9:1: error: redefinition of label 'dup'",
+        "main.c:7:1: note: previous definition is here",
+        "main.c:11:5: error: 'break' statement not in loop or switch statement",
+        "main.c:6:5: error: use of undeclared label 'missing'",
+        "main.c:7:1: warning: label 'dup' defined but not used",
+    ] {
+        assert_eq!(
+            out.stderr.matches(message).count(),
+            1,
+            "{}\n{}",
+            message,
+            out.stderr
+        );
+    }
+}
+
+#[test]
+fn unused_labels_only_warn() {
+    let out = compile(
+        "int main() {\n    int x = 0;\nskip:\n    x = 1;\nskip:\n    goto done;\ndone:\n    return x;\n}\n",
+    )
+    .err();
+    assert_eq!(
+        out.stderr
+            .matches("main.c:3:1: warning: label 'skip' defined but not used")
+            .count(),
+        1,
+        "{}",
+        out.stderr
+    );
+    assert!(!out.stderr.contains("'done'"), "{}", out.stderr);
+    assert!(out.stderr.contains("1 semantic error(s)"), "{}", out.stderr);
+    let out = compile("int main() {\n    int x = 0;\nskip:\n    x = 1;\n    return x;\n}\n").ok();
+    assert!(
+        out.stderr
+            .contains("main.c:3:1: warning: label 'skip' defined but not used"),
+        "{}",
+        out.stderr
+    );
+}
+
+#[test]
+fn break_and_continue_jump_to_the_loop_labels() {
+    let out = compile(
+        "int main() {\n    int s = 0;\n    int i;\n    for (i = 0; i < 10; i++) {\n        if (i == 2)\n            continue;\n        if (i == 5)\n            break;\n        s += i;\n    }\n    return s;\n}\n",
+    )
+    .ok();
+    let ir = out.ir();
+    // 跳转之后紧跟的四元式
+    let after = |target: &str| {
+        let at = ir.iter().position(|q| q == &format!("LABEL {}", target));
+        at.and_then(|k| ir.get(k + 1)).cloned().unwrap_or_default()
+    };
+    // continue 跳到步进，break 跳到循环条件后面
+    assert!(has(&ir, "JMP L3"), "{:?}", ir);
+    assert_eq!(after("L3"), "+ i 1 t4", "{:?}", ir);
+    assert!(has(&ir, "JMP L2"), "{:?}", ir);
+    assert_eq!(after("L2"), "RET s", "{:?}", ir);
+}
+
+#[test]
+fn break_and_continue_outside_loops_are_diagnosed() {
+    let out = compile(
+        "int main() {\n    int x = 1;\n    break;\n    if (x) continue;\n    switch (x) {\n    case 1: continue;\n    default: break;\n    }\n    while (x) {\n        switch (x) { default: break; }\n        continue;\n    }\n    return x;\n}\n",
+    )
+    .err();
+    for message in [
+        "main.c:3:5: error: 'break' statement not in loop or switch statement",
+        "main.c:4:12: error: 'continue' statement not in loop",
+        "main.c:6:13: error: 'continue' statement not in loop",
+    ] {
+        assert!(
+            out.stderr.contains(message),
+            "missing {}\n{}",
+            message,
+            out.stderr
+        );
+    }
+    // switch 里的 break 和循环里的 continue 都合法
+    assert!(
+        out.stderr.contains("\n3 semantic error(s)"),
+        "{}",
+        out.stderr
+    );
+}
+
+#[test]
+fn dense_switches_use_a_rodata_jump_table() {
+    let out = compile(
+        "int f(int x) {\n    int r = 0;\n    switch (x) {\n    case 1: r = 10; break;\n    case 2: r = 20; break;\n    case 4: r = 40;\n    case 5: r = 50; break;\n    default: r = -1;\n    }\n    return r;\n}\nint main() { return f(2); }\n",
+    )
+    .ok();
+    let ir = out.ir();
+    // 先检查范围，再用 x - 1 做下标
+    assert!(has(&ir, "< x 1 t0"), "{:?}", ir);
+    assert!(has(&ir, "JNZ t0 L5"), "{:?}", ir);
+    assert!(has(&ir, "> x 5 t1"), "{:?}", ir);
+    assert!(has(&ir, "- x 1 t2"), "{:?}", ir);
+    assert!(has(&ir, "JTABLE t2 L6"), "{:?}", ir);
+    assert!(out.asm.contains("    la t1, L6\n"), "{}", out.asm);
+    assert!(out.asm.contains("    jr t1\n"), "{}", out.asm);
+    // 没有 case 3，那一项跳到 default
+    assert!(
+        out.asm.contains(
+            ".section .rodata\n.align 2\nL6:\n    .word L1\n    .word L2\n    .word L5\n    .word L3\n    .word L4\n"
+        ),
+        "{}",
+        out.asm
+    );
+}
+
+#[test]
+fn sparse_switches_use_a_binary_search() {
+    let out = compile(
+        "int f(int x) {\n    switch (x) {\n    case 1: return 1;\n    case 100: return 2;\n    case 1000: return 3;\n    case 5000: return 4;\n    case 20000: return 5;\n    }\n    return 0;\n}\nint main() { return f(100); }\n",
+    )
+    .ok();
+    let ir = out.ir();
+    // 先和中间的 1000 比较分成两半，每一半逐个比较
+    assert!(has(&ir, "< x 1000 t0"), "{:?}", ir);
+    assert!(has(&ir, "JZ t0 L6"), "{:?}", ir);
+    assert!(has(&ir, "== x 1 t1"), "{:?}", ir);
+    assert!(has(&ir, "== x 100 t2"), "{:?}", ir);
+    assert!(has(&ir, "LABEL L6"), "{:?}", ir);
+    assert!(has(&ir, "== x 20000 t5"), "{:?}", ir);
+    // 都不相等时跳到 switch 后面
+    assert_eq!(ir.iter().filter(|q| *q == "JMP L0").count(), 2, "{:?}", ir);
+    assert!(!ir.iter().any(|q| q.starts_with("JTABLE")), "{:?}", ir);
+    assert!(!out.asm.contains(".rodata"), "{}", out.asm);
+}
+
+#[test]
+fn duplicate_case_labels_are_diagnosed() {
+    let out = compile(
+        "int main() {\n    int x = 1;\n    switch (x) {\n    case 1: x = 2;\n    case 3 - 2: x = 3;\n    default: x = 4;\n    default: x = 5;\n    }\n    return x;\n}\n",
+    )
+    .err();
+    // 常量表达式按值比较
+    assert!(
+        out.stderr.contains(
+            "main.c:5:5: error: duplicate case value '1'\nmain.c:4:5: note: previously used here\n"
+        ),
+        "{}",
+        out.stderr
+    );
+    assert!(
+        out.stderr.contains(
+            "main.c:7:5: error: multiple default labels in one switch\nmain.c:6:5: note: this is the first default label\n"
+        ),
+        "{}",
+        out.stderr
+    );
+    assert!(
+        out.stderr.contains("\n2 semantic error(s)"),
+        "{}",
+        out.stderr
+    );
+}
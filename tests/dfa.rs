@@ -0,0 +1,115 @@
+// build.rs 里的 DFA 生成器：最长匹配、规则优先级和最小化
+#[allow(dead_code)]
+#[path = "../build/dfa.rs"]
+mod dfa;
+#[path = "../src/lexer/spec.rs"]
+mod spec;
+
+use dfa::{Dfa, build_dfa};
+use spec::{RULES, Rule};
+
+// 从开头跑 DFA，返回最长匹配的规则下标和长度
+fn longest(dfa: &Dfa, input: &[u8]) -> Option<(usize, usize)> {
+    let mut state = dfa.start;
+    let mut best = None;
+    for (i, &b) in input.iter().enumerate() {
+        state = dfa.trans[state][dfa.byte_class[b as usize]];
+        if state == 0 {
+            break;
+        }
+        if let Some(rule) = dfa.accept[state] {
+            best = Some((rule, i + 1));
+        }
+    }
+    best
+}
+
+// 用真正的词法规则切分，返回每个记号的规则和文本
+fn tokens(input: &str) -> Vec<(Rule, &str)> {
+    let patterns: Vec<&str> = RULES.iter().map(|(_, re)| *re).collect();
+    let dfa = build_dfa(&patterns);
+    let mut out = Vec::new();
+    let mut rest = input;
+    while !rest.is_empty() {
+        let (rule, len) = longest(&dfa, rest.as_bytes()).expect("no rule matches");
+        out.push((RULES[rule].0, &rest[..len]));
+        rest = &rest[len..];
+    }
+    out
+}
+
+#[test]
+fn longest_match_wins() {
+    let dfa = build_dfa(&["if", "[a-z]+", "=|=="]);
+    assert_eq!(longest(&dfa, b"iffy"), Some((1, 4)));
+    assert_eq!(longest(&dfa, b"==x"), Some((2, 2)));
+    assert_eq!(longest(&dfa, b"=x"), Some((2, 1)));
+    assert_eq!(longest(&dfa, b"9"), None);
+}
+
+#[test]
+fn earlier_rule_breaks_ties() {
+    let dfa = build_dfa(&["if", "[a-z]+"]);
+    assert_eq!(longest(&dfa, b"if("), Some((0, 2)));
+    let dfa = build_dfa(&["[a-z]+", "if"]);
+    assert_eq!(longest(&dfa, b"if("), Some((0, 2)));
+}
+
+#[test]
+fn table_is_minimized() {
+    // (a|b)*abb 的最小 DFA 有 4 个状态，再加上死状态
+    let dfa = build_dfa(&["(a|b)*abb"]);
+    assert_eq!(dfa.trans.len(), 5);
+    // a、b 和其他字节三个等价类
+    assert_eq!(dfa.classes, 3);
+    assert_eq!(longest(&dfa, b"babaabb"), Some((0, 7)));
+    assert_eq!(longest(&dfa, b"abab"), None);
+    // 写法不同但语言相同的规则得到同样大小的表
+    assert_eq!(
+        build_dfa(&["(a|b)*"]).trans.len(),
+        build_dfa(&["(a*b*)*"]).trans.len()
+    );
+}
+
+#[test]
+fn spec_splits_c_source() {
+    use Rule::*;
+    assert_eq!(
+        tokens("int x=0x1Fu;/* a*b */s->f"),
+        vec![
+            (Ident, "int"),
+            (Whitespace, " "),
+            (Ident, "x"),
+            (Punct, "="),
+            (Number, "0x1Fu"),
+            (Punct, ";"),
+            (BlockComment, "/* a*b */"),
+            (Ident, "s"),
+            (Punct, "->"),
+            (Ident, "f"),
+        ]
+    );
+    // 和 C 的预处理数一样，数字后面的 . 和 e+ 都算进来
+    assert_eq!(tokens("1.5e+3..."), vec![(Number, "1.5e+3...")]);
+    assert_eq!(
+        tokens("f(...)"),
+        vec![(Ident, "f"), (Punct, "("), (Punct, "..."), (Punct, ")")]
+    );
+    assert_eq!(
+        tokens("a>>=b"),
+        vec![(Ident, "a"), (Punct, ">>="), (Ident, "b")]
+    );
+}
+
+#[test]
+fn spec_keeps_unterminated_literals() {
+    use Rule::*;
+    assert_eq!(tokens("/* open"), vec![(UnterminatedComment, "/* open")]);
+    assert_eq!(
+        tokens("\"abc\nx"),
+        vec![(UnterminatedStr, "\"abc"), (Whitespace, "\n"), (Ident, "x")]
+    );
+    assert_eq!(tokens("'\\'"), vec![(UnterminatedChar, "'\\'")]);
+    // 注释和字符串里可以有 UTF-8
+    assert_eq!(tokens("\"é\""), vec![(Str, "\"é\"")]);
+}
@@ -0,0 +1,75 @@
+// 全局变量的初始值和数据段
+mod common;
+
+use common::compile;
+
+#[test]
+fn initialized_globals_go_to_data() {
+    let out = compile(
+        "int a = 5;\nint b = -3;\nchar c = 'A';\nint z;\nint main() { return a + b + z; }\n",
+    )
+    .ok();
+    let (data, bss) = out.asm.split_once(".section .bss").unwrap();
+    assert!(data.contains(".section .data"));
+    assert!(data.contains("a:\n    .word 5\n"));
+    assert!(data.contains("b:\n    .word -3\n"));
+    assert!(data.contains("c:\n    .byte 65\n"));
+    assert!(bss.contains(".globl z\n.align 2\nz:\n    .zero 4\n"));
+}
+
+#[test]
+fn overflowing_initializer_wraps() {
+    let out = compile("int f = 0x7fffffff + 1;\nint main() { return f; }\n").ok();
+    assert!(out.asm.contains("f:\n    .word -2147483648\n"));
+}
+
+#[test]
+fn address_initializers_use_symbol_offsets() {
+    let out = compile(
+        "int arr[4];\nstruct S { int x; int y; } s;\nint *p = arr;\nint *q = &arr[2];\nint *r = arr - 1;\nint *t = &s.y;\nint main() { return *p; }\n",
+    )
+    .ok();
+    assert!(out.asm.contains("p:\n    .word arr\n"));
+    assert!(out.asm.contains("q:\n    .word arr+8\n"));
+    assert!(out.asm.contains("r:\n    .word arr-4\n"));
+    assert!(out.asm.contains("t:\n    .word s+4\n"));
+}
+
+#[test]
+fn tentative_definition_takes_later_initializer() {
+    let out =
+        compile("int z;\nint z = 100;\nstatic int hid = 7;\nint main() { return z + hid; }\n").ok();
+    assert!(out.asm.contains("z:\n    .word 100\n"));
+    assert!(!out.asm.contains(".zero"));
+    // static 的变量不导出
+    assert!(out.asm.contains("hid:\n    .word 7\n"));
+    assert!(!out.asm.contains(".globl hid"));
+}
+
+#[test]
+fn bad_initializers_are_diagnosed() {
+    let out = compile(
+        "int f();\nint a = 1;\nint a = 2;\nint k;\nint m = k;\nint n = f();\nint *p = 5;\nint arr[2] = 3;\nint main() { return 0; }\n",
+    )
+    .err();
+    assert!(
+        out.stderr
+            .contains("main.c:3:5: error: redefinition of 'a'")
+    );
+    assert!(
+        out.stderr
+            .contains("main.c:5:9: error: initializer element is not constant")
+    );
+    assert!(
+        out.stderr
+            .contains("main.c:6:9: error: initializer element is not constant")
+    );
+    assert!(
+        out.stderr
+            .contains("makes pointer from integer without a cast")
+    );
+    assert!(
+        out.stderr
+            .contains("main.c:8:14: error: invalid initializer")
+    );
+}
@@ -0,0 +1,71 @@
+// build.rs 里的 LALR(1) 表生成器：冲突报告和真正的文法
+#[path = "../src/parser/grammar.rs"]
+mod grammar;
+#[allow(dead_code)]
+#[path = "../build/lalr.rs"]
+mod lalr;
+
+use lalr::{build_tables, parse_grammar};
+
+fn conflicts(rules: &[&str]) -> String {
+    match build_tables(&parse_grammar(rules)) {
+        Ok(_) => panic!("expected a conflict"),
+        Err(report) => report,
+    }
+}
+
+#[test]
+fn reports_shift_reduce_with_item_set() {
+    let report = conflicts(&["Expr -> Expr '+' Expr | ID"]);
+    assert!(report.contains("shift/reduce conflict in state"));
+    assert!(report.contains(" on '+':\n"));
+    // 冲突的两个项目，以及整个项集带上向前看符号
+    assert!(report.contains("  shift  Expr -> Expr . '+' Expr\n"));
+    assert!(report.contains("  reduce Expr -> Expr '+' Expr .\n"));
+    assert!(report.contains("item set of state"));
+    assert!(report.contains("Expr -> Expr '+' Expr .    ['+' '$']"));
+}
+
+#[test]
+fn reports_reduce_reduce() {
+    let report = conflicts(&["Start -> Left | Right", "Left -> ID", "Right -> ID"]);
+    assert!(report.contains("reduce/reduce conflict in state"));
+    assert!(report.contains(" on '$':\n"));
+    assert!(report.contains("  reduce Left -> ID .\n"));
+    assert!(report.contains("  reduce Right -> ID .\n"));
+}
+
+#[test]
+fn dangling_else_needs_matched_statements() {
+    let report = conflicts(&["Stmt -> 'if' ID Stmt | 'if' ID Stmt 'else' Stmt | ID"]);
+    assert!(report.contains("shift/reduce conflict in state"));
+    assert!(report.contains(" on 'else':\n"));
+    // 和 grammar.rs 一样拆成 matched 和 unmatched 就没有冲突
+    assert!(
+        build_tables(&parse_grammar(&[
+            "Stmt -> Matched | Unmatched",
+            "Matched -> 'if' ID Matched 'else' Matched | ID",
+            "Unmatched -> 'if' ID Stmt | 'if' ID Matched 'else' Unmatched",
+        ]))
+        .is_ok()
+    );
+}
+
+#[test]
+fn accepts_lalr_grammar_that_is_not_slr() {
+    // 经典的例子，SLR 在 '=' 上有移进/归约冲突
+    let rules = [
+        "Start -> Left '=' Right | Right",
+        "Left -> '*' Right | ID",
+        "Right -> Left",
+    ];
+    assert!(build_tables(&parse_grammar(&rules)).is_ok());
+}
+
+#[test]
+fn parser_grammar_is_conflict_free() {
+    let rules: Vec<&str> = grammar::GRAMMAR.iter().map(|(_, rule)| *rule).collect();
+    if let Err(report) = build_tables(&parse_grammar(&rules)) {
+        panic!("{}", report);
+    }
+}
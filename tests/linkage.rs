@@ -0,0 +1,132 @@
+// 函数原型、extern 和 static 链接，以及多个文件分别编译
+mod common;
+
+use common::{compile, has, quickcc, scratch_dir, write};
+use std::fs;
+
+const HEADER: &str =
+    "extern int counter;\nextern int table[4];\nint add(int a, int b);\nint bump(int *);\n";
+
+// 在同一个目录里编译两个包含同一个头文件的源文件
+fn compile_pair(lib: &str, main: &str) -> (common::Output, common::Output) {
+    let dir = scratch_dir();
+    write(&dir, "lib.h", HEADER);
+    write(&dir, "lib.c", lib);
+    write(&dir, "main.c", main);
+    let lib = quickcc(&dir, &["lib.c"]);
+    let main = quickcc(&dir, &["main.c"]);
+    fs::remove_dir_all(dir).unwrap();
+    (lib, main)
+}
+
+#[test]
+fn prototypes_allow_calls_before_definition() {
+    let out = compile(
+        "int later(int x);\nstatic int twice(int);\nint main() {\n    return later(1) + twice(2);\n}\nstatic int twice(int x) {\n    return x * 2;\n}\nint later(int x) {\n    return x + 1;\n}\n",
+    )
+    .ok();
+    assert!(out.asm.contains("\n    call later\n"), "{}", out.asm);
+    assert!(out.asm.contains("\n    call twice\n"), "{}", out.asm);
+    // static 函数不导出
+    assert!(out.asm.contains(".globl later\nlater:\n"), "{}", out.asm);
+    assert!(out.asm.contains("\ntwice:\n"), "{}", out.asm);
+    assert!(!out.asm.contains(".globl twice"), "{}", out.asm);
+}
+
+#[test]
+fn externs_are_defined_in_one_file_only() {
+    let (lib, main) = compile_pair(
+        "#include \"lib.h\"\nint counter;\nint table[4];\nstatic int scale;\nint add(int a, int b) {\n    scale = 100;\n    return a + b;\n}\nint bump(int *p) {\n    counter++;\n    return *p + 1;\n}\n",
+        "#include \"lib.h\"\nint main() {\n    int v;\n    v = add(5, 7);\n    table[2] = bump(&v);\n    return counter;\n}\n",
+    );
+    let (lib, main) = (lib.ok(), main.ok());
+    // 定义的那边分配空间并导出，static 变量只分配空间
+    assert!(
+        lib.asm
+            .contains(".globl counter\n.align 2\ncounter:\n    .zero 4\n"),
+        "{}",
+        lib.asm
+    );
+    assert!(
+        lib.asm
+            .contains(".globl table\n.align 2\ntable:\n    .zero 16\n"),
+        "{}",
+        lib.asm
+    );
+    assert!(lib.asm.contains("\nscale:\n    .zero 4\n"), "{}", lib.asm);
+    assert!(!lib.asm.contains(".globl scale"), "{}", lib.asm);
+    assert!(lib.asm.contains(".globl add\nadd:\n"), "{}", lib.asm);
+    // 只有声明的那边按名字访问，不分配空间
+    let ir = main.ir();
+    assert!(has(&ir, "EXTERN counter 4"), "{:?}", ir);
+    assert!(has(&ir, "EXTERN table 16"), "{:?}", ir);
+    assert!(main.asm.contains(", counter\n"), "{}", main.asm);
+    assert!(!main.asm.contains("counter:"), "{}", main.asm);
+    assert!(!main.asm.contains("table:"), "{}", main.asm);
+    assert!(main.asm.contains("\n    call add\n"), "{}", main.asm);
+    assert!(main.asm.contains("\n    call bump\n"), "{}", main.asm);
+}
+
+#[test]
+fn conflicting_declarations_are_diagnosed() {
+    let out = compile(
+        "int f(int a);\nint f(int a, int b);\nint g(int);\nint g(int x) { return x; }\nint g(int y) { return y; }\nint h;\nint h();\nstatic int s();\nint s() { return 1; }\nint t();\nstatic int t();\nstatic int v;\nint v;\nextern static int w;\nextern extern int w2;\nint p(int, int *) { return 0; }\n",
+    )
+    .err();
+    for message in [
+        "main.c:2:5: error: conflicting types for 'f'; have 'int(int, int)'",
+        "main.c:5:5: error: redefinition of 'g'",
+        "main.c:7:5: error: 'h' redeclared as different kind of symbol",
+        "main.c:11:12: error: static declaration of 't' follows non-static declaration",
+        "main.c:13:5: error: non-static declaration of 'v' follows static declaration",
+        "main.c:14:1: error: multiple storage classes in declaration specifiers",
+        "main.c:15:1: error: duplicate 'extern'",
+        "main.c:16:7: error: parameter name omitted",
+    ] {
+        assert!(
+            out.stderr.contains(message),
+            "missing {}\n{}",
+            message,
+            out.stderr
+        );
+    }
+    // static 之后不带 static 的定义沿用内部链接
+    assert!(!out.stderr.contains("'s'"), "{}", out.stderr);
+}
+
+#[test]
+fn bad_calls_are_diagnosed() {
+    let out = compile(
+        "int g(int);\nint h;\nint main() {\n    int *q;\n    int z;\n    z = g(1, 2);\n    z = g();\n    z = g(q);\n    z = nothing(1);\n    z = h();\n    return z;\n}\nint g(int x) { return x; }\n",
+    )
+    .err();
+    for message in [
+        "main.c:6:9: error: too many arguments to function 'g'",
+        "main.c:7:9: error: too few arguments to function 'g'",
+        "main.c:8:11: error: incompatible type for argument 1 of 'g'",
+        "main.c:9:9: error: implicit declaration of function 'nothing'",
+        "main.c:10:9: error: called object 'h' is not a function",
+    ] {
+        assert!(
+            out.stderr.contains(message),
+            "missing {}\n{}",
+            message,
+            out.stderr
+        );
+    }
+}
+
+#[test]
+fn integer_arguments_convert_to_parameter_types() {
+    let out = compile(
+        "int id(char v) { return v; }\nint twice(int v) { return v * 2; }\nint main() {\n    int x;\n    char c;\n    x = 300;\n    c = 'b';\n    return id(x + 1) + id('a') + twice(c);\n}\n",
+    )
+    .ok();
+    let ir = out.ir();
+    // 传给 char 形参的值先截断，常量直接算好
+    assert!(has(&ir, "<< t1 24 t2"), "{:?}", ir);
+    assert!(has(&ir, ">> t2 24 t3"), "{:?}", ir);
+    assert!(has(&ir, "ARG t3"), "{:?}", ir);
+    assert!(has(&ir, "ARG 97"), "{:?}", ir);
+    assert!(has(&ir, "ARG c"), "{:?}", ir);
+}
@@ -0,0 +1,62 @@
+// 源码位置：四元式上的 span 和汇编里的 .file/.loc
+mod common;
+
+use common::{compile, quickcc, scratch_dir, write};
+use std::fs;
+
+#[test]
+fn quads_carry_the_statement_spans() {
+    let out =
+        compile("int main() {\n    int a = 3;\n    int b;\n    b = a * 2;\n    return b;\n}\n")
+            .ok();
+    // 初始化落在变量名上，赋值和 return 覆盖整个语句
+    for quad in [
+        "op: \"MOV\", src1: \"3\", src2: \"\", rd: \"a\", span: Span { file: 1, line: 2, col: 9, lo: 21, hi: 22 }",
+        "op: \"=\", src1: \"6\", src2: \"\", rd: \"b\", span: Span { file: 1, line: 4, col: 5, lo: 43, hi: 52 }",
+        "op: \"RET\", src1: \"b\", src2: \"\", rd: \"\", span: Span { file: 1, line: 5, col: 5, lo: 58, hi: 67 }",
+    ] {
+        assert!(
+            out.stdout.contains(quad),
+            "missing {}\n{}",
+            quad,
+            out.stdout
+        );
+    }
+    assert!(out.asm.starts_with(".file 1 \"main.c\"\n"), "{}", out.asm);
+    assert!(
+        out.asm.contains("    .loc 1 2 9\n")
+            && out.asm.contains("    .loc 1 4 5\n")
+            && out.asm.contains("    .loc 1 5 5\n"),
+        "{}",
+        out.asm
+    );
+}
+
+#[test]
+fn included_code_keeps_its_own_file() {
+    let dir = scratch_dir();
+    write(
+        &dir,
+        "twice.h",
+        "int twice(int v) {\n    return v * 2;\n}\n",
+    );
+    write(
+        &dir,
+        "main.c",
+        "#include \"twice.h\"\nint main() {\n    return twice(4);\n}\n",
+    );
+    let out = quickcc(&dir, &["main.c"]).ok();
+    fs::remove_dir_all(dir).unwrap();
+    // 头文件里的代码用头文件的编号和行列
+    assert!(out.stdout.contains(
+        "op: \"RET\", src1: \"t0\", src2: \"\", rd: \"\", span: Span { file: 2, line: 2, col: 5, lo: 23, hi: 36 }"
+    ), "{}", out.stdout);
+    let second = out.asm.lines().nth(1).unwrap_or_default();
+    assert!(
+        second.starts_with(".file 2 \"") && second.ends_with("twice.h\""),
+        "{}",
+        out.asm
+    );
+    assert!(out.asm.contains("    .loc 2 2 5\n"), "{}", out.asm);
+    assert!(out.asm.contains("    .loc 1 3 5\n"), "{}", out.asm);
+}
@@ -0,0 +1,184 @@
+// 复合赋值、自增自减和位运算
+mod common;
+
+use common::{compile, has};
+
+#[test]
+fn unsigned_compound_shift_is_logical() {
+    let out = compile(
+        "int main() { uint u; int i; uint *p; u = 0x80000000u; i = -8; p = &u; u >>= 4u; i >>= 1; *p >>= 1u; return i; }\n",
+    )
+    .ok();
+    // u 和 *p 是逻辑右移，i 是算术右移
+    assert_eq!(out.asm.matches("srli ").count(), 2, "{}", out.asm);
+    assert_eq!(out.asm.matches("srai ").count(), 1, "{}", out.asm);
+}
+
+#[test]
+fn logical_shift_is_not_a_source_operator() {
+    let out = compile("int main() { int i; i = 8; i >>>= 1; return i; }\n").err();
+    assert!(
+        out.stderr
+            .contains("main.c:1:32: error: expected expression, found '>='")
+    );
+}
+
+#[test]
+fn constants_fold_with_c_precedence() {
+    // % 先于 +，+ 先于 <<，然后是 &、^、|
+    let out = compile("int main() { return 1 + 2 << 3 & 0xff | 4 ^ 5 % 3; }\n").ok();
+    assert!(out.asm.contains("li a0, 30\n"), "{}", out.asm);
+    // 移位先于比较，% 和 >> 按 C 的规则处理负数
+    let out =
+        compile("int main() { return -7 % 3 + (1 << 2 < 5) * 100 + (-8 >> 1) * 1000; }\n").ok();
+    assert!(out.asm.contains("li a0, -3901\n"), "{}", out.asm);
+}
+
+#[test]
+fn bitwise_operators_lower_to_rv32() {
+    let out = compile(
+        "int f(int a, int b) {\n    return (a % b) + (a & b) + (a | b) + (a ^ b) + (a << b) + (a >> b) + ~a;\n}\nint main() { return f(7, 2); }\n",
+    )
+    .ok();
+    for op in ["rem", "and", "or", "xor", "sll", "sra", "not"] {
+        let inst = format!("\n    {} ", op);
+        assert!(out.asm.contains(&inst), "missing {} in\n{}", op, out.asm);
+    }
+}
+
+#[test]
+fn small_constants_use_immediates() {
+    let out = compile(
+        "int f(int a) { return (a & 7) + (a ^ 1) + (a | 2) + (a << 2) + (a >> 3); }\nuint g(uint u) { return u >> 3u; }\nint main() { return f(1); }\n",
+    )
+    .ok();
+    for op in ["andi", "xori", "ori", "slli", "srai", "srli"] {
+        let inst = format!("\n    {} ", op);
+        assert!(out.asm.contains(&inst), "missing {} in\n{}", op, out.asm);
+    }
+}
+
+#[test]
+fn char_operands_take_int_constants() {
+    // 'a' 的类型是 int，比较和逻辑运算的结果也是 int
+    let out = compile(
+        "int main() {\n    char s;\n    int r;\n    s = 'a';\n    r = s == 'a' && 'a' != s + 1;\n    return r;\n}\n",
+    )
+    .ok();
+    assert!(
+        out.ir().iter().any(|quad| quad == "== s 97 t0"),
+        "{:?}",
+        out.ir()
+    );
+}
+
+#[test]
+fn char_constants_are_ints_with_char_values() {
+    let out = compile(
+        "int main() {\n    char c;\n    int n;\n    c = '\\377';\n    n = 'ab' + '\\n';\n    return c == '\\377';\n}\n",
+    )
+    .ok();
+    let ir = out.ir();
+    // 和 char 变量比较时两边都是 -1
+    assert!(has(&ir, "= -1 c"), "{:?}", ir);
+    assert!(has(&ir, "== c -1 t0"), "{:?}", ir);
+    // 多字符常量按 int 拼起来
+    assert!(has(&ir, "= 24940 n"), "{:?}", ir);
+    assert!(
+        out.stderr
+            .contains("main.c:5:9: warning: multi-character character constant"),
+        "{}",
+        out.stderr
+    );
+    // 字符常量是 int，不能直接赋给指针
+    let out = compile("int main() {\n    char *p;\n    p = 'a';\n    return 0;\n}\n").err();
+    assert!(
+        out.stderr.contains(
+            "main.c:3:9: error: assignment to 'char *' from 'int' makes pointer from integer without a cast"
+        ),
+        "{}",
+        out.stderr
+    );
+}
+
+#[test]
+fn mixed_width_operands_are_promoted() {
+    // char 先提升成 int，再和 int 一起运算
+    let out = compile(
+        "int main() {\n    int x;\n    char c;\n    x = 0x1ff;\n    c = 'a';\n    x = (x & c) | (c ^ 2) | (c << 2) | c * x;\n    return x;\n}\n",
+    )
+    .ok();
+    let ir = out.ir();
+    assert!(has(&ir, "& x c t0"), "{:?}", ir);
+    assert!(has(&ir, "^ c 2 t1"), "{:?}", ir);
+    assert!(has(&ir, "<< c 2 t3"), "{:?}", ir);
+    assert!(has(&ir, "* c x t5"), "{:?}", ir);
+}
+
+#[test]
+fn non_integer_operands_are_diagnosed() {
+    let out = compile(
+        "int main() {\n    int x;\n    int *p;\n    char *s;\n    x = p & x;\n    x = x | s;\n    x = p << 1;\n    x = p == s;\n    x = p < 5;\n    return x;\n}\n",
+    )
+    .err();
+    for message in [
+        "main.c:5:9: error: invalid operands to binary & (have 'int *' and 'int')",
+        "main.c:6:9: error: invalid operands to binary | (have 'int' and 'char *')",
+        "main.c:7:9: error: invalid operands to binary << (have 'int *' and 'int')",
+        "main.c:8:9: error: comparison of distinct pointer types lacks a cast",
+        "main.c:9:9: error: comparison between pointer and integer",
+    ] {
+        assert!(
+            out.stderr.contains(message),
+            "missing {}\n{}",
+            message,
+            out.stderr
+        );
+    }
+    // 出错的表达式不再引出赋值的错误
+    assert!(out.stderr.contains("5 semantic error(s)"), "{}", out.stderr);
+    let out = compile(
+        "struct S { int a; };\nint main() {\n    struct S s;\n    int x;\n    x = s + 1;\n    return x;\n}\n",
+    )
+    .err();
+    assert!(out.stderr.contains("1 semantic error(s)"), "{}", out.stderr);
+}
+
+#[test]
+fn conditional_arms_convert_to_a_common_type() {
+    let out = compile(
+        "int main() {\n    int x;\n    char c;\n    int *p;\n    int *q;\n    x = 300;\n    c = 5;\n    p = &x;\n    q = x ? 0 : p;\n    x = (x ? c : x) + 1;\n    return *q + x;\n}\n",
+    )
+    .ok();
+    let ir = out.ir();
+    // 两个分支写进同一个临时变量，没选中的分支不求值
+    assert!(has(&ir, "JZ x L2"), "{:?}", ir);
+    assert!(has(&ir, "MOV c t2"), "{:?}", ir);
+    assert!(has(&ir, "MOV x t2"), "{:?}", ir);
+    assert!(has(&ir, "+ t2 1 t3"), "{:?}", ir);
+    // 空指针常量可以和指针混用
+    assert!(has(&ir, "MOV 0 t1"), "{:?}", ir);
+    assert!(has(&ir, "MOV p t1"), "{:?}", ir);
+}
+
+#[test]
+fn conditional_type_mismatches_are_diagnosed() {
+    let out = compile(
+        "struct S { int a; };\nint main() {\n    struct S s;\n    int x;\n    char c;\n    char *t;\n    int *p;\n    x = 1;\n    t = x ? c : x;\n    p = x ? p : c;\n    x = x ? s : x;\n    return 0;\n}\n",
+    )
+    .err();
+    for message in [
+        // char 和 int 的结果是 int
+        "main.c:9:9: error: assignment to 'char *' from 'int' makes pointer from integer without a cast",
+        "main.c:10:9: error: type mismatch in conditional expression ('int *' and 'char')",
+        "main.c:11:9: error: type mismatch in conditional expression ('struct S' and 'int')",
+    ] {
+        assert!(
+            out.stderr.contains(message),
+            "missing {}\n{}",
+            message,
+            out.stderr
+        );
+    }
+    assert!(out.stderr.contains("3 semantic error(s)"), "{}", out.stderr);
+}
@@ -0,0 +1,154 @@
+// 指针的声明、取地址、解引用和指针运算
+mod common;
+
+use common::{compile, has};
+
+#[test]
+fn arithmetic_scales_by_element_size() {
+    let out = compile(
+        "int main() {\n    int a[4];\n    int *p;\n    int *q;\n    char *c;\n    p = a + 1;\n    q = &a[3];\n    c = 0;\n    c = c + 3;\n    p++;\n    return q - p;\n}\n",
+    )
+    .ok();
+    let ir = out.ir();
+    // a + 1 和 &a[3] 按 int 的大小缩放
+    assert!(has(&ir, "+ t0 4 t1"), "{:?}", ir);
+    assert!(has(&ir, "+ t2 12 t3"), "{:?}", ir);
+    // char 指针不用缩放，0 是空指针常量
+    assert!(has(&ir, "= 0 c"), "{:?}", ir);
+    assert!(has(&ir, "+ c 3 t4"), "{:?}", ir);
+    assert!(has(&ir, "+ p 4 t5"), "{:?}", ir);
+    // 指针相减得到元素个数
+    assert!(has(&ir, "- q p t7"), "{:?}", ir);
+    assert!(has(&ir, "/ t7 4 t8"), "{:?}", ir);
+}
+
+#[test]
+fn loads_and_stores_use_pointee_width() {
+    let out = compile(
+        "int main() {\n    int x;\n    int *p;\n    int **pp;\n    char s;\n    char *c;\n    p = &x;\n    pp = &p;\n    **pp = 7;\n    c = &s;\n    *c = 'a';\n    if (p != 0 && p <= *pp) {\n        return *c;\n    }\n    return x;\n}\n",
+    )
+    .ok();
+    let ir = out.ir();
+    assert!(has(&ir, "ADDR x 4 t0"), "{:?}", ir);
+    assert!(has(&ir, "LOAD pp t2"), "{:?}", ir);
+    assert!(has(&ir, "STORE 7 t2"), "{:?}", ir);
+    assert!(has(&ir, "STOREB 97 c"), "{:?}", ir);
+    assert!(has(&ir, "!= p 0 t4"), "{:?}", ir);
+    assert!(has(&ir, "LOADB c t8"), "{:?}", ir);
+    // char 按字节读写
+    assert!(out.asm.contains("\n    sb "), "{}", out.asm);
+    assert!(out.asm.contains("\n    lb "), "{}", out.asm);
+}
+
+#[test]
+fn bad_pointer_operands_are_diagnosed() {
+    let out = compile(
+        "int main() {\n    int x;\n    int *p;\n    int *q;\n    x = *x;\n    p = &1;\n    q = p + p;\n    return x[0];\n}\n",
+    )
+    .err();
+    assert!(
+        out.stderr
+            .contains("main.c:5:9: error: invalid type argument of unary '*' (have 'int')")
+    );
+    assert!(
+        out.stderr
+            .contains("main.c:6:10: error: lvalue required as unary '&' operand")
+    );
+    assert!(
+        out.stderr
+            .contains("main.c:7:9: error: invalid operands to binary + (have 'int *' and 'int *')")
+    );
+    assert!(
+        out.stderr
+            .contains("main.c:8:12: error: subscripted value is neither array nor pointer")
+    );
+}
+
+#[test]
+fn mismatched_assignments_are_diagnosed() {
+    let out = compile(
+        "struct A { int x; };\nstruct B { int y; };\nint main() {\n    int x;\n    int *p;\n    char *c;\n    struct A a;\n    struct B b;\n    p = 5;\n    x = p;\n    c = p;\n    a = b;\n    return 0;\n}\n",
+    )
+    .err();
+    assert!(out.stderr.contains(
+        "main.c:9:9: error: assignment to 'int *' from 'int' makes pointer from integer without a cast"
+    ));
+    assert!(out.stderr.contains(
+        "main.c:10:9: error: assignment to 'int' from 'int *' makes integer from pointer without a cast"
+    ));
+    assert!(out.stderr.contains(
+        "main.c:11:9: error: assignment to 'char *' from incompatible pointer type 'int *'"
+    ));
+    assert!(out.stderr.contains(
+        "main.c:12:9: error: incompatible types when assigning to type 'struct A' from type 'struct B'"
+    ));
+}
+
+#[test]
+fn integer_types_convert_on_assignment() {
+    let out = compile(
+        "int main() {\n    int x;\n    char ch;\n    char d = x;\n    int *p;\n    p = 0;\n    ch = 'a';\n    x = ch;\n    ch = x;\n    ch += 200;\n    d = 300;\n    d++;\n    return x;\n}\n",
+    )
+    .ok();
+    let ir = out.ir();
+    // 写进 char 的值截成 8 位再做符号扩展，常量直接截断
+    assert!(has(&ir, "<< x 24 t0"), "{:?}", ir);
+    assert!(has(&ir, ">> t0 24 t1"), "{:?}", ir);
+    assert!(has(&ir, "MOV t1 d"), "{:?}", ir);
+    assert!(has(&ir, "= ch x"), "{:?}", ir);
+    assert!(has(&ir, "<< x 24 t2"), "{:?}", ir);
+    assert!(has(&ir, "= t3 ch"), "{:?}", ir);
+    assert!(has(&ir, "+ ch 200 t4"), "{:?}", ir);
+    assert!(has(&ir, ">> t5 24 t6"), "{:?}", ir);
+    assert!(has(&ir, "= t6 ch"), "{:?}", ir);
+    assert!(has(&ir, "= 44 d"), "{:?}", ir);
+    assert!(has(&ir, "+ d 1 t8"), "{:?}", ir);
+    assert!(has(&ir, "= t10 d"), "{:?}", ir);
+}
+
+#[test]
+fn logical_operators_take_any_scalars() {
+    // && 和 || 只要求两边是标量，类型不用一样
+    let out = compile(
+        "int main() {\n    int x;\n    int *p;\n    char c;\n    x = 1;\n    p = &x;\n    c = 0;\n    if (p && x) {\n        x = 2;\n    }\n    return x || p && c;\n}\n",
+    )
+    .ok();
+    let ir = out.ir();
+    assert!(has(&ir, "&& p x t1"), "{:?}", ir);
+    assert!(has(&ir, "&& p c t2"), "{:?}", ir);
+    assert!(has(&ir, "|| x t2 t3"), "{:?}", ir);
+}
+
+#[test]
+fn string_literals_decay_to_char_pointers() {
+    let out = compile(
+        "char *msg = \"hi\";\nchar *tail = \"hi\" + 1;\nint main() {\n    char *s = \"abc\";\n    s = \"hi\";\n    return *s;\n}\n",
+    )
+    .ok();
+    let ir = out.ir();
+    // 字符串放在数据段里，内容一样的共用一个标签
+    assert!(has(&ir, "INIT msg .LC0 4"), "{:?}", ir);
+    assert!(has(&ir, "INIT tail .LC0+1 4"), "{:?}", ir);
+    assert!(has(&ir, "ADDR .LC1 4 t0"), "{:?}", ir);
+    assert!(has(&ir, "ADDR .LC0 3 t1"), "{:?}", ir);
+    assert!(
+        out.asm
+            .contains("\n.LC0:\n    .byte 104\n    .byte 105\n    .zero 1\n"),
+        "{}",
+        out.asm
+    );
+    assert!(!out.asm.contains(".globl .LC"), "{}", out.asm);
+}
+
+#[test]
+fn string_literals_are_char_arrays() {
+    let out =
+        compile("int main() {\n    int *p = \"abc\";\n    int x = \"a\";\n    return 0;\n}\n")
+            .err();
+    assert!(out.stderr.contains(
+        "main.c:2:14: error: initialization of 'int *' from incompatible pointer type 'char *'"
+    ));
+    assert!(out.stderr.contains(
+        "main.c:3:13: error: initialization of 'int' from 'char *' makes integer from pointer without a cast"
+    ));
+}
@@ -0,0 +1,108 @@
+// 预处理器：宏展开、条件编译、#include 和 #line
+mod common;
+
+use common::{compile, quickcc, scratch_dir, write};
+use std::fs;
+
+// 预处理输出保留了原来的列号，比较时把连续的空白合成一个
+fn squeeze(text: &str) -> String {
+    text.lines()
+        .map(|line| line.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn preprocess(src: &str, args: &[&str]) -> common::Output {
+    let dir = scratch_dir();
+    write(
+        &dir,
+        "inc/h.h",
+        "#ifndef H\n#define H\n#define SQ(x) ((x) * (x))\nint helper;\n#endif\n",
+    );
+    write(&dir, "main.c", src);
+    let mut args = args.to_vec();
+    args.extend(["-E", "-Iinc", "main.c"]);
+    let out = quickcc(&dir, &args);
+    fs::remove_dir_all(dir).unwrap();
+    out
+}
+
+#[test]
+fn expands_macros_and_includes() {
+    let src = "#include \"inc/h.h\"\n#include <h.h>\n#define N 3\n#define STR(x) #x\n#define CAT(a, b) a##b\nint CAT(va, r) = SQ(N + 1);\nchar *s = STR(hello world);\n";
+    let out = preprocess(src, &[]).ok();
+    assert_eq!(
+        squeeze(&out.stdout),
+        "int helper;\nint var = ((3 + 1) * (3 + 1));\nchar *s = \"hello world\" ;"
+    );
+}
+
+#[test]
+fn evaluates_conditionals() {
+    let src = "#define N 3\n#if N * 2 == 6 && defined(N)\nint yes;\n#elif 1\nint no;\n#else\nint never;\n#endif\n#ifdef EXTRA\nint extra = EXTRA;\n#endif\n";
+    let out = preprocess(src, &["-DEXTRA=7"]).ok();
+    assert_eq!(squeeze(&out.stdout), "int yes;\nint extra = 7 ;");
+}
+
+#[test]
+fn malformed_include_is_an_error() {
+    for src in ["#include \"\n", "#include \"h.h\n", "#include h.h\n"] {
+        let out = preprocess(src, &[]).err();
+        assert!(
+            out.stderr
+                .contains("main.c:1:1: error: #include expects \"FILENAME\" or <FILENAME>"),
+            "{}",
+            out.stderr
+        );
+    }
+    let out = preprocess("#include \"missing.h\"\n", &[]).err();
+    assert!(out.stderr.contains("'missing.h' file not found"));
+}
+
+#[test]
+fn line_directive_renames_diagnostics() {
+    let out = compile("#line 10 \"foo.c\"\nint x = ;\n").err();
+    assert!(
+        out.stderr
+            .contains("foo.c:10:9: error: expected expression"),
+        "{}",
+        out.stderr
+    );
+    let out = preprocess("#line 5 \"\nint x;\n", &[]).err();
+    assert!(
+        out.stderr.contains("main.c:1:9: error: invalid filename"),
+        "{}",
+        out.stderr
+    );
+}
+
+#[test]
+fn invalid_utf8_bytes_are_reported() {
+    let dir = scratch_dir();
+    write(
+        &dir,
+        "main.c",
+        b"int main() {\n    int x\xff = 1;\n    // caf\xc3\xa9 \xe9\n    return 0;\n}\n",
+    );
+    let out = quickcc(&dir, &["main.c"]).err();
+    fs::remove_dir_all(dir).unwrap();
+    // 每个不合法的字节报告一次，合法的多字节字符不受影响，列号按字符计
+    for message in [
+        "main.c:2:10: error: invalid UTF-8 byte 0xff at offset 22",
+        "main.c:3:13: error: invalid UTF-8 byte 0xe9 at offset 42",
+    ] {
+        assert!(
+            out.stderr.contains(message),
+            "missing {}\n{}",
+            message,
+            out.stderr
+        );
+    }
+    assert_eq!(
+        out.stderr.matches("invalid UTF-8").count(),
+        2,
+        "{}",
+        out.stderr
+    );
+}
@@ -0,0 +1,81 @@
+// 结构体的布局、成员访问和整体复制
+mod common;
+
+use common::{compile, has, quickcc, scratch_dir, write};
+use std::fs;
+
+const NESTED: &str = "struct Inner { char c; int x; };\nstruct Outer { char tag; struct Inner in; char tail[3]; int *p; };\n";
+
+#[test]
+fn layout_dump_matches_ilp32() {
+    let dir = scratch_dir();
+    write(
+        &dir,
+        "main.c",
+        format!("{}int main() {{ return 0; }}\n", NESTED),
+    );
+    let out = quickcc(&dir, &["-fdump-record-layouts", "main.c"]).ok();
+    fs::remove_dir_all(dir).unwrap();
+    // char 后面补到 int 的对齐，嵌套的结构体展开到里面的成员
+    assert!(out.stdout.contains(
+        "*** Dumping AST Record Layout\n         0 | struct Inner\n         0 |   char c\n         4 |   int x\n           | [sizeof=8, align=4]\n"
+    ), "{}", out.stdout);
+    assert!(out.stdout.contains(
+        "         0 | struct Outer\n         0 |   char tag\n         4 |   struct Inner in\n         4 |     char c\n         8 |     int x\n        12 |   char[3] tail\n        16 |   int * p\n           | [sizeof=20, align=4]\n"
+    ), "{}", out.stdout);
+}
+
+#[test]
+fn member_access_uses_field_offsets() {
+    let out = compile(&format!(
+        "{}int main() {{\n    struct Outer o;\n    struct Outer *po;\n    po = &o;\n    po->in.x = 4;\n    o.tail[1] = 'z';\n    return o.in.x;\n}}\n",
+        NESTED
+    ))
+    .ok();
+    let ir = out.ir();
+    // po->in.x 在 4 + 4 的位置
+    assert!(has(&ir, "+ po 4 t1"), "{:?}", ir);
+    assert!(has(&ir, "+ t1 4 t2"), "{:?}", ir);
+    assert!(has(&ir, "STORE 4 t2"), "{:?}", ir);
+    // o.tail[1] 在 12 + 1 的位置，按字节写
+    assert!(has(&ir, "+ t3 12 t4"), "{:?}", ir);
+    assert!(has(&ir, "+ t4 1 t5"), "{:?}", ir);
+    assert!(has(&ir, "STOREB 122 t5"), "{:?}", ir);
+}
+
+#[test]
+fn assignment_copies_every_word() {
+    let out = compile(&format!(
+        "{}int main() {{\n    struct Outer o;\n    struct Outer copy;\n    copy = o;\n    return copy.in.x;\n}}\n",
+        NESTED
+    ))
+    .ok();
+    let ir = out.ir();
+    // 20 个字节按字复制 5 次
+    let loads = ir.iter().filter(|q| q.starts_with("LOAD ")).count();
+    let stores = ir.iter().filter(|q| q.starts_with("STORE ")).count();
+    assert_eq!(stores, 5, "{:?}", ir);
+    assert_eq!(loads, 6, "{:?}", ir);
+}
+
+#[test]
+fn struct_errors_are_diagnosed() {
+    let out = compile(
+        "struct A { int x; };\nstruct B { int y; };\nstruct A { int z; };\nint main() {\n    struct A a;\n    struct B b;\n    struct C c;\n    int n;\n    n = a.w;\n    n = n->x;\n    return a + 1;\n}\n",
+    )
+    .err();
+    for message in [
+        "main.c:3:1: error: redefinition of 'struct A'",
+        "main.c:7:14: error: storage size of 'c' isn't known",
+        "main.c:9:9: error: 'struct A' has no member named 'w'",
+        "main.c:10:9: error: invalid type argument of '->' (have 'int')",
+        "main.c:11:12: error: invalid operands to binary + (have 'struct A' and 'int')",
+    ] {
+        assert!(
+            out.stderr.contains(message),
+            "missing {}\n{}",
+            message,
+            out.stderr
+        );
+    }
+}
@@ -0,0 +1,80 @@
+// 语法分析：所有标点记号、语法错误的位置和出错后的恢复
+mod common;
+
+use common::{compile, has, quickcc, scratch_dir, write};
+use std::fs;
+
+#[test]
+fn end_of_input_is_at_the_end_of_the_last_line() {
+    // 空文件的输入结束在第一行开头
+    let out = compile("").err();
+    assert!(
+        out.stderr.starts_with("main.c:1:1: error: "),
+        "{}",
+        out.stderr
+    );
+    // 最后一行没有换行
+    let out = compile("int").err();
+    assert!(
+        out.stderr.contains("found end of input at 1:4"),
+        "{}",
+        out.stderr
+    );
+    // 以换行结尾时停在换行前的那一行
+    let out = compile("int main() {\n    return 0;\n").err();
+    assert!(
+        out.stderr
+            .contains("main.c:2:14: error: expected '}' at end of input\n"),
+        "{}",
+        out.stderr
+    );
+}
+
+#[test]
+fn end_of_input_stays_in_the_main_file() {
+    let dir = scratch_dir();
+    write(&dir, "a.h", "int a\n");
+    write(&dir, "main.c", "int f() {\n#include \"a.h\"\n");
+    let out = quickcc(&dir, &["main.c"]).err();
+    fs::remove_dir_all(dir).unwrap();
+    // 头文件最后的 token 后面还有主文件的内容，输入结束在 main.c 的 #include 行尾
+    assert!(
+        out.stderr
+            .contains("main.c:2:15: error: expected ';', ',', '[' or '=', found end of input\n"),
+        "{}",
+        out.stderr
+    );
+    assert!(!out.stderr.contains("a.h"), "{}", out.stderr);
+}
+
+#[test]
+fn independent_syntax_errors_are_each_reported_once() {
+    let out = compile(
+        "int f(int a) {\n    int x;\n    x = a +;\n    return x;\n}\nint g() {\n    int y\n    y = 2;\n    return y;\n}\nint h() {\n    return (1 + 2;\n}\nint main() {\n    return f(1) + g() + h();\n}\n",
+    )
+    .err();
+    // 每个函数里的错误各报一次，恢复之后后面的函数照常分析
+    assert_eq!(
+        out.stderr,
+        "main.c:3:12: error: expected expression, found ';'\nmain.c:7:10: error: expected ';' after declaration, found 'y' at 8:5\nmain.c:12:18: error: expected ')', found ';'\n0 lexical error(s), 3 syntax error(s)\n"
+    );
+}
+
+#[test]
+fn every_punctuator_reaches_the_parser() {
+    let out = compile(
+        "struct P <% int v; %>;\nint main() <%\n    int a<:2:>;\n    struct P p;\n    struct P *q = &p;\n    int i = 1;\n    a<:0:> = 5;\n    q->v = 64;\n    q->v >>= 2;\n    q->v <<= 1;\n    a<:1:> = i+++a<:0:>;\n    return i-->0 ? a<:1:> + q->v % 7 : -1;\n%>\n",
+    )
+    .ok();
+    let ir = out.ir();
+    // 复合赋值按 -> 访问的成员读改写
+    assert!(has(&ir, ">> t3 2 t4"), "{:?}", ir);
+    assert!(has(&ir, "STORE t4 q"), "{:?}", ir);
+    assert!(has(&ir, "<< t5 1 t6"), "{:?}", ir);
+    // i+++a 是 i++ + a，i-->0 是 i-- > 0
+    assert!(has(&ir, "MOV i t9"), "{:?}", ir);
+    assert!(has(&ir, "+ t9 t13 t14"), "{:?}", ir);
+    assert!(has(&ir, "- i 1 t16"), "{:?}", ir);
+    assert!(has(&ir, "> t15 0 t17"), "{:?}", ir);
+    assert!(has(&ir, "% t22 7 t23"), "{:?}", ir);
+}